@@ -1,4 +1,5 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
 use trading_calendar::{Market, NaiveDate, TradingCalendar};
 
 fn benchmark_is_trading_day(c: &mut Criterion) {
@@ -19,5 +20,19 @@ fn benchmark_trading_hours(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, benchmark_is_trading_day, benchmark_trading_hours);
+fn benchmark_add_trading_days(c: &mut Criterion) {
+    let calendar = TradingCalendar::new(Market::NYSE).expect("Failed to create calendar");
+
+    c.bench_function("add_trading_days", |b| {
+        let date = NaiveDate::from_ymd_opt(2025, 7, 15).unwrap();
+        b.iter(|| calendar.add_trading_days(black_box(date), black_box(500)));
+    });
+}
+
+criterion_group!(
+    benches,
+    benchmark_is_trading_day,
+    benchmark_trading_hours,
+    benchmark_add_trading_days
+);
 criterion_main!(benches);
@@ -0,0 +1,293 @@
+//! Command-line interface to the trading calendars
+//!
+//! ```text
+//! trading-calendar is-open NYSE --at 2025-07-03T14:00-04:00
+//! trading-calendar holidays LSE 2026 --format csv
+//! trading-calendar next-open TSE
+//! trading-calendar count NYSE 2025-01-01 2025-12-31
+//! trading-calendar add-days NYSE 2025-12-22 5
+//! ```
+//!
+//! `is-open` exits with status 0 when the market is in its regular session
+//! (or, with `--extended`, in pre-market or after-hours trading) and 1 when
+//! it is not, so scripts can gate on it directly. Errors exit with status 2.
+
+use chrono::{DateTime, FixedOffset, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use std::process::ExitCode;
+use std::str::FromStr;
+use trading_calendar::{
+    CalendarError, Holiday, Market, NaiveDate, Result, SessionKind, TradingCalendar,
+};
+
+#[derive(Parser)]
+#[command(name = "trading-calendar", version, about)]
+struct Cli {
+    /// Output format
+    #[arg(long, short, value_enum, global = true, default_value_t = Format::Text)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check whether a market is open (exit status 0 if open, 1 if closed)
+    IsOpen {
+        /// Market code, e.g. NYSE
+        #[arg(value_parser = Market::from_str)]
+        market: Market,
+        /// Instant to check, e.g. 2025-07-03T14:00-04:00 (defaults to now)
+        #[arg(long, value_parser = parse_instant)]
+        at: Option<DateTime<FixedOffset>>,
+        /// Count pre-market and after-hours trading as open
+        #[arg(long)]
+        extended: bool,
+    },
+    /// List the closures and early closes of a year
+    Holidays {
+        /// Market code, e.g. LSE
+        #[arg(value_parser = Market::from_str)]
+        market: Market,
+        /// Year to list
+        year: i32,
+    },
+    /// Show when a market next opens
+    NextOpen {
+        /// Market code, e.g. TSE
+        #[arg(value_parser = Market::from_str)]
+        market: Market,
+    },
+    /// Show when a market next closes
+    NextClose {
+        /// Market code, e.g. TSE
+        #[arg(value_parser = Market::from_str)]
+        market: Market,
+    },
+    /// Count the trading days between two dates (inclusive)
+    Count {
+        /// Market code, e.g. NYSE
+        #[arg(value_parser = Market::from_str)]
+        market: Market,
+        /// First date (YYYY-MM-DD)
+        start: NaiveDate,
+        /// Last date (YYYY-MM-DD)
+        end: NaiveDate,
+    },
+    /// Move a date by a number of trading days (negative to go back)
+    AddDays {
+        /// Market code, e.g. NYSE
+        #[arg(value_parser = Market::from_str)]
+        market: Market,
+        /// Starting date (YYYY-MM-DD)
+        date: NaiveDate,
+        /// Number of trading days
+        #[arg(allow_negative_numbers = true)]
+        days: i64,
+    },
+}
+
+/// What a command prints, and the status it exits with
+struct Output {
+    text: String,
+    json: serde_json::Value,
+    csv: String,
+    success: bool,
+}
+
+impl Output {
+    fn new(text: String, json: serde_json::Value, csv: String) -> Self {
+        Output {
+            text,
+            json,
+            csv,
+            success: true,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(output) => {
+            match cli.format {
+                Format::Text => println!("{}", output.text),
+                Format::Json => println!("{}", output.json),
+                Format::Csv => print!("{}", output.csv),
+            }
+            if output.success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(command: Command) -> Result<Output> {
+    match command {
+        Command::IsOpen {
+            market,
+            at,
+            extended,
+        } => {
+            let calendar = TradingCalendar::new(market)?;
+            let at = at.unwrap_or_else(|| Utc::now().fixed_offset());
+            let local = at.with_timezone(&calendar.timezone());
+            let session = calendar.session_at(at)?;
+            let open = session.is_some_and(|kind| extended || kind == SessionKind::Regular);
+
+            let state = match session {
+                Some(kind) if open => format!("open ({kind})"),
+                _ => "closed".to_string(),
+            };
+            let session = session.map(|kind| kind.to_string());
+            let mut output = Output::new(
+                format!("{} is {state} at {}", market.code(), local.to_rfc3339()),
+                json!({
+                    "market": market.code(),
+                    "at": at.to_rfc3339(),
+                    "open": open,
+                    "session": session,
+                }),
+                format!(
+                    "market,at,open,session\n{},{},{open},{}\n",
+                    market.code(),
+                    at.to_rfc3339(),
+                    session.as_deref().unwrap_or_default()
+                ),
+            );
+            output.success = open;
+            Ok(output)
+        }
+        Command::Holidays { market, year } => {
+            let calendar = TradingCalendar::new(market)?;
+            let mut days = calendar.holidays(year)?;
+            days.extend(calendar.early_closes(year)?);
+            days.sort_by_key(|day| day.date);
+            Ok(holiday_output(&days))
+        }
+        Command::NextOpen { market } => {
+            let instant = TradingCalendar::new(market)?.next_open()?;
+            Ok(instant_output(market, "next_open", instant.to_rfc3339()))
+        }
+        Command::NextClose { market } => {
+            let instant = TradingCalendar::new(market)?.next_close()?;
+            Ok(instant_output(market, "next_close", instant.to_rfc3339()))
+        }
+        Command::Count { market, start, end } => {
+            let count = TradingCalendar::new(market)?.count_trading_days(start, end)?;
+            Ok(Output::new(
+                count.to_string(),
+                json!({ "market": market.code(), "start": start, "end": end, "trading_days": count }),
+                format!(
+                    "market,start,end,trading_days\n{},{start},{end},{count}\n",
+                    market.code()
+                ),
+            ))
+        }
+        Command::AddDays { market, date, days } => {
+            let result = TradingCalendar::new(market)?.add_trading_days(date, days)?;
+            Ok(Output::new(
+                result.to_string(),
+                json!({ "market": market.code(), "date": date, "days": days, "result": result }),
+                format!(
+                    "market,date,days,result\n{},{date},{days},{result}\n",
+                    market.code()
+                ),
+            ))
+        }
+    }
+}
+
+fn holiday_output(days: &[Holiday]) -> Output {
+    let kind = |day: &Holiday| {
+        if day.market_closed {
+            "closed"
+        } else {
+            "early_close"
+        }
+    };
+    let close = |day: &Holiday| {
+        day.early_close
+            .map(|close| close.format("%H:%M").to_string())
+            .unwrap_or_default()
+    };
+
+    let text = days
+        .iter()
+        .map(|day| match day.early_close {
+            Some(_) => format!("{}  {} (closes {})", day.date, day.name, close(day)),
+            None => format!("{}  {}", day.date, day.name),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let json = days
+        .iter()
+        .map(|day| {
+            json!({
+                "date": day.date,
+                "name": day.name,
+                "type": kind(day),
+                "close": day.early_close.map(|_| close(day)),
+            })
+        })
+        .collect();
+    let mut csv = String::from("date,name,type,close\n");
+    for day in days {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            day.date,
+            csv_field(&day.name),
+            kind(day),
+            close(day)
+        ));
+    }
+    Output::new(text, json, csv)
+}
+
+fn instant_output(market: Market, field: &str, instant: String) -> Output {
+    Output::new(
+        instant.clone(),
+        json!({ "market": market.code(), field: instant }),
+        format!("market,{field}\n{},{instant}\n", market.code()),
+    )
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parse an RFC 3339 instant, allowing the seconds to be left out
+fn parse_instant(value: &str) -> Result<DateTime<FixedOffset>> {
+    let normalized = match value.strip_suffix(['Z', 'z']) {
+        Some(rest) => format!("{rest}+00:00"),
+        None => value.to_string(),
+    };
+    ["%Y-%m-%dT%H:%M:%S%.f%:z", "%Y-%m-%dT%H:%M%:z"]
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(&normalized, format).ok())
+        .ok_or_else(|| {
+            CalendarError::InvalidTime(format!(
+                "'{value}' is not an instant like 2025-07-03T14:00-04:00"
+            ))
+        })
+}
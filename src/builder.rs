@@ -0,0 +1,524 @@
+//! Calendar construction with user-supplied overrides
+//!
+//! [`TradingCalendarBuilder`] layers extra closures, extra early closes,
+//! removed holidays and custom session times on top of a built-in market,
+//! so an exchange announcement can be applied from configuration without
+//! waiting for a crate release.
+//!
+//! # Example
+//!
+//! ```
+//! use trading_calendar::{Market, TradingCalendar};
+//! use chrono::{NaiveDate, NaiveTime};
+//!
+//! let closure = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+//! let nyse = TradingCalendar::builder(Market::NYSE)
+//!     .add_closure(closure, "Emergency closure")
+//!     .build()?;
+//!
+//! assert!(!nyse.is_trading_day(closure)?);
+//! assert_eq!(nyse.holiday_info(closure)?.unwrap().name, "Emergency closure");
+//! # Ok::<(), trading_calendar::CalendarError>(())
+//! ```
+
+use crate::markets::{MarketImpl, WeekendMask};
+use crate::{
+    CalendarError, Holiday, Market, Result, Session, SpecialDay, TradingCalendar, TradingHours,
+    WorkingDay,
+};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use chrono_tz::Tz;
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+/// Builder for a [`TradingCalendar`] with overrides on top of a base market
+///
+/// Overrides are validated when [`build`](Self::build) is called.
+#[derive(Debug, Clone)]
+pub struct TradingCalendarBuilder {
+    market: Market,
+    years: Option<RangeInclusive<i32>>,
+    closures: Vec<Holiday>,
+    early_closes: Vec<Holiday>,
+    removed: Vec<NaiveDate>,
+    regular: Option<Session>,
+    pre_market: Option<Option<Session>>,
+    after_hours: Option<Option<Session>>,
+}
+
+impl TradingCalendarBuilder {
+    /// Start building a calendar for the specified market
+    pub fn new(market: Market) -> Self {
+        TradingCalendarBuilder {
+            market,
+            years: None,
+            closures: Vec::new(),
+            early_closes: Vec::new(),
+            removed: Vec::new(),
+            regular: None,
+            pre_market: None,
+            after_hours: None,
+        }
+    }
+
+    /// Get the market the overrides apply to
+    pub fn market(&self) -> Market {
+        self.market
+    }
+
+    /// Restrict the calendar to a range of years
+    pub fn year_range(mut self, years: RangeInclusive<i32>) -> Self {
+        self.years = Some(years);
+        self
+    }
+
+    /// Close the market for the whole day
+    ///
+    /// The date must be a weekday of the market (or a weekend day on which
+    /// it trades).
+    pub fn add_closure(mut self, date: NaiveDate, name: &str) -> Self {
+        self.closures.push(Holiday::new(date, name, true));
+        self
+    }
+
+    /// Close the market early on a day
+    ///
+    /// The date must be a weekday of the market (or a weekend day on which
+    /// it trades).
+    pub fn add_early_close(mut self, date: NaiveDate, close: NaiveTime, name: &str) -> Self {
+        self.early_closes
+            .push(Holiday::with_early_close(date, name, close));
+        self
+    }
+
+    /// Remove a built-in holiday or early close, making it a full trading day
+    ///
+    /// The date must be a weekday of the market (or a weekend day on which
+    /// it trades).
+    pub fn remove_holiday(mut self, date: NaiveDate) -> Self {
+        self.removed.push(date);
+        self
+    }
+
+    /// Replace the regular session times
+    ///
+    /// Built-in intraday breaks that no longer fit inside the session are
+    /// dropped.
+    pub fn regular_session(mut self, session: Session) -> Self {
+        self.regular = Some(session);
+        self
+    }
+
+    /// Replace the pre-market session, or remove it with `None`
+    pub fn pre_market(mut self, session: Option<Session>) -> Self {
+        self.pre_market = Some(session);
+        self
+    }
+
+    /// Replace the after-hours session, or remove it with `None`
+    pub fn after_hours(mut self, session: Option<Session>) -> Self {
+        self.after_hours = Some(session);
+        self
+    }
+
+    /// Build the calendar
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::InvalidConfiguration` if the year range is empty or
+    ///   outside the market's rules, a date is given more than one override,
+    ///   an override falls on the market's weekend, or an early close falls
+    ///   outside the regular session
+    /// - `CalendarError::DateOutOfRange` if an override is outside the year range
+    /// - `CalendarError::InvalidSession` if the regular session is overnight
+    pub fn build(self) -> Result<TradingCalendar> {
+        let base = self.market.create_implementation()?;
+        let supported = base.supported_years();
+        let years = self.years.clone().unwrap_or_else(|| supported.clone());
+        if years.is_empty()
+            || !supported.contains(years.start())
+            || !supported.contains(years.end())
+        {
+            return Err(CalendarError::InvalidConfiguration(format!(
+                "Year range {}-{} is outside the years supported by {} ({}-{})",
+                years.start(),
+                years.end(),
+                self.market.code(),
+                supported.start(),
+                supported.end()
+            )));
+        }
+
+        if let Some(regular) = &self.regular {
+            if regular.start >= regular.end {
+                return Err(CalendarError::InvalidSession);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let dates = self
+            .closures
+            .iter()
+            .chain(&self.early_closes)
+            .map(|day| day.date)
+            .chain(self.removed.iter().copied());
+        for date in dates {
+            if !years.contains(&date.year()) {
+                return Err(CalendarError::DateOutOfRange(date));
+            }
+            if !seen.insert(date) {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "More than one override given for {date}"
+                )));
+            }
+            if is_off_week(base.as_ref(), date) {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "Override for {date} falls on the {} weekend",
+                    self.market.code()
+                )));
+            }
+        }
+
+        if self.is_empty() {
+            return Ok(TradingCalendar::from_parts(self.market, base, years));
+        }
+
+        let overrides = OverrideMarket {
+            base,
+            closures: self
+                .closures
+                .into_iter()
+                .map(|day| (day.date, day))
+                .collect(),
+            early_closes: self
+                .early_closes
+                .into_iter()
+                .map(|day| (day.date, day))
+                .collect(),
+            removed: self.removed.into_iter().collect(),
+            regular: self.regular,
+            pre_market: self.pre_market,
+            after_hours: self.after_hours,
+        };
+
+        for day in overrides.early_closes.values() {
+            let regular = overrides.regular_session(day.date);
+            let close = day.early_close.unwrap_or(regular.end);
+            if close <= regular.start || close >= regular.end {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "Early close at {close} on {} is outside the regular session {regular}",
+                    day.date
+                )));
+            }
+        }
+
+        Ok(TradingCalendar::from_parts(
+            self.market,
+            Box::new(overrides),
+            years,
+        ))
+    }
+
+    /// Whether the builder has no overrides besides the year range
+    fn is_empty(&self) -> bool {
+        self.closures.is_empty()
+            && self.early_closes.is_empty()
+            && self.removed.is_empty()
+            && self.regular.is_none()
+            && self.pre_market.is_none()
+            && self.after_hours.is_none()
+    }
+}
+
+/// Whether a date falls on a market's weekend and is not a working day on
+/// which it trades
+pub(crate) fn is_off_week(market: &dyn MarketImpl, date: NaiveDate) -> bool {
+    market.is_weekend(date)
+        && !market
+            .special_working_days(date.year())
+            .iter()
+            .any(|day| day.date == date && day.trading)
+}
+
+/// A market implementation with user overrides applied on top of a base market
+struct OverrideMarket {
+    base: Box<dyn MarketImpl>,
+    closures: HashMap<NaiveDate, Holiday>,
+    early_closes: HashMap<NaiveDate, Holiday>,
+    removed: HashSet<NaiveDate>,
+    regular: Option<Session>,
+    pre_market: Option<Option<Session>>,
+    after_hours: Option<Option<Session>>,
+}
+
+impl OverrideMarket {
+    /// Whether a date's built-in holiday or early close is overridden
+    fn is_overridden(&self, date: NaiveDate) -> bool {
+        self.removed.contains(&date)
+            || self.closures.contains_key(&date)
+            || self.early_closes.contains_key(&date)
+    }
+
+    /// The regular session for a date, with any override applied
+    fn regular_session(&self, date: NaiveDate) -> Session {
+        self.regular
+            .clone()
+            .unwrap_or_else(|| self.base.trading_hours(date).regular)
+    }
+}
+
+impl MarketImpl for OverrideMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        if self.closures.contains_key(&date) {
+            true
+        } else if self.removed.contains(&date) || self.early_closes.contains_key(&date) {
+            false
+        } else {
+            self.base.is_holiday(date)
+        }
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays: Vec<Holiday> = self
+            .base
+            .holidays(year)
+            .into_iter()
+            .filter(|day| !self.is_overridden(day.date))
+            .chain(
+                self.closures
+                    .values()
+                    .filter(|day| day.date.year() == year)
+                    .cloned(),
+            )
+            .collect();
+        holidays.sort_by_key(|day| day.date);
+        holidays
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        let mut early_closes: Vec<Holiday> = self
+            .base
+            .early_closes(year)
+            .into_iter()
+            .filter(|day| !self.is_overridden(day.date))
+            .chain(
+                self.early_closes
+                    .values()
+                    .filter(|day| day.date.year() == year)
+                    .cloned(),
+            )
+            .collect();
+        early_closes.sort_by_key(|day| day.date);
+        early_closes
+    }
+
+    fn early_close(&self, date: NaiveDate) -> Option<NaiveTime> {
+        if let Some(day) = self.early_closes.get(&date) {
+            day.early_close
+        } else if self.is_overridden(date) {
+            None
+        } else {
+            self.base.early_close(date)
+        }
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        self.base
+            .special_days(year)
+            .into_iter()
+            .filter(|day| !self.is_overridden(day.date))
+            .collect()
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = self.base.trading_hours(date);
+        let base_open = hours.regular.start;
+        let base_close = hours.market_close();
+
+        if let Some(regular) = &self.regular {
+            hours.regular = regular.clone();
+            hours
+                .breaks
+                .retain(|pause| pause.start > regular.start && pause.end < regular.end);
+        }
+        hours.early_close = self.early_close(date);
+
+        // Built-in extended sessions adjoin the regular session, so they
+        // follow it unless replaced explicitly
+        if let Some(pre_market) = &self.pre_market {
+            hours.pre_market = pre_market.clone();
+        } else if let Some(pre) = &mut hours.pre_market {
+            if pre.end == base_open {
+                pre.end = hours.regular.start;
+            }
+        }
+        if let Some(after_hours) = &self.after_hours {
+            hours.after_hours = after_hours.clone();
+        } else {
+            let close = hours.market_close();
+            if let Some(after) = &mut hours.after_hours {
+                if after.start == base_close {
+                    after.start = close;
+                }
+            }
+        }
+
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        self.base.timezone()
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        self.base.supported_years()
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.base.name()
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.base.code()
+    }
+
+    fn weekend(&self, date: NaiveDate) -> WeekendMask {
+        self.base.weekend(date)
+    }
+
+    fn special_working_days(&self, year: i32) -> Vec<WorkingDay> {
+        self.base.special_working_days(year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{date, time};
+
+    #[test]
+    fn test_closures_and_removals() {
+        let nyse = TradingCalendarBuilder::new(Market::NYSE)
+            .add_closure(date(2025, 3, 12), "Emergency closure")
+            .remove_holiday(date(2025, 1, 9))
+            .build()
+            .unwrap();
+
+        assert!(!nyse.is_trading_day(date(2025, 3, 12)).unwrap());
+        assert!(nyse.is_trading_day(date(2025, 1, 9)).unwrap());
+
+        let holidays = nyse.holidays(2025).unwrap();
+        assert!(holidays.iter().any(|h| h.name == "Emergency closure"));
+        assert!(!holidays.iter().any(|h| h.date == date(2025, 1, 9)));
+        assert!(holidays.windows(2).all(|pair| pair[0].date < pair[1].date));
+        assert!(nyse.special_days(2025).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_early_close_overrides() {
+        let nyse = TradingCalendarBuilder::new(Market::NYSE)
+            .add_early_close(date(2025, 3, 12), time(14, 0), "Systems upgrade")
+            .remove_holiday(date(2025, 12, 24))
+            .build()
+            .unwrap();
+
+        let hours = nyse.trading_hours(date(2025, 3, 12));
+        assert_eq!(hours.market_close(), time(14, 0));
+        assert_eq!(hours.after_hours.unwrap().start, time(14, 0));
+
+        // Removing a built-in half day restores the full session
+        let hours = nyse.trading_hours(date(2025, 12, 24));
+        assert!(!hours.is_early_close());
+        assert_eq!(hours.after_hours.unwrap().start, time(16, 0));
+        assert!(!nyse
+            .early_closes(2025)
+            .unwrap()
+            .iter()
+            .any(|h| h.date == date(2025, 12, 24)));
+    }
+
+    #[test]
+    fn test_session_overrides() {
+        let tse = TradingCalendarBuilder::new(Market::TSE)
+            .regular_session(Session::new(time(9, 0), time(15, 0)).unwrap())
+            .build()
+            .unwrap();
+        let hours = tse.trading_hours(date(2025, 3, 10));
+        assert_eq!(hours.market_close(), time(15, 0));
+        assert!(hours.has_breaks());
+
+        let lse = TradingCalendarBuilder::new(Market::LSE)
+            .regular_session(Session::new(time(8, 0), time(16, 35)).unwrap())
+            .pre_market(None)
+            .build()
+            .unwrap();
+        let hours = lse.trading_hours(date(2025, 3, 10));
+        assert_eq!(hours.market_close(), time(16, 35));
+        assert!(hours.pre_market.is_none());
+    }
+
+    #[test]
+    fn test_invalid_overrides() {
+        let day = date(2025, 3, 12);
+        let build = |builder: TradingCalendarBuilder| builder.build().err();
+
+        assert!(matches!(
+            build(TradingCalendarBuilder::new(Market::NYSE).year_range(1900..=2000)),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            build(
+                TradingCalendarBuilder::new(Market::NYSE)
+                    .year_range(2020..=2024)
+                    .add_closure(day, "Out of range")
+            ),
+            Some(CalendarError::DateOutOfRange(_))
+        ));
+        assert!(matches!(
+            build(
+                TradingCalendarBuilder::new(Market::NYSE)
+                    .add_closure(day, "Closed")
+                    .add_early_close(day, time(13, 0), "Half day")
+            ),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            build(TradingCalendarBuilder::new(Market::NYSE).add_early_close(
+                day,
+                time(17, 0),
+                "After close"
+            )),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            build(
+                TradingCalendarBuilder::new(Market::NYSE)
+                    .regular_session(Session::new(time(16, 0), time(9, 30)).unwrap())
+            ),
+            Some(CalendarError::InvalidSession)
+        ));
+
+        // Overrides on the market's weekend
+        let saturday = date(2025, 3, 15);
+        for builder in [
+            TradingCalendarBuilder::new(Market::NYSE).add_closure(saturday, "Closed"),
+            TradingCalendarBuilder::new(Market::NYSE).add_early_close(
+                saturday,
+                time(13, 0),
+                "Half day",
+            ),
+            TradingCalendarBuilder::new(Market::NYSE).remove_holiday(saturday),
+            TradingCalendarBuilder::new(Market::TADAWUL).add_closure(date(2025, 3, 14), "Friday"),
+        ] {
+            assert!(matches!(
+                build(builder),
+                Some(CalendarError::InvalidConfiguration(_))
+            ));
+        }
+        // Sunday is a weekday in Riyadh
+        assert!(TradingCalendarBuilder::new(Market::TADAWUL)
+            .add_closure(date(2025, 3, 16), "Closed")
+            .build()
+            .is_ok());
+    }
+}
@@ -1,24 +1,168 @@
 //! Main trading calendar implementation
 
+use crate::clock::{Clock, SystemClock};
+use crate::index::{TradingDayIndex, YearIndex};
 use crate::markets::MarketImpl;
-use crate::{CalendarError, Market, Result, TradingHours, MAX_YEAR, MIN_YEAR};
-use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use crate::{
+    CalendarError, Holiday, Market, Result, SessionKind, SessionLocation, SessionWindow,
+    SpecialDay, TradingCalendarBuilder, TradingHours, WeekendMask, WorkingDay,
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::Tz;
+use std::ops::{Range, RangeInclusive};
+use std::sync::Arc;
+
+/// Trading days the session queries search before concluding the market
+/// does not hold a kind of session
+const SESSION_SEARCH_DAYS: usize = 14;
 
 /// A trading calendar for a specific market
 pub struct TradingCalendar {
     market: Market,
     implementation: Box<dyn MarketImpl>,
+    years: RangeInclusive<i32>,
+    clock: Arc<dyn Clock>,
+    index: TradingDayIndex,
 }
 
 impl TradingCalendar {
     /// Create a new trading calendar for the specified market
+    ///
+    /// The calendar accepts every year the market's rules cover; see
+    /// [`supported_years`](Self::supported_years).
     pub fn new(market: Market) -> Result<Self> {
         let implementation = market.create_implementation()?;
-        Ok(TradingCalendar {
+        let years = implementation.supported_years();
+        Ok(Self::from_parts(market, implementation, years))
+    }
+
+    /// Create a trading calendar restricted to a range of years
+    ///
+    /// Queries for dates outside `years` return
+    /// `CalendarError::DateOutOfRange`, which guards against silently
+    /// extrapolating rules beyond the period a caller has validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if the range is empty or
+    /// extends beyond the years the market's rules cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030)?;
+    /// assert_eq!(nyse.supported_years(), 2020..=2030);
+    ///
+    /// let before = NaiveDate::from_ymd_opt(2019, 12, 31).unwrap();
+    /// assert!(nyse.is_trading_day(before).is_err());
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn with_year_range(market: Market, years: RangeInclusive<i32>) -> Result<Self> {
+        Self::builder(market).year_range(years).build()
+    }
+
+    /// Start building a calendar with overrides on top of a market
+    ///
+    /// See [`TradingCalendarBuilder`] for the available overrides.
+    pub fn builder(market: Market) -> TradingCalendarBuilder {
+        TradingCalendarBuilder::new(market)
+    }
+
+    /// Create a trading calendar from a declarative definition
+    ///
+    /// The calendar reports [`Market::Custom`] from [`market`](Self::market)
+    /// and otherwise behaves like a built-in market. See
+    /// [`definition`](crate::definition) for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` or
+    /// `CalendarError::InvalidSession` if the definition is inconsistent,
+    /// e.g. an unknown timezone or an early close outside the regular session.
+    #[cfg(feature = "definitions")]
+    pub fn from_definition(definition: crate::CalendarDefinition) -> Result<Self> {
+        let implementation = crate::definition::DefinitionMarket::new(definition)?;
+        let years = implementation.supported_years();
+        Ok(Self::from_parts(
+            Market::Custom,
+            Box::new(implementation),
+            years,
+        ))
+    }
+
+    /// Assemble a calendar from an implementation and a validated year range
+    pub(crate) fn from_parts(
+        market: Market,
+        implementation: Box<dyn MarketImpl>,
+        years: RangeInclusive<i32>,
+    ) -> Self {
+        TradingCalendar {
             market,
             implementation,
-        })
+            years,
+            clock: Arc::new(SystemClock),
+            index: TradingDayIndex::default(),
+        }
+    }
+
+    /// Read the current time from `clock` instead of the system clock
+    ///
+    /// Affects [`is_open_now`](Self::is_open_now), [`next_open`](Self::next_open)
+    /// and [`next_close`](Self::next_close). See [`ManualClock`](crate::clock::ManualClock)
+    /// for a clock tests can move.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Get the range of years this calendar answers queries for
+    pub fn supported_years(&self) -> RangeInclusive<i32> {
+        self.years.clone()
+    }
+
+    /// Ensure a year is within the supported range
+    fn check_year(&self, year: i32) -> Result<()> {
+        if self.years.contains(&year) {
+            Ok(())
+        } else {
+            Err(CalendarError::DateOutOfRange(
+                NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or_default(),
+            ))
+        }
+    }
+
+    /// Ensure a date is within the supported range
+    fn check_date(&self, date: NaiveDate) -> Result<()> {
+        if self.years.contains(&date.year()) {
+            Ok(())
+        } else {
+            Err(CalendarError::DateOutOfRange(date))
+        }
+    }
+
+    /// Get the trading day table of a supported year
+    pub(crate) fn year_index(&self, year: i32) -> Result<Arc<YearIndex>> {
+        self.check_year(year)?;
+        Ok(self
+            .index
+            .year(year, |date| self.implementation.is_trading_day(date)))
+    }
+
+    /// Get the trading day table of a month's year and the month's slice of it
+    fn month_index(&self, year: i32, month: u32) -> Result<(Arc<YearIndex>, Range<usize>)> {
+        let invalid =
+            || CalendarError::InvalidDateCalculation(format!("Invalid year/month: {year}/{month}"));
+        let start = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(invalid)?;
+        let index = self.year_index(year)?;
+        let end = match month {
+            12 => index.len(),
+            _ => index.before(NaiveDate::from_ymd_opt(year, month + 1, 1).ok_or_else(invalid)?),
+        };
+        let range = index.before(start)..end;
+        Ok((index, range))
     }
 
     /// Check if a specific date is a trading day
@@ -28,7 +172,8 @@ impl TradingCalendar {
     ///
     /// # Errors
     ///
-    /// Returns `CalendarError::DateOutOfRange` if the date is outside 2020-2030.
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
     ///
     /// # Examples
     ///
@@ -52,9 +197,7 @@ impl TradingCalendar {
     /// # Ok::<(), trading_calendar::CalendarError>(())
     /// ```
     pub fn is_trading_day(&self, date: NaiveDate) -> Result<bool> {
-        if date.year() < MIN_YEAR || date.year() > MAX_YEAR {
-            return Err(CalendarError::DateOutOfRange(date));
-        }
+        self.check_date(date)?;
         Ok(self.implementation.is_trading_day(date))
     }
 
@@ -65,7 +208,8 @@ impl TradingCalendar {
     ///
     /// # Errors
     ///
-    /// Returns `CalendarError::DateOutOfRange` if the date is outside 2020-2030.
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
     ///
     /// # Examples
     ///
@@ -85,12 +229,174 @@ impl TradingCalendar {
     /// # Ok::<(), trading_calendar::CalendarError>(())
     /// ```
     pub fn is_holiday(&self, date: NaiveDate) -> Result<bool> {
-        if date.year() < MIN_YEAR || date.year() > MAX_YEAR {
-            return Err(CalendarError::DateOutOfRange(date));
-        }
+        self.check_date(date)?;
         Ok(self.implementation.is_holiday(date))
     }
 
+    /// Get all market holidays in a year, sorted by date
+    ///
+    /// Each entry carries the holiday's name, so callers can report *why*
+    /// the market is closed. Weekends are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the year is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    ///
+    /// let lse = TradingCalendar::new(Market::LSE)?;
+    /// let holidays = lse.holidays(2025)?;
+    /// assert!(holidays.iter().any(|h| h.name == "Spring Bank Holiday"));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn holidays(&self, year: i32) -> Result<Vec<Holiday>> {
+        self.check_year(year)?;
+        Ok(self.implementation.holidays(year))
+    }
+
+    /// Get holiday information for a specific date
+    ///
+    /// Covers both full closures and early close days; the latter have
+    /// `market_closed == false` and carry their close time in `early_close`.
+    /// Returns `None` for regular trading days and weekends.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let thanksgiving = NaiveDate::from_ymd_opt(2025, 11, 27).unwrap();
+    ///
+    /// let holiday = nyse.holiday_info(thanksgiving)?.unwrap();
+    /// assert_eq!(holiday.name, "Thanksgiving Day");
+    /// assert!(holiday.market_closed);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn holiday_info(&self, date: NaiveDate) -> Result<Option<Holiday>> {
+        Ok(self
+            .holidays(date.year())?
+            .into_iter()
+            .chain(self.implementation.early_closes(date.year()))
+            .find(|holiday| holiday.date == date))
+    }
+
+    /// Get all early close days in a year, sorted by date
+    ///
+    /// Each entry has `market_closed == false`, the reason in `name` and the
+    /// market close time (in the market's timezone) in `early_close`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the year is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveTime;
+    ///
+    /// let lse = TradingCalendar::new(Market::LSE)?;
+    /// let half_days = lse.early_closes(2025)?;
+    /// assert_eq!(half_days[0].name, "Christmas Eve");
+    /// assert_eq!(half_days[0].early_close, NaiveTime::from_hms_opt(12, 30, 0));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn early_closes(&self, year: i32) -> Result<Vec<Holiday>> {
+        self.check_year(year)?;
+        Ok(self.implementation.early_closes(year))
+    }
+
+    /// Get the one-off closures and early closes in a year, sorted by date
+    ///
+    /// Special days are market closures or early closes outside the regular
+    /// holiday rules, such as national days of mourning or weather events.
+    /// They are already reflected in [`holidays`](Self::holidays),
+    /// [`early_closes`](Self::early_closes) and [`is_trading_day`](Self::is_trading_day);
+    /// this listing adds the source of each one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the year is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let sandy = nyse.special_days(2012)?;
+    /// assert_eq!(sandy.len(), 2);
+    /// assert_eq!(sandy[0].name, "Hurricane Sandy");
+    /// assert!(!nyse.is_trading_day(NaiveDate::from_ymd_opt(2012, 10, 29).unwrap())?);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn special_days(&self, year: i32) -> Result<Vec<SpecialDay>> {
+        self.check_year(year)?;
+        Ok(self.implementation.special_days(year))
+    }
+
+    /// Get the days of the week the market is closed, as in force on a date
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market, WeekendMask};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    /// assert_eq!(nyse.weekend(date), WeekendMask::SATURDAY_SUNDAY);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn weekend(&self, date: NaiveDate) -> WeekendMask {
+        self.implementation.weekend(date)
+    }
+
+    /// Get the weekend days declared official working days in a year,
+    /// sorted by date
+    ///
+    /// Each entry records whether the exchange trades that day; working
+    /// days on which it trades are reflected in
+    /// [`is_trading_day`](Self::is_trading_day).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the year is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// // Shanghai stays closed on the make-up working days of Spring Festival
+    /// let sse = TradingCalendar::new(Market::SSE)?;
+    /// let working_days = sse.special_working_days(2025)?;
+    /// assert_eq!(working_days[0].date, NaiveDate::from_ymd_opt(2025, 1, 26).unwrap());
+    /// assert!(!working_days[0].trading);
+    /// assert!(!sse.is_trading_day(working_days[0].date)?);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn special_working_days(&self, year: i32) -> Result<Vec<WorkingDay>> {
+        self.check_year(year)?;
+        Ok(self.implementation.special_working_days(year))
+    }
+
     /// Get trading hours for a specific date
     pub fn trading_hours(&self, date: NaiveDate) -> TradingHours {
         self.implementation.trading_hours(date)
@@ -111,95 +417,459 @@ impl TradingCalendar {
         self.market
     }
 
+    /// Get the display name of this calendar's market
+    ///
+    /// Calendars built from a definition report the definition's name
+    /// rather than that of [`Market::Custom`].
+    pub fn name(&self) -> &str {
+        self.implementation
+            .name()
+            .unwrap_or_else(|| self.market.name())
+    }
+
+    /// Get the short code of this calendar's market
+    ///
+    /// Calendars built from a definition report the definition's code
+    /// rather than that of [`Market::Custom`].
+    pub fn code(&self) -> &str {
+        self.implementation
+            .code()
+            .unwrap_or_else(|| self.market.code())
+    }
+
     /// Get the timezone for this market
     pub fn timezone(&self) -> Tz {
         self.implementation.timezone()
     }
 
     /// Check if the market is currently open
+    ///
+    /// The current time comes from the calendar's [`Clock`].
     pub fn is_open_now(&self) -> Result<bool> {
-        let now = Utc::now().with_timezone(&self.timezone());
-        let date = now.date_naive();
+        self.is_open_at(self.clock.now())
+    }
+
+    /// Check if the market is open at an instant
+    ///
+    /// Pre-market and after-hours sessions count as open, as for
+    /// [`TradingHours::is_open_at`]. Sessions that opened on the previous
+    /// calendar day, such as the CME Globex Sunday-evening open, are
+    /// considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the instant falls on a
+    /// market date outside [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::DateTime;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let instant = DateTime::parse_from_rfc3339("2025-03-10T14:00:00Z").unwrap();
+    /// assert!(nyse.is_open_at(instant)?); // 10:00 in New York
+    ///
+    /// // Globex opens on Sunday evening for Monday's trading date
+    /// let cme = TradingCalendar::new(Market::CME)?;
+    /// let sunday = DateTime::parse_from_rfc3339("2025-03-09T18:00:00-05:00").unwrap();
+    /// assert!(cme.is_open_at(sunday)?);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn is_open_at<T: TimeZone>(&self, instant: DateTime<T>) -> Result<bool> {
+        Ok(self.session_at(instant)?.is_some())
+    }
 
+    /// Get the next time the market opens
+    ///
+    /// Intraday breaks are honoured: during the Tokyo lunch break this is
+    /// the start of the afternoon session. The current time comes from the
+    /// calendar's [`Clock`].
+    pub fn next_open(&self) -> Result<DateTime<Tz>> {
+        self.next_open_after(self.clock.now())
+    }
+
+    /// Get the next time the market closes
+    ///
+    /// Intraday breaks are honoured: during the Tokyo morning session this
+    /// is the start of the lunch break. The current time comes from the
+    /// calendar's [`Clock`].
+    pub fn next_close(&self) -> Result<DateTime<Tz>> {
+        self.next_close_after(self.clock.now())
+    }
+
+    /// Get the first opening of the regular session strictly after an instant
+    ///
+    /// The end of an intraday break counts as an opening.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the search leaves
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::DateTime;
+    ///
+    /// let tse = TradingCalendar::new(Market::TSE)?;
+    /// // Friday during the lunch break
+    /// let lunch = DateTime::parse_from_rfc3339("2025-03-07T12:00:00+09:00").unwrap();
+    /// assert_eq!(tse.next_open_after(lunch)?.to_rfc3339(), "2025-03-07T12:30:00+09:00");
+    /// // Friday after the close opens again on Monday
+    /// let evening = DateTime::parse_from_rfc3339("2025-03-07T18:00:00+09:00").unwrap();
+    /// assert_eq!(tse.next_open_after(evening)?.to_rfc3339(), "2025-03-10T09:00:00+09:00");
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn next_open_after<T: TimeZone>(&self, instant: DateTime<T>) -> Result<DateTime<Tz>> {
+        let local = self.local_time(instant);
+        let open = self.find_edge(local, SessionKind::Regular, true, |window| window.start)?;
+        self.localize(open, "market open")
+    }
+
+    /// Get the first close of the regular session strictly after an instant
+    ///
+    /// The start of an intraday break counts as a close, and early closes
+    /// are honoured.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the search leaves
+    /// [`supported_years`](Self::supported_years).
+    pub fn next_close_after<T: TimeZone>(&self, instant: DateTime<T>) -> Result<DateTime<Tz>> {
+        let local = self.local_time(instant);
+        let close = self.find_edge(local, SessionKind::Regular, true, |window| window.end)?;
+        self.localize(close, "market close")
+    }
+
+    /// Get the last opening of the regular session strictly before an instant
+    ///
+    /// The end of an intraday break counts as an opening.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the search leaves
+    /// [`supported_years`](Self::supported_years).
+    pub fn previous_open_before<T: TimeZone>(&self, instant: DateTime<T>) -> Result<DateTime<Tz>> {
+        let local = self.local_time(instant);
+        let open = self.find_edge(local, SessionKind::Regular, false, |window| window.start)?;
+        self.localize(open, "market open")
+    }
+
+    /// Get the last close of the regular session strictly before an instant
+    ///
+    /// The start of an intraday break counts as a close, and early closes
+    /// are honoured.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the search leaves
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::DateTime;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// // The Monday after Thanksgiving looks back to the Friday half day
+    /// let monday = DateTime::parse_from_rfc3339("2025-12-01T08:00:00-05:00").unwrap();
+    /// assert_eq!(
+    ///     nyse.previous_close_before(monday)?.to_rfc3339(),
+    ///     "2025-11-28T13:00:00-05:00"
+    /// );
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn previous_close_before<T: TimeZone>(&self, instant: DateTime<T>) -> Result<DateTime<Tz>> {
+        let local = self.local_time(instant);
+        let close = self.find_edge(local, SessionKind::Regular, false, |window| window.end)?;
+        self.localize(close, "market close")
+    }
+
+    /// Get the regular session of a trading date as UTC intervals
+    ///
+    /// Each `(open, close)` pair is one trading segment, so intraday breaks
+    /// split the session and early closes shorten it. Non-trading days have
+    /// no intervals.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let tse = TradingCalendar::new(Market::TSE)?;
+    /// let sessions = tse.regular_sessions_utc(NaiveDate::from_ymd_opt(2025, 3, 10).unwrap())?;
+    /// assert_eq!(sessions.len(), 2); // morning and afternoon
+    /// assert_eq!(sessions[0].0.to_rfc3339(), "2025-03-10T00:00:00+00:00");
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn regular_sessions_utc(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
         if !self.is_trading_day(date)? {
-            return Ok(false);
+            return Ok(Vec::new());
         }
 
-        let hours = self.trading_hours(date);
-        Ok(hours.is_open_at(now.time()))
+        self.trading_hours(date)
+            .windows()
+            .into_iter()
+            .filter(|window| window.kind == SessionKind::Regular)
+            .map(|window| {
+                let open = self.localize(window.start, "market open")?;
+                let close = self.localize(window.end, "market close")?;
+                Ok((open.with_timezone(&Utc), close.with_timezone(&Utc)))
+            })
+            .collect()
     }
 
-    /// Get the next time the market opens
-    pub fn next_open(&self) -> Result<DateTime<Tz>> {
-        let now = Utc::now().with_timezone(&self.timezone());
-        let mut date = now.date_naive();
-
-        if self.is_trading_day(date)? {
-            let hours = self.trading_hours(date);
-            if now.time() < hours.regular.start {
-                let dt = date.and_time(hours.regular.start);
-                return self
-                    .timezone()
-                    .from_local_datetime(&dt)
-                    .earliest()
-                    .ok_or_else(|| {
-                        CalendarError::InvalidTime(
-                            "Invalid timezone conversion for market open".to_string(),
-                        )
-                    });
-            }
+    /// Get the session in progress at an instant
+    ///
+    /// Returns `None` outside trading hours, during intraday breaks, and on
+    /// non-trading days. A session that opened on the previous calendar day
+    /// for the next trading date is found, as for
+    /// [`is_open_at`](Self::is_open_at).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the instant falls on a
+    /// market date outside [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market, SessionKind};
+    /// use chrono::DateTime;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let early = DateTime::parse_from_rfc3339("2025-03-10T05:00:00-04:00").unwrap();
+    /// assert_eq!(nyse.session_at(early)?, Some(SessionKind::PreMarket));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn session_at<T: TimeZone>(&self, instant: DateTime<T>) -> Result<Option<SessionKind>> {
+        let local = self.local_time(instant);
+        Ok(self.window_at(local)?.map(|(_, window)| window.kind))
+    }
+
+    /// Get the trading date and session an instant belongs to
+    ///
+    /// Sessions that open on the previous calendar day belong to the next
+    /// trading date: a Sunday-evening CME Globex fill is attributed to
+    /// Monday. Returns `None` outside trading hours, during intraday breaks,
+    /// and on non-trading days.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the instant falls on a
+    /// market date outside [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market, SessionKind};
+    /// use chrono::{DateTime, Duration, NaiveDate};
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let trade = DateTime::parse_from_rfc3339("2025-03-10T23:30:00Z").unwrap();
+    /// let location = nyse.classify(trade)?.unwrap(); // 19:30 in New York
+    /// assert_eq!(location.trading_date, NaiveDate::from_ymd_opt(2025, 3, 10).unwrap());
+    /// assert_eq!(location.session_kind, SessionKind::AfterHours);
+    /// assert_eq!(location.offset_from_open, Duration::minutes(210));
+    ///
+    /// let cme = TradingCalendar::new(Market::CME)?;
+    /// let fill = DateTime::parse_from_rfc3339("2025-03-09T23:00:00Z").unwrap();
+    /// let location = cme.classify(fill)?.unwrap(); // Sunday 18:00 in Chicago
+    /// assert_eq!(location.trading_date, NaiveDate::from_ymd_opt(2025, 3, 10).unwrap());
+    /// assert_eq!(location.session_kind, SessionKind::PreMarket);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn classify<T: TimeZone>(&self, instant: DateTime<T>) -> Result<Option<SessionLocation>> {
+        let instant = instant.with_timezone(&Utc);
+        let local = self.local_time(instant);
+        let Some((trading_date, window)) = self.window_at(local)? else {
+            return Ok(None);
+        };
+
+        let open = self
+            .trading_hours(trading_date)
+            .windows()
+            .into_iter()
+            .find(|first| first.kind == window.kind)
+            .map_or(window.start, |first| first.start);
+        // Measure in absolute time, across daylight saving changes
+        let offset_from_open = instant - self.localize(open, "session open")?.with_timezone(&Utc);
+
+        Ok(Some(SessionLocation {
+            trading_date,
+            session_kind: window.kind,
+            offset_from_open,
+        }))
+    }
+
+    /// Check if one of the given kinds of session is in progress at an instant
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the instant falls on a
+    /// market date outside [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market, SessionKind};
+    /// use chrono::DateTime;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let evening = DateTime::parse_from_rfc3339("2025-03-10T17:00:00-04:00").unwrap();
+    /// assert!(!nyse.is_open_at_for(evening, &[SessionKind::Regular])?);
+    /// assert!(nyse.is_open_at_for(evening, &SessionKind::ALL)?);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn is_open_at_for<T: TimeZone>(
+        &self,
+        instant: DateTime<T>,
+        kinds: &[SessionKind],
+    ) -> Result<bool> {
+        Ok(self
+            .session_at(instant)?
+            .is_some_and(|kind| kinds.contains(&kind)))
+    }
+
+    /// Get the first start of a kind of session strictly after an instant
+    ///
+    /// For [`SessionKind::Regular`] the end of an intraday break counts as
+    /// a start, as for [`next_open_after`](Self::next_open_after).
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::DateOutOfRange` if the search leaves
+    ///   [`supported_years`](Self::supported_years)
+    /// - `CalendarError::NoTradingDayFound` if the market does not hold that
+    ///   kind of session, e.g. pre-market trading on the TSE
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market, SessionKind};
+    /// use chrono::DateTime;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let friday = DateTime::parse_from_rfc3339("2025-03-07T12:00:00-05:00").unwrap();
+    /// let start = nyse.next_session_start(SessionKind::PreMarket, friday)?;
+    /// assert_eq!(start.to_rfc3339(), "2025-03-10T04:00:00-04:00");
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn next_session_start<T: TimeZone>(
+        &self,
+        kind: SessionKind,
+        after: DateTime<T>,
+    ) -> Result<DateTime<Tz>> {
+        let local = self.local_time(after);
+        let start = self.find_edge(local, kind, true, |window| window.start)?;
+        self.localize(start, "session start")
+    }
+
+    /// Get the session windows of a trading date
+    ///
+    /// Non-trading days have no windows.
+    fn session_windows(&self, date: NaiveDate) -> Result<Vec<SessionWindow>> {
+        if !self.is_trading_day(date)? {
+            return Ok(Vec::new());
         }
+        Ok(self.trading_hours(date).windows())
+    }
 
-        date = self.next_trading_day(date);
-        let hours = self.trading_hours(date);
-        let dt = date.and_time(hours.regular.start);
+    /// Get the trading date and session window containing a local time
+    ///
+    /// A trading date's sessions may start on the previous calendar day or
+    /// end on the next, so the trading dates either side are searched when
+    /// they are supported.
+    fn window_at(&self, local: NaiveDateTime) -> Result<Option<(NaiveDate, SessionWindow)>> {
+        let date = local.date();
+        self.check_date(date)?;
 
-        self.timezone()
-            .from_local_datetime(&dt)
-            .earliest()
-            .ok_or_else(|| {
-                CalendarError::InvalidTime(
-                    "Invalid timezone conversion for market open".to_string(),
-                )
-            })
+        for day in [date.pred_opt(), Some(date), date.succ_opt()]
+            .into_iter()
+            .flatten()
+            .filter(|day| self.check_date(*day).is_ok())
+        {
+            if let Some(window) = self
+                .session_windows(day)?
+                .into_iter()
+                .find(|window| window.contains(local))
+            {
+                return Ok(Some((day, window)));
+            }
+        }
+        Ok(None)
     }
 
-    /// Get the next time the market closes
-    pub fn next_close(&self) -> Result<DateTime<Tz>> {
-        let now = Utc::now().with_timezone(&self.timezone());
-        let mut date = now.date_naive();
-
-        // Check if market is open today
-        if self.is_trading_day(date)? {
-            let hours = self.trading_hours(date);
-            let close_time = hours.market_close();
-
-            if now.time() < close_time {
-                let dt = date.and_time(close_time);
-                return self
-                    .timezone()
-                    .from_local_datetime(&dt)
-                    .earliest()
-                    .ok_or_else(|| {
-                        CalendarError::InvalidTime(
-                            "Invalid timezone conversion for market close".to_string(),
-                        )
-                    });
+    /// Find the nearest session edge strictly after (`forward`) or before a
+    /// local time
+    ///
+    /// The search starts from the trading date on the other side of the
+    /// local date, whose sessions may cross midnight, and gives up after
+    /// `SESSION_SEARCH_DAYS` trading days.
+    fn find_edge(
+        &self,
+        local: NaiveDateTime,
+        kind: SessionKind,
+        forward: bool,
+        edge: fn(&SessionWindow) -> NaiveDateTime,
+    ) -> Result<NaiveDateTime> {
+        let step = Duration::days(if forward { 1 } else { -1 });
+        self.check_date(local.date())?;
+
+        let mut date = local.date() - step;
+        if self.check_date(date).is_err() {
+            date = local.date();
+        }
+
+        let mut trading_days = 0;
+        while trading_days <= SESSION_SEARCH_DAYS {
+            let windows = self.session_windows(date)?;
+            if !windows.is_empty() {
+                trading_days += 1;
+            }
+            let edges = windows
+                .iter()
+                .filter(|window| window.kind == kind)
+                .map(edge);
+            let found = if forward {
+                edges.filter(|at| *at > local).min()
+            } else {
+                edges.filter(|at| *at < local).max()
+            };
+            if let Some(at) = found {
+                return Ok(at);
             }
+            date += step;
         }
 
-        // Market is closed today, find next trading day
-        date = self.next_trading_day(date);
-        let hours = self.trading_hours(date);
-        let dt = date.and_time(hours.market_close());
+        Err(CalendarError::NoTradingDayFound)
+    }
+
+    /// Convert an instant into the market's local date and time
+    fn local_time<T: TimeZone>(&self, instant: DateTime<T>) -> NaiveDateTime {
+        instant.with_timezone(&self.timezone()).naive_local()
+    }
 
+    /// Convert a local market date and time into a timezone-aware instant
+    fn localize(&self, at: NaiveDateTime, what: &str) -> Result<DateTime<Tz>> {
         self.timezone()
-            .from_local_datetime(&dt)
+            .from_local_datetime(&at)
             .earliest()
             .ok_or_else(|| {
-                CalendarError::InvalidTime(
-                    "Invalid timezone conversion for market close".to_string(),
-                )
+                CalendarError::InvalidTime(format!("Invalid timezone conversion for {what}"))
             })
     }
 
@@ -216,35 +886,179 @@ impl TradingCalendar {
     /// # Ok::<(), trading_calendar::CalendarError>(())
     /// ```
     pub fn trading_days_in_month(&self, year: i32, month: u32) -> Result<Vec<NaiveDate>> {
-        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
-            return Err(CalendarError::DateOutOfRange(
-                NaiveDate::from_ymd_opt(year, month, 1).unwrap_or_default(),
-            ));
-        }
+        let (index, range) = self.month_index(year, month)?;
+        Ok(index.days()[range].to_vec())
+    }
 
-        let mut days = Vec::new();
-        let start = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
-            CalendarError::InvalidDateCalculation(format!("Invalid year/month: {year}/{month}"))
-        })?;
+    /// Get the `n`th trading day of a month, counting from 1
+    ///
+    /// Returns `None` if the month has fewer than `n` trading days (or `n`
+    /// is 0).
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::DateOutOfRange` if the year is outside
+    ///   [`supported_years`](Self::supported_years)
+    /// - `CalendarError::InvalidDateCalculation` if the month is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// // January 1st is a holiday
+    /// let first = nyse.nth_trading_day_of_month(2025, 1, 1)?;
+    /// assert_eq!(first, NaiveDate::from_ymd_opt(2025, 1, 2));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn nth_trading_day_of_month(
+        &self,
+        year: i32,
+        month: u32,
+        n: usize,
+    ) -> Result<Option<NaiveDate>> {
+        let (index, range) = self.month_index(year, month)?;
+        Ok(n.checked_sub(1)
+            .and_then(|offset| index.days()[range].get(offset).copied()))
+    }
 
-        let end = if month == 12 {
-            NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    /// Get the last trading day of a month
+    ///
+    /// Returns `None` if the market does not trade at all that month.
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::DateOutOfRange` if the year is outside
+    ///   [`supported_years`](Self::supported_years)
+    /// - `CalendarError::InvalidDateCalculation` if the month is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let lse = TradingCalendar::new(Market::LSE)?;
+    /// // May 26th is the Spring Bank Holiday
+    /// let last = lse.last_trading_day_of_month(2025, 5)?;
+    /// assert_eq!(last, NaiveDate::from_ymd_opt(2025, 5, 30));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn last_trading_day_of_month(&self, year: i32, month: u32) -> Result<Option<NaiveDate>> {
+        let (index, range) = self.month_index(year, month)?;
+        Ok(index.days()[range].last().copied())
+    }
+
+    /// Move a date by a number of trading days
+    ///
+    /// For positive `n` this is the `n`th trading day after `date`; for
+    /// negative `n` the `|n|`th trading day before it. `date` itself need
+    /// not be a trading day, and is returned unchanged when `n` is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if `date` or the result is
+    /// outside [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    ///
+    /// // Skips Christmas Day
+    /// assert_eq!(nyse.add_trading_days(date(12, 22), 5)?, date(12, 30));
+    /// assert_eq!(nyse.add_trading_days(date(12, 30), -5)?, date(12, 22));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn add_trading_days(&self, date: NaiveDate, n: i64) -> Result<NaiveDate> {
+        let mut year = date.year();
+        let mut index = self.year_index(year)?;
+        let out_of_range = |year: i32| {
+            CalendarError::DateOutOfRange(NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(date))
+        };
+
+        // Position in the year's trading days of the result; a step count
+        // too large to represent is beyond any supported year
+        let position = if n > 0 {
+            let after = index.before(date) + usize::from(index.contains(date));
+            (after as i64).checked_add(n - 1)
+        } else if n < 0 {
+            (index.before(date) as i64).checked_add(n)
         } else {
-            NaiveDate::from_ymd_opt(year, month + 1, 1)
+            return Ok(date);
+        };
+        let mut position = position.ok_or(CalendarError::DateOutOfRange(date))?;
+
+        while position >= index.len() as i64 {
+            position -= index.len() as i64;
+            year += 1;
+            index = self.year_index(year).map_err(|_| out_of_range(year))?;
         }
-        .ok_or_else(|| {
-            CalendarError::InvalidDateCalculation(format!("Invalid year/month: {year}/{month}"))
-        })?;
-
-        let mut current = start;
-        while current < end {
-            if self.is_trading_day(current)? {
-                days.push(current);
-            }
-            current += chrono::Duration::days(1);
+        while position < 0 {
+            year -= 1;
+            index = self.year_index(year).map_err(|_| out_of_range(year))?;
+            position += index.len() as i64;
+        }
+        Ok(index.days()[position as usize])
+    }
+
+    /// Move a date back by a number of trading days
+    ///
+    /// Equivalent to [`add_trading_days(date, -n)`](Self::add_trading_days).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if `date` or the result is
+    /// outside [`supported_years`](Self::supported_years).
+    pub fn sub_trading_days(&self, date: NaiveDate, n: i64) -> Result<NaiveDate> {
+        let n = n.checked_neg().ok_or(CalendarError::DateOutOfRange(date))?;
+        self.add_trading_days(date, n)
+    }
+
+    /// Count the trading days from `start` up to but excluding `end`
+    ///
+    /// The range is half-open, `[start, end)`, so that
+    /// `add_trading_days(start, trading_days_between(start, end)?)` is `end`
+    /// whenever both are trading days. If `end` is before `start` the count
+    /// of `[end, start)` is returned negated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if either date is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{TradingCalendar, Market};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    /// let friday = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// assert_eq!(nyse.trading_days_between(monday, friday)?, 4);
+    /// assert_eq!(nyse.trading_days_between(friday, monday)?, -4);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn trading_days_between(&self, start: NaiveDate, end: NaiveDate) -> Result<i64> {
+        self.check_date(start)?;
+        self.check_date(end)?;
+        if end < start {
+            return self.trading_days_between(end, start).map(|count| -count);
         }
 
-        Ok(days)
+        let mut count = -(self.year_index(start.year())?.before(start) as i64);
+        for year in start.year()..end.year() {
+            count += self.year_index(year)?.len() as i64;
+        }
+        count += self.year_index(end.year())?.before(end) as i64;
+        Ok(count)
     }
 
     /// Count trading days between two dates (inclusive)
@@ -263,21 +1077,12 @@ impl TradingCalendar {
     /// # Ok::<(), trading_calendar::CalendarError>(())
     /// ```
     pub fn count_trading_days(&self, start: NaiveDate, end: NaiveDate) -> Result<usize> {
-        if start.year() < MIN_YEAR || end.year() > MAX_YEAR {
-            return Err(CalendarError::DateOutOfRange(start));
+        let between = self.trading_days_between(start, end)?;
+        if between < 0 {
+            return Ok(0);
         }
-
-        let mut count = 0;
-        let mut current = start;
-
-        while current <= end {
-            if self.is_trading_day(current)? {
-                count += 1;
-            }
-            current += chrono::Duration::days(1);
-        }
-
-        Ok(count)
+        let last = usize::from(self.year_index(end.year())?.contains(end));
+        Ok(between as usize + last)
     }
 }
 
@@ -290,6 +1095,8 @@ impl Default for TradingCalendar {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Session;
+    use chrono::NaiveTime;
 
     #[test]
     fn test_trading_calendar_creation() {
@@ -335,13 +1142,219 @@ mod tests {
         assert_eq!(next, NaiveDate::from_ymd_opt(2025, 1, 7).unwrap()); // Tuesday
     }
 
+    #[test]
+    fn test_as_of_queries() {
+        let tse = TradingCalendar::new(Market::TSE).unwrap();
+        let tokyo = |d: u32, h: u32, m: u32| {
+            chrono_tz::Asia::Tokyo
+                .with_ymd_and_hms(2025, 3, d, h, m, 0)
+                .unwrap()
+        };
+
+        // Friday morning session, lunch break and afternoon session
+        assert!(tse.is_open_at(tokyo(7, 10, 0)).unwrap());
+        assert!(!tse.is_open_at(tokyo(7, 12, 0)).unwrap());
+        assert_eq!(
+            tse.next_close_after(tokyo(7, 10, 0)).unwrap(),
+            tokyo(7, 11, 30)
+        );
+        assert_eq!(
+            tse.next_open_after(tokyo(7, 12, 0)).unwrap(),
+            tokyo(7, 12, 30)
+        );
+        assert_eq!(
+            tse.previous_close_before(tokyo(7, 12, 0)).unwrap(),
+            tokyo(7, 11, 30)
+        );
+        assert_eq!(
+            tse.previous_open_before(tokyo(7, 14, 0)).unwrap(),
+            tokyo(7, 12, 30)
+        );
+
+        // Over the weekend, and exactly at an open
+        assert_eq!(
+            tse.next_open_after(tokyo(8, 10, 0)).unwrap(),
+            tokyo(10, 9, 0)
+        );
+        assert_eq!(
+            tse.next_open_after(tokyo(10, 9, 0)).unwrap(),
+            tokyo(10, 12, 30)
+        );
+        assert_eq!(
+            tse.previous_open_before(tokyo(10, 9, 0)).unwrap(),
+            tokyo(7, 12, 30)
+        );
+        assert_eq!(
+            tse.previous_close_before(tokyo(9, 10, 0)).unwrap(),
+            tokyo(7, 15, 30)
+        );
+
+        // Instants in other timezones are converted to market time
+        let utc = Utc.with_ymd_and_hms(2025, 3, 7, 1, 0, 0).unwrap();
+        assert!(tse.is_open_at(utc).unwrap());
+    }
+
+    #[test]
+    fn test_session_queries() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+        let new_york = |m: u32, d: u32, h: u32, min: u32| {
+            chrono_tz::America::New_York
+                .with_ymd_and_hms(2025, m, d, h, min, 0)
+                .unwrap()
+        };
+
+        assert_eq!(nyse.session_at(new_york(3, 10, 3, 0)).unwrap(), None);
+        assert_eq!(
+            nyse.session_at(new_york(3, 10, 16, 0)).unwrap(),
+            Some(SessionKind::AfterHours)
+        );
+        // After-hours trading begins at the early close on half days
+        assert_eq!(
+            nyse.session_at(new_york(11, 28, 13, 30)).unwrap(),
+            Some(SessionKind::AfterHours)
+        );
+        assert_eq!(
+            nyse.next_session_start(SessionKind::AfterHours, new_york(11, 28, 10, 0))
+                .unwrap(),
+            new_york(11, 28, 13, 0)
+        );
+        assert!(!nyse
+            .is_open_at_for(new_york(3, 10, 5, 0), &[SessionKind::Regular])
+            .unwrap());
+
+        // The TSE has no extended sessions
+        let tse = TradingCalendar::new(Market::TSE).unwrap();
+        assert!(matches!(
+            tse.next_session_start(SessionKind::PreMarket, new_york(3, 10, 3, 0)),
+            Err(CalendarError::NoTradingDayFound)
+        ));
+    }
+
+    #[test]
+    fn test_overnight_sessions() {
+        let cme = TradingCalendar::new(Market::CME).unwrap();
+        let chicago = |m: u32, d: u32, h: u32, min: u32| {
+            chrono_tz::America::Chicago
+                .with_ymd_and_hms(2025, m, d, h, min, 0)
+                .unwrap()
+        };
+
+        // Monday's session opens on Sunday evening, not Friday evening
+        assert!(!cme.is_open_at(chicago(3, 7, 18, 0)).unwrap());
+        assert!(!cme.is_open_at(chicago(3, 9, 16, 0)).unwrap());
+        assert_eq!(
+            cme.session_at(chicago(3, 9, 18, 0)).unwrap(),
+            Some(SessionKind::PreMarket)
+        );
+        assert_eq!(
+            cme.next_session_start(SessionKind::PreMarket, chicago(3, 7, 12, 0))
+                .unwrap(),
+            chicago(3, 9, 17, 0)
+        );
+        // The daily maintenance break
+        assert!(!cme.is_open_at(chicago(3, 10, 16, 30)).unwrap());
+        assert!(cme.is_open_at(chicago(3, 10, 17, 0)).unwrap());
+        assert_eq!(
+            cme.previous_close_before(chicago(3, 10, 7, 0)).unwrap(),
+            chicago(3, 7, 15, 15)
+        );
+
+        // Globex halts at noon on Martin Luther King Jr. Day and reopens
+        // that evening for Tuesday
+        assert!(!cme.is_open_at(chicago(1, 20, 13, 0)).unwrap());
+        assert!(cme.is_open_at(chicago(1, 20, 18, 0)).unwrap());
+
+        // Blue Ocean-style overnight trading ahead of the NYSE session
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let overnight = TradingCalendar::builder(Market::NYSE)
+            .pre_market(Some(Session::new(time(20, 0), time(4, 0)).unwrap()))
+            .build()
+            .unwrap();
+        let new_york = |m: u32, d: u32, h: u32| {
+            chrono_tz::America::New_York
+                .with_ymd_and_hms(2025, m, d, h, 0, 0)
+                .unwrap()
+        };
+        assert!(overnight.is_open_at(new_york(3, 9, 21)).unwrap()); // Sunday
+        assert!(!overnight.is_open_at(new_york(3, 7, 21)).unwrap()); // Friday
+        assert!(overnight.is_open_at(new_york(3, 10, 3)).unwrap());
+    }
+
+    #[test]
+    fn test_classify() {
+        let tse = TradingCalendar::new(Market::TSE).unwrap();
+        let tokyo = |d: u32, h: u32, min: u32| {
+            chrono_tz::Asia::Tokyo
+                .with_ymd_and_hms(2025, 3, d, h, min, 0)
+                .unwrap()
+        };
+
+        // The afternoon session is measured from the morning open
+        let location = tse.classify(tokyo(10, 13, 0)).unwrap().unwrap();
+        assert_eq!(location.session_kind, SessionKind::Regular);
+        assert_eq!(location.offset_from_open, Duration::hours(4));
+        assert_eq!(tse.classify(tokyo(10, 12, 0)).unwrap(), None); // lunch
+        assert_eq!(tse.classify(tokyo(8, 10, 0)).unwrap(), None); // Saturday
+
+        // A fill in the Thanksgiving evening session belongs to Friday
+        let cme = TradingCalendar::new(Market::CME).unwrap();
+        let fill = chrono_tz::America::Chicago
+            .with_ymd_and_hms(2025, 11, 27, 20, 0, 0)
+            .unwrap();
+        let location = cme.classify(fill).unwrap().unwrap();
+        assert_eq!(
+            location.trading_date,
+            NaiveDate::from_ymd_opt(2025, 11, 28).unwrap()
+        );
+        assert_eq!(location.offset_from_open, Duration::hours(3));
+
+        let calendar = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+        assert!(calendar
+            .classify(Utc.with_ymd_and_hms(2031, 1, 2, 15, 0, 0).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_as_of_queries_range() {
+        let calendar = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+        let new_years_eve = Utc.with_ymd_and_hms(2030, 12, 31, 22, 0, 0).unwrap();
+        assert!(calendar.next_open_after(new_years_eve).is_err());
+        assert!(calendar
+            .is_open_at(Utc.with_ymd_and_hms(2031, 1, 2, 15, 0, 0).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_clock() {
+        use crate::clock::ManualClock;
+        use std::sync::Arc;
+
+        // Saturday afternoon in New York
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2025, 1, 4, 18, 0, 0).unwrap(),
+        ));
+        let calendar = TradingCalendar::new(Market::NYSE)
+            .unwrap()
+            .with_clock(Arc::clone(&clock));
+        let monday_open = calendar.next_open().unwrap();
+        assert_eq!(monday_open.to_rfc3339(), "2025-01-06T09:30:00-05:00");
+        assert!(!calendar.is_open_now().unwrap());
+
+        clock.set(monday_open.with_timezone(&Utc));
+        assert!(calendar.is_open_now().unwrap());
+        assert_eq!(
+            calendar.next_close().unwrap().to_rfc3339(),
+            "2025-01-06T16:00:00-05:00"
+        );
+    }
+
     #[test]
     fn test_year_range_validation() {
         let calendar = TradingCalendar::new(Market::NYSE).unwrap();
 
         // Test dates outside supported range
-        let early_date = NaiveDate::from_ymd_opt(2019, 1, 1).unwrap();
-        let late_date = NaiveDate::from_ymd_opt(2031, 1, 1).unwrap();
+        let early_date = NaiveDate::from_ymd_opt(1989, 1, 1).unwrap();
+        let late_date = NaiveDate::from_ymd_opt(2101, 1, 1).unwrap();
 
         assert!(calendar.is_trading_day(early_date).is_err());
         assert!(calendar.is_holiday(early_date).is_err());
@@ -353,4 +1366,21 @@ mod tests {
         assert!(calendar.is_trading_day(valid_date).is_ok());
         assert!(calendar.is_holiday(valid_date).is_ok());
     }
+
+    #[test]
+    fn test_configured_year_range() {
+        let calendar = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+        assert_eq!(calendar.supported_years(), 2020..=2030);
+        assert!(calendar
+            .is_trading_day(NaiveDate::from_ymd_opt(2019, 12, 31).unwrap())
+            .is_err());
+        assert!(calendar.holidays(2031).is_err());
+
+        // Ranges must lie within the market's rules and must not be empty
+        assert!(TradingCalendar::with_year_range(Market::NYSE, 1900..=2000).is_err());
+        assert!(
+            TradingCalendar::with_year_range(Market::NYSE, RangeInclusive::new(2030, 2020))
+                .is_err()
+        );
+    }
 }
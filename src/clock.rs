@@ -0,0 +1,110 @@
+//! Time sources for the `_now` queries
+//!
+//! [`TradingCalendar::is_open_now`](crate::TradingCalendar::is_open_now),
+//! [`next_open`](crate::TradingCalendar::next_open) and
+//! [`next_close`](crate::TradingCalendar::next_close) read the current time
+//! from the calendar's [`Clock`]. Calendars use [`SystemClock`] unless given
+//! another with [`TradingCalendar::with_clock`](crate::TradingCalendar::with_clock),
+//! which lets tests and replays drive them with a [`ManualClock`].
+
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex};
+
+/// A source of the current time
+pub trait Clock: Send + Sync {
+    /// The current instant
+    fn now(&self) -> DateTime<Utc>;
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+/// The system's wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to
+///
+/// Share it with a calendar through an [`Arc`] to move time under it.
+///
+/// # Examples
+///
+/// ```
+/// use trading_calendar::clock::ManualClock;
+/// use trading_calendar::{Market, TradingCalendar};
+/// use chrono::{Duration, TimeZone, Utc};
+/// use std::sync::Arc;
+///
+/// // Sunday, 10:00 in New York
+/// let clock = Arc::new(ManualClock::new(Utc.with_ymd_and_hms(2025, 3, 9, 14, 0, 0).unwrap()));
+/// let nyse = TradingCalendar::new(Market::NYSE)?.with_clock(Arc::clone(&clock));
+/// assert!(!nyse.is_open_now()?);
+///
+/// clock.advance(Duration::days(1));
+/// assert!(nyse.is_open_now()?);
+/// # Ok::<(), trading_calendar::CalendarError>(())
+/// ```
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    /// Create a clock stopped at `now`
+    pub fn new(now: DateTime<Utc>) -> Self {
+        ManualClock {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Move the clock to `now`
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.lock() = now;
+    }
+
+    /// Move the clock forward (or back, for a negative duration)
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        // The guarded value is always valid, so a poisoned lock is harmless
+        self.now
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_manual_clock() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 2, 14, 30, 0).unwrap();
+        let clock = Arc::new(ManualClock::new(start));
+        let shared: Arc<dyn Clock> = clock.clone();
+
+        clock.advance(Duration::minutes(90));
+        assert_eq!(shared.now(), start + Duration::minutes(90));
+
+        clock.set(start);
+        assert_eq!(shared.now(), start);
+    }
+}
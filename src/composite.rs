@@ -0,0 +1,521 @@
+//! Joint calendars across several markets
+//!
+//! A [`CompositeCalendar`] answers the usual calendar questions for a group
+//! of markets: with [`JoinRule::AllOpen`] a day is a trading day only if
+//! every market trades, with [`JoinRule::AnyOpen`] if at least one does.
+//! Intraday windows are computed in UTC so markets in different timezones
+//! line up correctly.
+//!
+//! # Example
+//!
+//! ```
+//! use trading_calendar::composite::{CompositeCalendar, JoinRule};
+//! use trading_calendar::Market;
+//! use chrono::NaiveDate;
+//!
+//! let both = CompositeCalendar::from_markets(&[Market::NYSE, Market::TSX], JoinRule::AllOpen)?;
+//! // Canada Day: NYSE trades, TSX does not
+//! assert!(!both.is_trading_day(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap())?);
+//!
+//! let either = CompositeCalendar::from_markets(&[Market::NYSE, Market::TSX], JoinRule::AnyOpen)?;
+//! assert!(either.is_trading_day(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap())?);
+//! # Ok::<(), trading_calendar::CalendarError>(())
+//! ```
+
+use crate::{CalendarError, Market, Result, TradingCalendar};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use std::ops::RangeInclusive;
+
+/// Days [`CompositeCalendar`] searches before concluding the markets never
+/// trade together
+const MAX_SEARCH_DAYS: usize = 366;
+
+/// How the markets of a [`CompositeCalendar`] combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum JoinRule {
+    /// Open only when every market is open
+    AllOpen,
+    /// Open when at least one market is open
+    AnyOpen,
+}
+
+/// A time interval in UTC, from open to close
+pub type UtcWindow = (DateTime<Utc>, DateTime<Utc>);
+
+/// A calendar combining several markets
+pub struct CompositeCalendar {
+    calendars: Vec<TradingCalendar>,
+    rule: JoinRule,
+    years: RangeInclusive<i32>,
+}
+
+impl CompositeCalendar {
+    /// Combine calendars under a join rule
+    ///
+    /// The composite supports the years every calendar supports.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if no calendars are
+    /// given or their supported years do not overlap.
+    pub fn new(calendars: Vec<TradingCalendar>, rule: JoinRule) -> Result<Self> {
+        let years = calendars
+            .iter()
+            .map(TradingCalendar::supported_years)
+            .reduce(|a, b| *a.start().max(b.start())..=*a.end().min(b.end()))
+            .ok_or_else(|| {
+                CalendarError::InvalidConfiguration(
+                    "A composite calendar needs at least one market".to_string(),
+                )
+            })?;
+        if years.is_empty() {
+            return Err(CalendarError::InvalidConfiguration(
+                "The calendars' supported years do not overlap".to_string(),
+            ));
+        }
+        Ok(CompositeCalendar {
+            calendars,
+            rule,
+            years,
+        })
+    }
+
+    /// Combine the default calendars of several markets
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if no markets are
+    /// given or a market's calendar cannot be created.
+    pub fn from_markets(markets: &[Market], rule: JoinRule) -> Result<Self> {
+        let calendars = markets
+            .iter()
+            .map(|market| TradingCalendar::new(*market))
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(calendars, rule)
+    }
+
+    /// Get the combined calendars
+    pub fn calendars(&self) -> &[TradingCalendar] {
+        &self.calendars
+    }
+
+    /// Get the join rule
+    pub fn rule(&self) -> JoinRule {
+        self.rule
+    }
+
+    /// Get the years every combined calendar supports
+    pub fn supported_years(&self) -> RangeInclusive<i32> {
+        self.years.clone()
+    }
+
+    /// Check if a date is a trading day under the join rule
+    ///
+    /// Dates are compared as calendar dates in each market's own timezone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    pub fn is_trading_day(&self, date: NaiveDate) -> Result<bool> {
+        let mut trading = self
+            .calendars
+            .iter()
+            .map(|calendar| calendar.is_trading_day(date));
+        match self.rule {
+            // Every calendar is asked so that none of their errors is skipped
+            JoinRule::AllOpen => trading.try_fold(true, |all, day| Ok(day? && all)),
+            JoinRule::AnyOpen => trading.try_fold(false, |any, day| Ok(day? || any)),
+        }
+    }
+
+    /// Get the next trading day after a date under the join rule
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::DateOutOfRange` if the search leaves
+    ///   [`supported_years`](Self::supported_years)
+    /// - `CalendarError::NoTradingDayFound` if the markets do not trade
+    ///   together within a year
+    pub fn next_trading_day(&self, date: NaiveDate) -> Result<NaiveDate> {
+        self.search(date, Duration::days(1))
+    }
+
+    /// Get the previous trading day before a date under the join rule
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::DateOutOfRange` if the search leaves
+    ///   [`supported_years`](Self::supported_years)
+    /// - `CalendarError::NoTradingDayFound` if the markets do not trade
+    ///   together within a year
+    pub fn previous_trading_day(&self, date: NaiveDate) -> Result<NaiveDate> {
+        self.search(date, Duration::days(-1))
+    }
+
+    fn search(&self, mut date: NaiveDate, step: Duration) -> Result<NaiveDate> {
+        for _ in 0..MAX_SEARCH_DAYS {
+            date += step;
+            if self.is_trading_day(date)? {
+                return Ok(date);
+            }
+        }
+        Err(CalendarError::NoTradingDayFound)
+    }
+
+    /// Count trading days between two dates (inclusive) under the join rule
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if either date is outside
+    /// [`supported_years`](Self::supported_years).
+    pub fn count_trading_days(&self, start: NaiveDate, end: NaiveDate) -> Result<usize> {
+        // Check both ends before counting, as the single calendars do
+        self.is_trading_day(start)?;
+        self.is_trading_day(end)?;
+
+        let mut count = 0;
+        for year in start.year()..=end.year() {
+            // Each market's trading days of the year within the range
+            let days = self
+                .calendars
+                .iter()
+                .map(|calendar| {
+                    let index = calendar.year_index(year)?;
+                    let from = if year == start.year() {
+                        index.before(start)
+                    } else {
+                        0
+                    };
+                    let to = if year == end.year() {
+                        index.before(end) + usize::from(index.contains(end))
+                    } else {
+                        index.len()
+                    };
+                    Ok((index, from..to.max(from)))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            count += match self.rule {
+                JoinRule::AllOpen => {
+                    let (first, rest) = days.split_first().expect("At least one market");
+                    first.0.days()[first.1.clone()]
+                        .iter()
+                        .filter(|date| rest.iter().all(|(index, _)| index.contains(**date)))
+                        .count()
+                }
+                JoinRule::AnyOpen => {
+                    let mut union: Vec<NaiveDate> = days
+                        .iter()
+                        .flat_map(|(index, range)| index.days()[range.clone()].iter().copied())
+                        .collect();
+                    union.sort_unstable();
+                    union.dedup();
+                    union.len()
+                }
+            };
+        }
+        Ok(count)
+    }
+
+    /// Check if the markets are in regular session at an instant under the
+    /// join rule
+    ///
+    /// Unlike [`TradingCalendar::is_open_at`], extended hours do not count.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the instant falls on a
+    /// market date outside the supported years.
+    pub fn is_in_regular_session_at<T: TimeZone>(&self, instant: DateTime<T>) -> Result<bool> {
+        let instant = instant.with_timezone(&Utc);
+        let mut open = self
+            .calendars
+            .iter()
+            .map(|calendar| calendar.is_open_at_for(instant, &[crate::SessionKind::Regular]));
+        match self.rule {
+            // Every calendar is asked so that none of their errors is skipped
+            JoinRule::AllOpen => open.try_fold(true, |all, day| Ok(day? && all)),
+            JoinRule::AnyOpen => open.try_fold(false, |any, day| Ok(day? || any)),
+        }
+    }
+
+    /// Get the UTC windows of a date in which the join rule holds
+    ///
+    /// Each market contributes its regular session on `date` in its own
+    /// timezone, so the windows of far-apart markets need not overlap even
+    /// when both trade that date. Under [`JoinRule::AllOpen`] the windows are
+    /// the overlap of every market's session, under [`JoinRule::AnyOpen`]
+    /// their union.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::composite::{CompositeCalendar, JoinRule};
+    /// use trading_calendar::Market;
+    /// use chrono::NaiveDate;
+    ///
+    /// let both = CompositeCalendar::from_markets(&[Market::LSE, Market::NYSE], JoinRule::AllOpen)?;
+    /// let windows = both.open_windows(NaiveDate::from_ymd_opt(2025, 6, 2).unwrap())?;
+    /// // 09:30 New York to 16:30 London
+    /// assert_eq!(windows[0].0.to_rfc3339(), "2025-06-02T13:30:00+00:00");
+    /// assert_eq!(windows[0].1.to_rfc3339(), "2025-06-02T15:30:00+00:00");
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn open_windows(&self, date: NaiveDate) -> Result<Vec<UtcWindow>> {
+        Ok(match self.rule {
+            JoinRule::AllOpen => intersect_all(self.regular_sessions_utc(date)?),
+            JoinRule::AnyOpen => union_all(self.regular_sessions_utc(date)?),
+        })
+    }
+
+    /// Get the UTC windows of a date in which every market is in regular
+    /// session, whatever the join rule
+    ///
+    /// Sessions are placed in UTC from each market's own timezone, so the
+    /// weeks in which the US and Europe are on different daylight saving
+    /// time widen or narrow the overlap by an hour. Early closes shorten
+    /// the overlap and intraday breaks split it. A date on which any market
+    /// is closed has no overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::composite::{CompositeCalendar, JoinRule};
+    /// use trading_calendar::Market;
+    /// use chrono::NaiveDate;
+    ///
+    /// let fx = CompositeCalendar::from_markets(&[Market::LSE, Market::NYSE], JoinRule::AnyOpen)?;
+    ///
+    /// // New York is on summer time, London is not yet: three hours
+    /// let windows = fx.overlapping_sessions(NaiveDate::from_ymd_opt(2025, 3, 17).unwrap())?;
+    /// assert_eq!(windows[0].0.to_rfc3339(), "2025-03-17T13:30:00+00:00");
+    /// assert_eq!(windows[0].1.to_rfc3339(), "2025-03-17T16:30:00+00:00");
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn overlapping_sessions(&self, date: NaiveDate) -> Result<Vec<UtcWindow>> {
+        Ok(intersect_all(self.regular_sessions_utc(date)?))
+    }
+
+    /// Each market's regular session segments on a date, in UTC
+    fn regular_sessions_utc(&self, date: NaiveDate) -> Result<Vec<Vec<UtcWindow>>> {
+        self.calendars
+            .iter()
+            .map(|calendar| calendar.regular_sessions_utc(date))
+            .collect()
+    }
+}
+
+/// Sort windows and merge those that overlap or touch
+fn union_all(sessions: Vec<Vec<UtcWindow>>) -> Vec<UtcWindow> {
+    let mut windows: Vec<UtcWindow> = sessions.into_iter().flatten().collect();
+    windows.sort();
+    let mut merged: Vec<UtcWindow> = Vec::with_capacity(windows.len());
+    for (start, end) in windows {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Keep the times covered by a window of every list
+fn intersect_all(sessions: Vec<Vec<UtcWindow>>) -> Vec<UtcWindow> {
+    let mut sessions = sessions.into_iter();
+    let Some(first) = sessions.next() else {
+        return Vec::new();
+    };
+    sessions.fold(union_all(vec![first]), |overlap, windows| {
+        let windows = union_all(vec![windows]);
+        let mut result = Vec::new();
+        for a in &overlap {
+            for b in &windows {
+                let start = a.0.max(b.0);
+                let end = a.1.min(b.1);
+                if start < end {
+                    result.push((start, end));
+                }
+            }
+        }
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{date, time};
+    use crate::Session;
+
+    #[test]
+    fn test_join_rules() {
+        let markets = [Market::NYSE, Market::LSE];
+        let all = CompositeCalendar::from_markets(&markets, JoinRule::AllOpen).unwrap();
+        let any = CompositeCalendar::from_markets(&markets, JoinRule::AnyOpen).unwrap();
+
+        // Thanksgiving (NYSE closed) and the Early May Bank Holiday (LSE closed)
+        for day in [date(2025, 11, 27), date(2025, 5, 5)] {
+            assert!(!all.is_trading_day(day).unwrap());
+            assert!(any.is_trading_day(day).unwrap());
+        }
+        assert!(!any.is_trading_day(date(2025, 12, 25)).unwrap());
+
+        assert_eq!(
+            all.next_trading_day(date(2025, 5, 2)).unwrap(),
+            date(2025, 5, 6)
+        );
+        assert_eq!(
+            any.next_trading_day(date(2025, 5, 2)).unwrap(),
+            date(2025, 5, 5)
+        );
+        assert_eq!(
+            all.previous_trading_day(date(2025, 5, 6)).unwrap(),
+            date(2025, 5, 2)
+        );
+
+        // Days either market trades, counted once
+        let (start, end) = (date(2025, 1, 1), date(2025, 12, 31));
+        let single: usize = all
+            .calendars()
+            .iter()
+            .map(|calendar| calendar.count_trading_days(start, end).unwrap())
+            .sum();
+        assert_eq!(
+            all.count_trading_days(start, end).unwrap()
+                + any.count_trading_days(start, end).unwrap(),
+            single
+        );
+
+        // Across a year boundary, matching a day-by-day walk
+        let (start, end) = (date(2024, 12, 20), date(2025, 1, 10));
+        for composite in [&all, &any] {
+            let walked = start
+                .iter_days()
+                .take_while(|day| *day <= end)
+                .filter(|day| composite.is_trading_day(*day).unwrap())
+                .count();
+            assert_eq!(composite.count_trading_days(start, end).unwrap(), walked);
+            assert_eq!(composite.count_trading_days(end, start).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_open_windows() {
+        let markets = [Market::LSE, Market::TSE];
+        let all = CompositeCalendar::from_markets(&markets, JoinRule::AllOpen).unwrap();
+        let any = CompositeCalendar::from_markets(&markets, JoinRule::AnyOpen).unwrap();
+
+        // Tokyo closes (06:30 UTC) before London opens (07:00 UTC in summer)
+        let day = date(2025, 6, 2);
+        assert!(all.is_trading_day(day).unwrap());
+        assert!(all.open_windows(day).unwrap().is_empty());
+
+        // Morning, afternoon and London sessions stay separate
+        let windows = any.open_windows(day).unwrap();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[2].0.to_rfc3339(), "2025-06-02T07:00:00+00:00");
+
+        let instant = Utc.with_ymd_and_hms(2025, 6, 2, 8, 0, 0).unwrap();
+        assert!(any.is_in_regular_session_at(instant).unwrap());
+        assert!(!all.is_in_regular_session_at(instant).unwrap());
+    }
+
+    #[test]
+    fn test_composite_years() {
+        let calendars = vec![
+            TradingCalendar::with_year_range(Market::NYSE, 2000..=2030).unwrap(),
+            TradingCalendar::with_year_range(Market::LSE, 2020..=2040).unwrap(),
+        ];
+        let composite = CompositeCalendar::new(calendars, JoinRule::AllOpen).unwrap();
+        assert_eq!(composite.supported_years(), 2020..=2030);
+        assert!(composite.is_trading_day(date(2031, 1, 2)).is_err());
+        // Christmas is decisive for NYSE, but LSE does not cover 2015
+        assert!(composite.is_trading_day(date(2015, 12, 25)).is_err());
+        let christmas = Utc.with_ymd_and_hms(2015, 12, 25, 15, 0, 0).unwrap();
+        assert!(composite.is_in_regular_session_at(christmas).is_err());
+
+        assert!(CompositeCalendar::new(Vec::new(), JoinRule::AnyOpen).is_err());
+    }
+
+    #[test]
+    fn test_overlap_across_dst_transitions() {
+        let fx = CompositeCalendar::from_markets(&[Market::LSE, Market::NYSE], JoinRule::AllOpen)
+            .unwrap();
+        let hours = |day| {
+            let windows = fx.overlapping_sessions(day).unwrap();
+            assert_eq!(windows.len(), 1);
+            let (start, end) = windows[0];
+            (start.format("%H:%M").to_string(), (end - start).num_hours())
+        };
+
+        // Both on winter time, then New York moves first (March 9th 2025)
+        assert_eq!(hours(date(2025, 3, 7)), ("14:30".to_string(), 2));
+        assert_eq!(hours(date(2025, 3, 10)), ("13:30".to_string(), 3));
+        assert_eq!(hours(date(2025, 3, 28)), ("13:30".to_string(), 3));
+        // London follows on March 30th
+        assert_eq!(hours(date(2025, 3, 31)), ("13:30".to_string(), 2));
+        // London goes back first (October 26th), New York on November 2nd
+        assert_eq!(hours(date(2025, 10, 27)), ("13:30".to_string(), 3));
+        assert_eq!(hours(date(2025, 11, 3)), ("14:30".to_string(), 2));
+    }
+
+    #[test]
+    fn test_overlap_early_closes_and_breaks() {
+        let fx = CompositeCalendar::from_markets(&[Market::LSE, Market::NYSE], JoinRule::AnyOpen)
+            .unwrap();
+        // LSE closes at 12:30 on Christmas Eve, before New York opens
+        assert!(fx
+            .overlapping_sessions(date(2025, 12, 24))
+            .unwrap()
+            .is_empty());
+        // Thanksgiving: NYSE closed
+        assert!(fx
+            .overlapping_sessions(date(2025, 11, 27))
+            .unwrap()
+            .is_empty());
+
+        // A late-opening Tokyo calendar still shares the lunch break
+        let late = TradingCalendar::builder(Market::TSE)
+            .regular_session(Session::new(time(10, 0), time(14, 0)).unwrap())
+            .build()
+            .unwrap();
+        let tokyo = CompositeCalendar::new(
+            vec![TradingCalendar::new(Market::TSE).unwrap(), late],
+            JoinRule::AllOpen,
+        )
+        .unwrap();
+        let windows: Vec<_> = tokyo
+            .overlapping_sessions(date(2025, 6, 2))
+            .unwrap()
+            .iter()
+            .map(|(start, end)| {
+                (
+                    start.format("%H:%M").to_string(),
+                    end.format("%H:%M").to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            windows,
+            [
+                ("01:00".to_string(), "02:30".to_string()),
+                ("03:30".to_string(), "05:00".to_string()),
+            ]
+        );
+    }
+}
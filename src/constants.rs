@@ -3,37 +3,222 @@
 //! # Safety
 //!
 //! All time constants in this module are hardcoded and known to be valid.
-//! The unwrap() calls are safe because these are compile-time constants
-//! with values that are guaranteed to be valid NaiveTime instances.
+//! They are built through the `const fn` helpers [`time`] and [`date`],
+//! which panic at compile time if a constant is ever given an out-of-range
+//! value.
 
-use chrono::NaiveTime;
+use chrono::{NaiveDate, NaiveTime};
+
+/// Build a `NaiveTime` constant (evaluated at compile time)
+pub(crate) const fn time(hour: u32, min: u32) -> NaiveTime {
+    match NaiveTime::from_hms_opt(hour, min, 0) {
+        Some(t) => t,
+        None => panic!("invalid market time constant"),
+    }
+}
+
+/// Build a `NaiveDate` constant (evaluated at compile time)
+pub(crate) const fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(d) => d,
+        None => panic!("invalid market date constant"),
+    }
+}
 
 // US Market Times
-pub const US_REGULAR_OPEN: NaiveTime = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+pub const US_REGULAR_OPEN: NaiveTime = time(9, 30);
 
-pub const US_REGULAR_CLOSE: NaiveTime = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+pub const US_REGULAR_CLOSE: NaiveTime = time(16, 0);
 
-pub const US_PREMARKET_OPEN: NaiveTime = NaiveTime::from_hms_opt(4, 0, 0).unwrap();
+pub const US_PREMARKET_OPEN: NaiveTime = time(4, 0);
 
-pub const US_AFTERHOURS_CLOSE: NaiveTime = NaiveTime::from_hms_opt(20, 0, 0).unwrap();
+pub const US_AFTERHOURS_CLOSE: NaiveTime = time(20, 0);
 
-pub const US_EARLY_CLOSE: NaiveTime = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+pub const US_EARLY_CLOSE: NaiveTime = time(13, 0);
 
 // UK Market Times
-pub const UK_REGULAR_OPEN: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+pub const UK_REGULAR_OPEN: NaiveTime = time(8, 0);
 
-pub const UK_REGULAR_CLOSE: NaiveTime = NaiveTime::from_hms_opt(16, 30, 0).unwrap();
+pub const UK_REGULAR_CLOSE: NaiveTime = time(16, 30);
 
-pub const UK_EARLY_CLOSE: NaiveTime = NaiveTime::from_hms_opt(12, 30, 0).unwrap();
+pub const UK_EARLY_CLOSE: NaiveTime = time(12, 30);
 
 // Japan Market Times
-pub const JP_REGULAR_OPEN: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+pub const JP_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const JP_REGULAR_CLOSE: NaiveTime = time(15, 0);
+
+/// TSE close from 2024-11-05, when the afternoon session was extended
+pub const JP_EXTENDED_CLOSE: NaiveTime = time(15, 30);
+
+pub const JP_LUNCH_START: NaiveTime = time(11, 30);
 
-pub const JP_REGULAR_CLOSE: NaiveTime = NaiveTime::from_hms_opt(15, 0, 0).unwrap();
+/// TSE lunch start before 2011-11-21, when the morning session ran to 11:00
+pub const JP_FORMER_LUNCH_START: NaiveTime = time(11, 0);
+
+pub const JP_LUNCH_END: NaiveTime = time(12, 30);
 
 // Canada Market Times
-pub const CA_REGULAR_OPEN: NaiveTime = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+pub const CA_REGULAR_OPEN: NaiveTime = time(9, 30);
+
+pub const CA_REGULAR_CLOSE: NaiveTime = time(16, 0);
+
+pub const CA_EARLY_CLOSE: NaiveTime = time(13, 0);
+
+// Euronext Market Times (Paris time; Lisbon trades the same hours an hour
+// earlier on the local clock)
+pub const EURONEXT_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const EURONEXT_REGULAR_CLOSE: NaiveTime = time(17, 30);
+
+pub const EURONEXT_EARLY_CLOSE: NaiveTime = time(14, 5);
+
+// Germany (Xetra) Market Times
+pub const DE_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const DE_REGULAR_CLOSE: NaiveTime = time(17, 30);
+
+// Switzerland Market Times
+pub const CH_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const CH_REGULAR_CLOSE: NaiveTime = time(17, 30);
+
+// Italy Market Times
+pub const IT_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const IT_REGULAR_CLOSE: NaiveTime = time(17, 30);
+
+// Spain Market Times
+pub const ES_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const ES_REGULAR_CLOSE: NaiveTime = time(17, 30);
+
+pub const ES_EARLY_CLOSE: NaiveTime = time(14, 0);
+
+// Nasdaq Nordic Market Times
+pub const SE_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const SE_REGULAR_CLOSE: NaiveTime = time(17, 30);
+
+pub const SE_EARLY_CLOSE: NaiveTime = time(13, 0);
+
+pub const FI_REGULAR_OPEN: NaiveTime = time(10, 0);
+
+pub const FI_REGULAR_CLOSE: NaiveTime = time(18, 30);
+
+pub const DK_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const DK_REGULAR_CLOSE: NaiveTime = time(17, 0);
+
+// Hong Kong Market Times
+pub const HK_REGULAR_OPEN: NaiveTime = time(9, 30);
+
+pub const HK_REGULAR_CLOSE: NaiveTime = time(16, 0);
+
+pub const HK_LUNCH_START: NaiveTime = time(12, 0);
+
+pub const HK_LUNCH_END: NaiveTime = time(13, 0);
+
+/// HKEX half days trade the morning session only
+pub const HK_EARLY_CLOSE: NaiveTime = time(12, 0);
+
+// China (Shanghai/Shenzhen) Market Times
+pub const CN_REGULAR_OPEN: NaiveTime = time(9, 30);
+
+pub const CN_REGULAR_CLOSE: NaiveTime = time(15, 0);
+
+pub const CN_LUNCH_START: NaiveTime = time(11, 30);
+
+pub const CN_LUNCH_END: NaiveTime = time(13, 0);
+
+// Korea Market Times
+pub const KR_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const KR_REGULAR_CLOSE: NaiveTime = time(15, 30);
+
+// Australia Market Times
+pub const AU_REGULAR_OPEN: NaiveTime = time(10, 0);
+
+pub const AU_REGULAR_CLOSE: NaiveTime = time(16, 0);
+
+pub const AU_EARLY_CLOSE: NaiveTime = time(14, 10);
+
+// India Market Times
+pub const IN_PREOPEN_START: NaiveTime = time(9, 0);
+
+pub const IN_REGULAR_OPEN: NaiveTime = time(9, 15);
+
+pub const IN_REGULAR_CLOSE: NaiveTime = time(15, 30);
+
+// Singapore Market Times
+pub const SG_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const SG_REGULAR_CLOSE: NaiveTime = time(17, 0);
+
+pub const SG_LUNCH_START: NaiveTime = time(12, 0);
+
+pub const SG_LUNCH_END: NaiveTime = time(13, 0);
+
+/// SGX half days trade the morning session only
+pub const SG_EARLY_CLOSE: NaiveTime = time(12, 0);
+
+// Saudi Arabia Market Times
+pub const SA_PREOPEN_START: NaiveTime = time(9, 30);
+
+pub const SA_REGULAR_OPEN: NaiveTime = time(10, 0);
+
+pub const SA_REGULAR_CLOSE: NaiveTime = time(15, 0);
+
+// UAE Market Times
+pub const AE_REGULAR_OPEN: NaiveTime = time(10, 0);
+
+pub const AE_REGULAR_CLOSE: NaiveTime = time(15, 0);
+
+// South Africa Market Times
+pub const ZA_REGULAR_OPEN: NaiveTime = time(9, 0);
+
+pub const ZA_REGULAR_CLOSE: NaiveTime = time(17, 0);
+
+pub const ZA_EARLY_CLOSE: NaiveTime = time(12, 0);
+
+// Egypt Market Times
+pub const EG_REGULAR_OPEN: NaiveTime = time(10, 0);
+
+pub const EG_REGULAR_CLOSE: NaiveTime = time(14, 30);
+
+// CME Globex Times (Central Time)
+/// Globex reopens after the daily maintenance break, on the calendar day
+/// before the trading date
+pub const CME_GLOBEX_OPEN: NaiveTime = time(17, 0);
+
+pub const CME_REGULAR_OPEN: NaiveTime = time(8, 30);
+
+/// Equity index futures regular trading hours end
+pub const CME_REGULAR_CLOSE: NaiveTime = time(15, 15);
+
+/// Globex closes for the daily maintenance break until 17:00
+pub const CME_GLOBEX_CLOSE: NaiveTime = time(16, 0);
+
+/// Globex halts at noon on US holidays it does not close for
+pub const CME_HOLIDAY_HALT: NaiveTime = time(12, 0);
+
+pub const CME_EARLY_CLOSE: NaiveTime = time(12, 15);
+
+// Cboe Options Times (Eastern Time)
+/// Index options trade 15 minutes past the equity options close
+pub const CBOE_INDEX_CLOSE: NaiveTime = time(16, 15);
+
+pub const CBOE_INDEX_EARLY_CLOSE: NaiveTime = time(13, 15);
+
+// Brazil Market Times
+pub const BR_REGULAR_OPEN: NaiveTime = time(10, 0);
+
+pub const BR_REGULAR_CLOSE: NaiveTime = time(17, 0);
+
+/// B3 opens at 13:00 on Ash Wednesday
+pub const BR_LATE_OPEN: NaiveTime = time(13, 0);
 
-pub const CA_REGULAR_CLOSE: NaiveTime = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+// Mexico Market Times
+pub const MX_REGULAR_OPEN: NaiveTime = time(8, 30);
 
-pub const CA_EARLY_CLOSE: NaiveTime = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+pub const MX_REGULAR_CLOSE: NaiveTime = time(15, 0);
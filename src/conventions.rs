@@ -0,0 +1,279 @@
+//! Business day adjustment conventions
+//!
+//! Dates produced by a schedule (coupon dates, option expiries, fixing
+//! dates) often fall on days the market is closed. The ISDA conventions in
+//! [`BusinessDayConvention`] say how to roll such a date onto a trading day,
+//! and [`TradingCalendar::adjust`] applies them against a market's calendar.
+//!
+//! # Example
+//!
+//! ```
+//! use trading_calendar::{BusinessDayConvention, Market, TradingCalendar};
+//! use chrono::NaiveDate;
+//!
+//! let lse = TradingCalendar::new(Market::LSE)?;
+//! // Saturday, May 31st 2025: following would leave the month
+//! let date = NaiveDate::from_ymd_opt(2025, 5, 31).unwrap();
+//!
+//! let following = lse.adjust(date, BusinessDayConvention::Following)?;
+//! assert_eq!(following, NaiveDate::from_ymd_opt(2025, 6, 2).unwrap());
+//!
+//! let modified = lse.adjust(date, BusinessDayConvention::ModifiedFollowing)?;
+//! assert_eq!(modified, NaiveDate::from_ymd_opt(2025, 5, 30).unwrap());
+//! # Ok::<(), trading_calendar::CalendarError>(())
+//! ```
+
+use crate::{CalendarError, Result, TradingCalendar};
+use chrono::{Datelike, Months, NaiveDate};
+
+/// How to roll a date that is not a trading day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum BusinessDayConvention {
+    /// Leave the date as it is
+    Unadjusted,
+    /// Roll forward to the next trading day
+    Following,
+    /// Roll forward, unless that leaves the month, then roll back
+    ModifiedFollowing,
+    /// Roll back to the previous trading day
+    Preceding,
+    /// Roll back, unless that leaves the month, then roll forward
+    ModifiedPreceding,
+    /// Roll to the closer of the next and previous trading days, forward on
+    /// a tie
+    Nearest,
+}
+
+impl TradingCalendar {
+    /// Roll a date onto a trading day according to a convention
+    ///
+    /// Trading days are returned unchanged under every convention.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if `date` or the adjusted
+    /// date is outside [`supported_years`](Self::supported_years).
+    pub fn adjust(&self, date: NaiveDate, convention: BusinessDayConvention) -> Result<NaiveDate> {
+        use BusinessDayConvention::*;
+
+        if convention == Unadjusted || self.is_trading_day(date)? {
+            return Ok(date);
+        }
+
+        let following = || self.checked(self.next_trading_day(date));
+        let preceding = || self.checked(self.previous_trading_day(date));
+        match convention {
+            Unadjusted => Ok(date),
+            Following => following(),
+            Preceding => preceding(),
+            ModifiedFollowing => {
+                let next = following()?;
+                if next.month() == date.month() {
+                    Ok(next)
+                } else {
+                    preceding()
+                }
+            }
+            ModifiedPreceding => {
+                let previous = preceding()?;
+                if previous.month() == date.month() {
+                    Ok(previous)
+                } else {
+                    following()
+                }
+            }
+            Nearest => {
+                let next = following()?;
+                let previous = preceding()?;
+                if next - date <= date - previous {
+                    Ok(next)
+                } else {
+                    Ok(previous)
+                }
+            }
+        }
+    }
+
+    /// Move a date by whole months and roll it onto a trading day
+    ///
+    /// The day of the month is kept where possible and clamped to the end
+    /// of shorter months (January 31st plus one month is February 28th or
+    /// 29th) before `convention` is applied.
+    ///
+    /// With `end_of_month`, a date that is the last trading day of its month
+    /// moves to the last trading day of the target month, as in the ISDA
+    /// end-of-month rule for schedules.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if `date` or the result is
+    /// outside [`supported_years`](Self::supported_years).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{BusinessDayConvention, Market, TradingCalendar};
+    /// use chrono::NaiveDate;
+    ///
+    /// let nyse = TradingCalendar::new(Market::NYSE)?;
+    /// let convention = BusinessDayConvention::ModifiedFollowing;
+    /// // February 27th 2026 is the last trading day of the month
+    /// let feb = NaiveDate::from_ymd_opt(2026, 2, 27).unwrap();
+    ///
+    /// let march = nyse.shift_months(feb, 1, convention, false)?;
+    /// assert_eq!(march, NaiveDate::from_ymd_opt(2026, 3, 27).unwrap());
+    ///
+    /// let march_end = nyse.shift_months(feb, 1, convention, true)?;
+    /// assert_eq!(march_end, NaiveDate::from_ymd_opt(2026, 3, 31).unwrap());
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn shift_months(
+        &self,
+        date: NaiveDate,
+        months: i32,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Result<NaiveDate> {
+        let is_month_end = end_of_month
+            && self.last_trading_day_of_month(date.year(), date.month())? == Some(date);
+
+        let shifted = if months >= 0 {
+            date.checked_add_months(Months::new(months.unsigned_abs()))
+        } else {
+            date.checked_sub_months(Months::new(months.unsigned_abs()))
+        }
+        .ok_or_else(|| {
+            CalendarError::InvalidDateCalculation(format!("Cannot shift {date} by {months} months"))
+        })?;
+
+        if is_month_end {
+            if let Some(last) = self.last_trading_day_of_month(shifted.year(), shifted.month())? {
+                return Ok(last);
+            }
+        }
+        self.adjust(shifted, convention)
+    }
+
+    /// Ensure a date found by stepping is within the supported range
+    fn checked(&self, date: NaiveDate) -> Result<NaiveDate> {
+        self.is_trading_day(date).map(|_| date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+    use crate::Market;
+    use BusinessDayConvention::*;
+
+    #[test]
+    fn test_conventions() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+
+        // Good Friday 2025 (April 18th): Thursday before, Monday after
+        let good_friday = date(2025, 4, 18);
+        assert_eq!(nyse.adjust(good_friday, Unadjusted).unwrap(), good_friday);
+        assert_eq!(
+            nyse.adjust(good_friday, Following).unwrap(),
+            date(2025, 4, 21)
+        );
+        assert_eq!(
+            nyse.adjust(good_friday, Preceding).unwrap(),
+            date(2025, 4, 17)
+        );
+        assert_eq!(
+            nyse.adjust(good_friday, Nearest).unwrap(),
+            date(2025, 4, 17)
+        );
+
+        // Saturday rolls back to Friday, Sunday forward to Monday
+        assert_eq!(
+            nyse.adjust(date(2025, 3, 8), Nearest).unwrap(),
+            date(2025, 3, 7)
+        );
+        assert_eq!(
+            nyse.adjust(date(2025, 3, 9), Nearest).unwrap(),
+            date(2025, 3, 10)
+        );
+
+        // Christmas Day 2024 is a Wednesday: a tie, so forward
+        assert_eq!(
+            nyse.adjust(date(2024, 12, 25), Nearest).unwrap(),
+            date(2024, 12, 26)
+        );
+
+        // Trading days are left alone
+        let monday = date(2025, 3, 10);
+        for convention in [
+            Following,
+            ModifiedFollowing,
+            Preceding,
+            ModifiedPreceding,
+            Nearest,
+        ] {
+            assert_eq!(nyse.adjust(monday, convention).unwrap(), monday);
+        }
+    }
+
+    #[test]
+    fn test_modified_conventions_stay_in_month() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+
+        // Sunday, August 31st 2025, before Labor Day
+        let end = date(2025, 8, 31);
+        assert_eq!(nyse.adjust(end, Following).unwrap(), date(2025, 9, 2));
+        assert_eq!(
+            nyse.adjust(end, ModifiedFollowing).unwrap(),
+            date(2025, 8, 29)
+        );
+
+        // Saturday, November 1st 2025
+        let start = date(2025, 11, 1);
+        assert_eq!(nyse.adjust(start, Preceding).unwrap(), date(2025, 10, 31));
+        assert_eq!(
+            nyse.adjust(start, ModifiedPreceding).unwrap(),
+            date(2025, 11, 3)
+        );
+    }
+
+    #[test]
+    fn test_shift_months() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+
+        // Clamped to the end of February, then rolled back within the month
+        let jan = date(2026, 1, 31);
+        assert_eq!(
+            nyse.shift_months(jan, 1, ModifiedFollowing, false).unwrap(),
+            date(2026, 2, 27)
+        );
+        assert_eq!(
+            nyse.shift_months(date(2025, 3, 31), -1, Following, false)
+                .unwrap(),
+            date(2025, 2, 28)
+        );
+
+        // Only dates on the last trading day follow the month end
+        assert_eq!(
+            nyse.shift_months(date(2025, 4, 29), 1, Following, true)
+                .unwrap(),
+            date(2025, 5, 29)
+        );
+        assert_eq!(
+            nyse.shift_months(date(2025, 4, 30), 1, Following, true)
+                .unwrap(),
+            date(2025, 5, 30)
+        );
+
+        // Results outside the calendar's years are errors
+        let calendar = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+        assert!(calendar.adjust(date(2030, 12, 31), Following).is_ok());
+        assert!(calendar
+            .shift_months(date(2030, 12, 31), 1, Following, false)
+            .is_err());
+    }
+}
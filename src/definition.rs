@@ -0,0 +1,733 @@
+//! Declarative calendar definitions
+//!
+//! A [`CalendarDefinition`] describes a market that the crate does not ship:
+//! its timezone, weekend days, session times and holiday rules. Definitions
+//! are loaded from TOML or JSON and turned into a [`TradingCalendar`] with
+//! [`TradingCalendar::from_definition`], after which the calendar behaves
+//! exactly like a built-in market.
+//!
+//! Holiday and early close rules are one of:
+//!
+//! - `fixed`: a month and day, with an optional weekend `observance`
+//!   (`none`, `next_weekday`, `previous_weekday` or `nearest_weekday`)
+//! - `nth_weekday`: e.g. the 3rd Monday of January
+//! - `last_weekday`: e.g. the last Monday of May
+//! - `easter`: an offset in days from Easter Sunday (`-2` for Good Friday)
+//! - `date`: an explicit one-off date
+//!
+//! Every rule accepts optional `start_year` and `end_year` bounds.
+//!
+//! # Example
+//!
+//! ```
+//! use trading_calendar::{CalendarDefinition, TradingCalendar};
+//! use chrono::NaiveDate;
+//!
+//! let definition = CalendarDefinition::from_toml(r#"
+//!     name = "Example Regional Exchange"
+//!     code = "XREX"
+//!     timezone = "America/Chicago"
+//!     regular = { start = "08:30", end = "15:00" }
+//!
+//!     [[holidays]]
+//!     name = "New Year's Day"
+//!     rule = "fixed"
+//!     month = 1
+//!     day = 1
+//!     observance = "next_weekday"
+//!
+//!     [[holidays]]
+//!     name = "Good Friday"
+//!     rule = "easter"
+//!     offset = -2
+//! "#)?;
+//!
+//! let calendar = TradingCalendar::from_definition(definition)?;
+//! let good_friday = NaiveDate::from_ymd_opt(2025, 4, 18).unwrap();
+//! assert!(!calendar.is_trading_day(good_friday)?);
+//! assert_eq!(calendar.timezone().name(), "America/Chicago");
+//! # Ok::<(), trading_calendar::CalendarError>(())
+//! ```
+
+use crate::markets::{MarketImpl, WeekendMask};
+use crate::utils::easter::calculate_easter;
+use crate::utils::{last_weekday_of_month, nth_weekday_of_month, HolidayCache};
+use crate::{CalendarError, Holiday, Result, Session, TradingHours, MAX_YEAR, MIN_YEAR};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+/// A complete market calendar described as data
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CalendarDefinition {
+    /// Display name of the market
+    pub name: String,
+    /// Short code of the market (e.g. a MIC)
+    pub code: String,
+    /// IANA timezone name, e.g. `America/Chicago`
+    pub timezone: String,
+    /// Days of the week the market never trades
+    #[serde(default = "default_weekend")]
+    pub weekend: Vec<Weekday>,
+    /// First year the definition covers (defaults to [`MIN_YEAR`])
+    #[serde(default)]
+    pub start_year: Option<i32>,
+    /// Last year the definition covers (defaults to [`MAX_YEAR`])
+    #[serde(default)]
+    pub end_year: Option<i32>,
+    /// Regular trading session
+    pub regular: Session,
+    /// Pre-market session (if available), ending by the regular open
+    #[serde(default)]
+    pub pre_market: Option<Session>,
+    /// After-hours session (if available), starting after the regular close
+    #[serde(default)]
+    pub after_hours: Option<Session>,
+    /// Intraday breaks within the regular session, ordered by start time
+    #[serde(default)]
+    pub breaks: Vec<Session>,
+    /// Full-day closures
+    #[serde(default)]
+    pub holidays: Vec<HolidayRule>,
+    /// Half days
+    #[serde(default)]
+    pub early_closes: Vec<EarlyCloseRule>,
+}
+
+/// A named full-day closure rule
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HolidayRule {
+    /// The name of the holiday
+    pub name: String,
+    /// How the date is determined each year
+    #[serde(flatten)]
+    pub rule: DateRule,
+    /// First year the rule applies
+    #[serde(default)]
+    pub start_year: Option<i32>,
+    /// Last year the rule applies
+    #[serde(default)]
+    pub end_year: Option<i32>,
+}
+
+/// A named early close rule
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EarlyCloseRule {
+    /// The reason for the early close
+    pub name: String,
+    /// Market close time in the market's timezone
+    pub close: NaiveTime,
+    /// How the date is determined each year
+    #[serde(flatten)]
+    pub rule: DateRule,
+    /// First year the rule applies
+    #[serde(default)]
+    pub start_year: Option<i32>,
+    /// Last year the rule applies
+    #[serde(default)]
+    pub end_year: Option<i32>,
+}
+
+/// How a rule's date is determined in a given year
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum DateRule {
+    /// A fixed month and day, moved off weekends by `observance`
+    Fixed {
+        /// Month (1-12)
+        month: u32,
+        /// Day of the month
+        day: u32,
+        /// How a date falling on a weekend is observed
+        #[serde(default)]
+        observance: Observance,
+    },
+    /// The nth occurrence of a weekday in a month (1-5)
+    NthWeekday {
+        /// Month (1-12)
+        month: u32,
+        /// Day of the week
+        weekday: Weekday,
+        /// Occurrence within the month, starting at 1
+        nth: u8,
+    },
+    /// The last occurrence of a weekday in a month
+    LastWeekday {
+        /// Month (1-12)
+        month: u32,
+        /// Day of the week
+        weekday: Weekday,
+    },
+    /// A number of days from Easter Sunday
+    Easter {
+        /// Offset in days, e.g. -2 for Good Friday or 1 for Easter Monday
+        #[serde(default)]
+        offset: i64,
+    },
+    /// A single explicit date
+    Date {
+        /// The date
+        date: NaiveDate,
+    },
+}
+
+/// How a fixed-date holiday falling on a weekend is observed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Observance {
+    /// Not moved; the holiday is lost when it falls on a weekend
+    #[default]
+    None,
+    /// Moved to the following weekday
+    NextWeekday,
+    /// Moved to the preceding weekday
+    PreviousWeekday,
+    /// Moved to the closer of the preceding and following weekdays,
+    /// preferring the following one on a tie (Saturday to Friday and Sunday
+    /// to Monday for a Saturday-Sunday weekend)
+    NearestWeekday,
+}
+
+fn default_weekend() -> Vec<Weekday> {
+    vec![Weekday::Sat, Weekday::Sun]
+}
+
+impl CalendarDefinition {
+    /// Parse a definition from TOML
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if the input is not a
+    /// valid definition.
+    pub fn from_toml(input: &str) -> Result<Self> {
+        toml::from_str(input).map_err(|e| {
+            CalendarError::InvalidConfiguration(format!("Invalid TOML calendar definition: {e}"))
+        })
+    }
+
+    /// Parse a definition from JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if the input is not a
+    /// valid definition.
+    pub fn from_json(input: &str) -> Result<Self> {
+        serde_json::from_str(input).map_err(|e| {
+            CalendarError::InvalidConfiguration(format!("Invalid JSON calendar definition: {e}"))
+        })
+    }
+
+    /// Load a definition from a `.toml` or `.json` file
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if the file cannot be
+    /// read, has another extension, or is not a valid definition.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let input = std::fs::read_to_string(path).map_err(|e| {
+            CalendarError::InvalidConfiguration(format!("Failed to read {}: {e}", path.display()))
+        })?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::from_toml(&input),
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json(&input),
+            _ => Err(CalendarError::InvalidConfiguration(format!(
+                "Unsupported calendar definition format: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// The range of years the definition covers
+    pub fn supported_years(&self) -> RangeInclusive<i32> {
+        self.start_year.unwrap_or(MIN_YEAR)..=self.end_year.unwrap_or(MAX_YEAR)
+    }
+}
+
+impl DateRule {
+    /// Resolve the rule to a date in a year, if it occurs that year
+    ///
+    /// The observed date of a fixed holiday may fall in the adjacent year.
+    fn resolve(&self, year: i32, weekend: &[Weekday]) -> Option<NaiveDate> {
+        match *self {
+            DateRule::Fixed {
+                month,
+                day,
+                observance,
+            } => {
+                let date = NaiveDate::from_ymd_opt(year, month, day)?;
+                observance.apply(date, weekend)
+            }
+            DateRule::NthWeekday {
+                month,
+                weekday,
+                nth,
+            } => nth_weekday_of_month(year, month, weekday, nth),
+            DateRule::LastWeekday { month, weekday } => last_weekday_of_month(year, month, weekday),
+            DateRule::Easter { offset } => calculate_easter(year)
+                .ok()
+                .map(|easter| easter + chrono::Duration::days(offset)),
+            DateRule::Date { date } => (date.year() == year).then_some(date),
+        }
+    }
+
+    /// Check the rule's fields are in range
+    fn validate(&self, name: &str) -> Result<()> {
+        let invalid = |detail: &str| {
+            Err(CalendarError::InvalidConfiguration(format!(
+                "Rule '{name}': {detail}"
+            )))
+        };
+        match *self {
+            DateRule::Fixed { month, day, .. } => {
+                // 2000 is a leap year, so February 29th is accepted
+                if NaiveDate::from_ymd_opt(2000, month, day).is_none() {
+                    return invalid(&format!("invalid month/day {month}/{day}"));
+                }
+            }
+            DateRule::NthWeekday { month, nth, .. } => {
+                if !(1..=12).contains(&month) {
+                    return invalid(&format!("invalid month {month}"));
+                }
+                if !(1..=5).contains(&nth) {
+                    return invalid(&format!("nth must be between 1 and 5, got {nth}"));
+                }
+            }
+            DateRule::LastWeekday { month, .. } => {
+                if !(1..=12).contains(&month) {
+                    return invalid(&format!("invalid month {month}"));
+                }
+            }
+            DateRule::Easter { offset } => {
+                if offset.abs() > 366 {
+                    return invalid(&format!("Easter offset {offset} is more than a year"));
+                }
+            }
+            DateRule::Date { .. } => {}
+        }
+        Ok(())
+    }
+}
+
+impl Observance {
+    /// Move a date off the weekend according to this observance
+    fn apply(self, date: NaiveDate, weekend: &[Weekday]) -> Option<NaiveDate> {
+        let is_weekend = |d: NaiveDate| weekend.contains(&d.weekday());
+        if !is_weekend(date) {
+            return Some(date);
+        }
+        let step = |direction: i64| {
+            let mut d = date;
+            while is_weekend(d) {
+                d += chrono::Duration::days(direction);
+            }
+            d
+        };
+        match self {
+            Observance::None => None,
+            Observance::NextWeekday => Some(step(1)),
+            Observance::PreviousWeekday => Some(step(-1)),
+            Observance::NearestWeekday => {
+                let (previous, next) = (step(-1), step(1));
+                if date - previous < next - date {
+                    Some(previous)
+                } else {
+                    Some(next)
+                }
+            }
+        }
+    }
+}
+
+/// Whether a rule bounded by optional start and end years applies in a year
+fn in_years(year: i32, start: Option<i32>, end: Option<i32>) -> bool {
+    start.map_or(true, |start| year >= start) && end.map_or(true, |end| year <= end)
+}
+
+/// Market implementation built from a [`CalendarDefinition`]
+pub(crate) struct DefinitionMarket {
+    definition: CalendarDefinition,
+    timezone: Tz,
+    cache: HolidayCache,
+}
+
+impl DefinitionMarket {
+    /// Validate a definition and build the market
+    pub(crate) fn new(definition: CalendarDefinition) -> Result<Self> {
+        let timezone: Tz = definition.timezone.parse().map_err(|_| {
+            CalendarError::InvalidConfiguration(format!(
+                "Unknown timezone: {}",
+                definition.timezone
+            ))
+        })?;
+
+        let years = definition.supported_years();
+        if years.is_empty() {
+            return Err(CalendarError::InvalidConfiguration(format!(
+                "Year range {}-{} is empty",
+                years.start(),
+                years.end()
+            )));
+        }
+
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        if weekdays.iter().all(|day| definition.weekend.contains(day)) {
+            return Err(CalendarError::InvalidConfiguration(
+                "Weekend must leave at least one trading day".to_string(),
+            ));
+        }
+
+        let regular = &definition.regular;
+        if regular.start >= regular.end {
+            return Err(CalendarError::InvalidSession);
+        }
+        // Extended sessions adjoin the regular session on the same day
+        if let Some(pre) = &definition.pre_market {
+            if pre.start >= pre.end {
+                return Err(CalendarError::InvalidSession);
+            }
+            if pre.end > regular.start {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "Pre-market session {pre} must end by the regular open at {}",
+                    regular.start.format("%H:%M")
+                )));
+            }
+        }
+        if let Some(after) = &definition.after_hours {
+            if after.start >= after.end {
+                return Err(CalendarError::InvalidSession);
+            }
+            if after.start < regular.end {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "After-hours session {after} must start after the regular close at {}",
+                    regular.end.format("%H:%M")
+                )));
+            }
+        }
+        let mut hours = TradingHours::new(NaiveDate::default(), regular.clone(), None, None);
+        for pause in &definition.breaks {
+            hours = hours.with_break(pause.clone())?;
+        }
+
+        for rule in &definition.holidays {
+            rule.rule.validate(&rule.name)?;
+        }
+        for rule in &definition.early_closes {
+            rule.rule.validate(&rule.name)?;
+            if rule.close <= regular.start || rule.close >= regular.end {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "Rule '{}': early close at {} is outside the regular session {regular}",
+                    rule.name, rule.close
+                )));
+            }
+        }
+
+        Ok(DefinitionMarket {
+            definition,
+            timezone,
+            cache: HolidayCache::default(),
+        })
+    }
+
+    /// Resolve rules for a year, including observed dates moved in from
+    /// adjacent years
+    fn resolve<'a, I>(&self, year: i32, rules: I) -> Vec<(NaiveDate, usize)>
+    where
+        I: Iterator<Item = (&'a DateRule, Option<i32>, Option<i32>)> + Clone,
+    {
+        let mut dates = Vec::new();
+        for rule_year in year - 1..=year + 1 {
+            for (index, (rule, start, end)) in rules.clone().enumerate() {
+                if !in_years(rule_year, start, end) {
+                    continue;
+                }
+                if let Some(date) = rule.resolve(rule_year, &self.definition.weekend) {
+                    if date.year() == year && !dates.iter().any(|(d, _)| *d == date) {
+                        dates.push((date, index));
+                    }
+                }
+            }
+        }
+        dates.sort();
+        dates
+    }
+}
+
+impl MarketImpl for DefinitionMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self.cache.get_or_compute(year, || {
+            self.holidays(year)
+                .into_iter()
+                .map(|holiday| holiday.date)
+                .collect()
+        });
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let rules = self
+            .definition
+            .holidays
+            .iter()
+            .map(|rule| (&rule.rule, rule.start_year, rule.end_year));
+        self.resolve(year, rules)
+            .into_iter()
+            .map(|(date, index)| Holiday::new(date, &self.definition.holidays[index].name, true))
+            .collect()
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        let rules = self
+            .definition
+            .early_closes
+            .iter()
+            .map(|rule| (&rule.rule, rule.start_year, rule.end_year));
+        self.resolve(year, rules)
+            .into_iter()
+            .filter(|(date, _)| !self.is_weekend(*date) && !self.is_holiday(*date))
+            .map(|(date, index)| {
+                let rule = &self.definition.early_closes[index];
+                Holiday::with_early_close(date, &rule.name, rule.close)
+            })
+            .collect()
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let definition = &self.definition;
+        let mut hours = TradingHours::new(
+            date,
+            definition.regular.clone(),
+            definition.pre_market.clone(),
+            definition.after_hours.clone(),
+        );
+        hours.breaks = definition.breaks.clone();
+
+        if let Some(early_close) = self.early_close(date) {
+            hours.early_close = Some(early_close);
+            if let Some(after) = &mut hours.after_hours {
+                if after.start == definition.regular.end {
+                    after.start = early_close;
+                }
+            }
+        }
+
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        self.definition.supported_years()
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.definition.name)
+    }
+
+    fn code(&self) -> Option<&str> {
+        Some(&self.definition.code)
+    }
+
+    fn weekend(&self, _date: NaiveDate) -> WeekendMask {
+        WeekendMask::from_days(&self.definition.weekend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    const DEFINITION: &str = r#"
+        name = "Test Exchange"
+        code = "XTST"
+        timezone = "Asia/Dubai"
+        weekend = ["Sat", "Sun"]
+        regular = { start = "10:00", end = "15:00" }
+        pre_market = { start = "09:30", end = "10:00" }
+        after_hours = { start = "15:00", end = "16:00" }
+        breaks = [{ start = "12:00", end = "12:30" }]
+
+        [[holidays]]
+        name = "New Year's Day"
+        rule = "fixed"
+        month = 1
+        day = 1
+        observance = "nearest_weekday"
+
+        [[holidays]]
+        name = "Founders' Day"
+        rule = "nth_weekday"
+        month = 3
+        weekday = "Mon"
+        nth = 2
+        start_year = 2024
+
+        [[holidays]]
+        name = "Easter Monday"
+        rule = "easter"
+        offset = 1
+
+        [[holidays]]
+        name = "Systems Migration"
+        rule = "date"
+        date = "2025-06-06"
+
+        [[early_closes]]
+        name = "Last Trading Day"
+        rule = "fixed"
+        month = 12
+        day = 31
+        close = "13:00"
+    "#;
+
+    fn market() -> DefinitionMarket {
+        DefinitionMarket::new(CalendarDefinition::from_toml(DEFINITION).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_rules() {
+        let market = market();
+        assert!(market.is_holiday(date(2025, 3, 10)));
+        assert!(!market.is_holiday(date(2023, 3, 13)));
+        assert!(market.is_holiday(date(2025, 4, 21)));
+        assert!(market.is_holiday(date(2025, 6, 6)));
+        assert!(!market.is_holiday(date(2026, 6, 5)));
+
+        let names: Vec<_> = market.holidays(2025).into_iter().map(|h| h.name).collect();
+        assert_eq!(
+            names,
+            [
+                "New Year's Day",
+                "Founders' Day",
+                "Easter Monday",
+                "Systems Migration"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_observance_across_years() {
+        // January 1st, 2022 is a Saturday: observed on Friday, December 31st, 2021
+        let market = market();
+        assert!(market.is_holiday(date(2021, 12, 31)));
+        assert!(!market.is_holiday(date(2022, 1, 3)));
+        assert!(market.holidays(2022).iter().all(|h| h.date.year() == 2022));
+
+        // The half day rule yields to the observed holiday
+        assert!(market.early_close(date(2021, 12, 31)).is_none());
+        assert_eq!(
+            market.early_close(date(2025, 12, 31)),
+            NaiveTime::from_hms_opt(13, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_observances() {
+        let weekend = default_weekend();
+        let saturday = date(2026, 7, 4);
+        let sunday = date(2027, 7, 4);
+        assert_eq!(Observance::None.apply(saturday, &weekend), None);
+        assert_eq!(
+            Observance::NextWeekday.apply(saturday, &weekend),
+            Some(date(2026, 7, 6))
+        );
+        assert_eq!(
+            Observance::PreviousWeekday.apply(sunday, &weekend),
+            Some(date(2027, 7, 2))
+        );
+        assert_eq!(
+            Observance::NearestWeekday.apply(saturday, &weekend),
+            Some(date(2026, 7, 3))
+        );
+        assert_eq!(
+            Observance::NearestWeekday.apply(sunday, &weekend),
+            Some(date(2027, 7, 5))
+        );
+    }
+
+    #[test]
+    fn test_json_matches_toml() {
+        let toml = CalendarDefinition::from_toml(DEFINITION).unwrap();
+        let json = serde_json::to_string(&toml).unwrap();
+        assert_eq!(CalendarDefinition::from_json(&json).unwrap(), toml);
+    }
+
+    #[test]
+    fn test_invalid_definitions() {
+        let with = |from: &str, to: &str| {
+            CalendarDefinition::from_toml(&DEFINITION.replace(from, to))
+                .and_then(DefinitionMarket::new)
+                .err()
+        };
+
+        assert!(with(r#""Asia/Dubai""#, r#""Mars/Olympus""#).is_some());
+        assert!(with("nth = 2", "nth = 6").is_some());
+        assert!(with("day = 31", "day = 32").is_some());
+        assert!(with(r#"close = "13:00""#, r#"close = "16:00""#).is_some());
+        assert!(with(r#"start = "12:00""#, r#"start = "09:00""#).is_some());
+        assert!(with(r#"rule = "easter""#, r#"rule = "lunar""#).is_some());
+
+        // Reversed, overlapping and misplaced extended sessions
+        let pre = r#"pre_market = { start = "09:30", end = "10:00" }"#;
+        let after = r#"after_hours = { start = "15:00", end = "16:00" }"#;
+        assert!(matches!(
+            with(pre, r#"pre_market = { start = "10:00", end = "09:30" }"#),
+            Some(CalendarError::InvalidSession)
+        ));
+        assert!(matches!(
+            with(pre, r#"pre_market = { start = "09:30", end = "10:30" }"#),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            with(pre, r#"pre_market = { start = "15:30", end = "16:30" }"#),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            with(after, r#"after_hours = { start = "16:00", end = "15:00" }"#),
+            Some(CalendarError::InvalidSession)
+        ));
+        assert!(matches!(
+            with(after, r#"after_hours = { start = "14:30", end = "16:00" }"#),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            with(after, r#"after_hours = { start = "08:00", end = "09:00" }"#),
+            Some(CalendarError::InvalidConfiguration(_))
+        ));
+        assert!(with(
+            r#"weekend = ["Sat", "Sun"]"#,
+            r#"weekend = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]"#
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_weekend_mask() {
+        let definition = DEFINITION.replace(r#"["Sat", "Sun"]"#, r#"["Fri", "Sat"]"#);
+        let market =
+            DefinitionMarket::new(CalendarDefinition::from_toml(&definition).unwrap()).unwrap();
+        assert_eq!(
+            market.weekend(date(2025, 3, 7)),
+            WeekendMask::FRIDAY_SATURDAY
+        );
+        assert!(!market.is_trading_day(date(2025, 3, 7)));
+        assert!(market.is_trading_day(date(2025, 3, 9)));
+    }
+}
@@ -7,7 +7,7 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum CalendarError {
     /// Date is outside the supported range
-    #[error("Date {0} is outside supported range (2020-2030). Please use a date within the supported range.")]
+    #[error("Date {0} is outside the calendar's supported year range. See TradingCalendar::supported_years().")]
     DateOutOfRange(NaiveDate),
 
     /// Invalid time provided
@@ -29,6 +29,24 @@ pub enum CalendarError {
     /// Invalid session times
     #[error("Invalid session: end time must be after start time for regular sessions")]
     InvalidSession,
+
+    /// iCalendar data that does not follow the RFC 5545 syntax
+    #[error("Malformed iCalendar data at line {line}: {message}")]
+    MalformedIcs {
+        /// Line number (1-based) where the problem was found
+        line: usize,
+        /// Description of the problem
+        message: String,
+    },
+
+    /// A well-formed iCalendar event that cannot be used as a closure
+    #[error("Unsupported iCalendar event at line {line}: {message}")]
+    UnsupportedIcsEvent {
+        /// Line number (1-based) of the event's BEGIN:VEVENT
+        line: usize,
+        /// Description of the problem
+        message: String,
+    },
 }
 
 /// Result type alias for trading calendar operations
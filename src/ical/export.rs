@@ -0,0 +1,384 @@
+//! iCalendar export
+
+use super::{escape_text, push_line};
+use crate::{Result, TradingCalendar};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use std::ops::RangeInclusive;
+
+/// Product identifier written to exported calendars
+const PRODID: &str = concat!("-//trading-calendar//", env!("CARGO_PKG_VERSION"), "//EN");
+
+/// Domain used to make event UIDs globally unique
+const UID_DOMAIN: &str = "trading-calendar";
+
+/// Writes a calendar's closures and early closes as an iCalendar document
+///
+/// # Examples
+///
+/// ```
+/// use trading_calendar::ical::IcsExporter;
+/// use trading_calendar::{Market, TradingCalendar};
+/// use chrono::{TimeZone, Utc};
+///
+/// let lse = TradingCalendar::new(Market::LSE)?;
+/// let ics = IcsExporter::new(&lse)
+///     .dtstamp(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+///     .export(2025..=2025)?;
+/// assert!(ics.contains("DTSTAMP:20250101T000000Z\r\n"));
+/// assert!(ics.contains("SUMMARY:LSE closed: Good Friday\r\n"));
+/// # Ok::<(), trading_calendar::CalendarError>(())
+/// ```
+pub struct IcsExporter<'a> {
+    calendar: &'a TradingCalendar,
+    dtstamp: DateTime<Utc>,
+}
+
+impl<'a> IcsExporter<'a> {
+    /// Create an exporter for a calendar, stamped with the current time
+    pub fn new(calendar: &'a TradingCalendar) -> Self {
+        IcsExporter {
+            calendar,
+            dtstamp: Utc::now(),
+        }
+    }
+
+    /// Set the `DTSTAMP` written to every event
+    ///
+    /// A fixed stamp makes exports reproducible, e.g. for diffing.
+    pub fn dtstamp(mut self, dtstamp: DateTime<Utc>) -> Self {
+        self.dtstamp = dtstamp;
+        self
+    }
+
+    /// Export the closures and early closes of a range of years
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the range extends beyond
+    /// the calendar's supported years.
+    pub fn export(&self, years: RangeInclusive<i32>) -> Result<String> {
+        let calendar = self.calendar;
+        let tz = calendar.timezone();
+
+        let mut closures = Vec::new();
+        let mut early_closes = Vec::new();
+        for year in years.clone() {
+            closures.extend(calendar.holidays(year)?);
+            early_closes.extend(calendar.early_closes(year)?);
+        }
+
+        let mut output = String::new();
+        push_line(&mut output, "BEGIN:VCALENDAR");
+        push_line(&mut output, "VERSION:2.0");
+        push_line(&mut output, &format!("PRODID:{PRODID}"));
+        push_line(&mut output, "CALSCALE:GREGORIAN");
+        push_line(&mut output, "METHOD:PUBLISH");
+        push_line(
+            &mut output,
+            &format!("X-WR-CALNAME:{}", escape_text(calendar.name())),
+        );
+        push_line(&mut output, &format!("X-WR-TIMEZONE:{}", tz.name()));
+
+        if !early_closes.is_empty() {
+            write_vtimezone(&mut output, tz, &years);
+        }
+
+        let dtstamp = format_utc(self.dtstamp);
+        for holiday in &closures {
+            let date = holiday.date;
+            push_line(&mut output, "BEGIN:VEVENT");
+            push_line(
+                &mut output,
+                &format!(
+                    "UID:{}-{}-closed@{UID_DOMAIN}",
+                    calendar.code(),
+                    compact(date)
+                ),
+            );
+            push_line(&mut output, &format!("DTSTAMP:{dtstamp}"));
+            push_line(
+                &mut output,
+                &format!("DTSTART;VALUE=DATE:{}", compact(date)),
+            );
+            push_line(
+                &mut output,
+                &format!("DTEND;VALUE=DATE:{}", compact(date + Duration::days(1))),
+            );
+            push_line(
+                &mut output,
+                &format!(
+                    "SUMMARY:{}",
+                    escape_text(&format!("{} closed: {}", calendar.code(), holiday.name))
+                ),
+            );
+            push_line(&mut output, "TRANSP:TRANSPARENT");
+            push_line(&mut output, "END:VEVENT");
+        }
+
+        for holiday in &early_closes {
+            let date = holiday.date;
+            let hours = calendar.trading_hours(date);
+            let close = holiday.early_close.unwrap_or_else(|| hours.market_close());
+            push_line(&mut output, "BEGIN:VEVENT");
+            push_line(
+                &mut output,
+                &format!(
+                    "UID:{}-{}-early-close@{UID_DOMAIN}",
+                    calendar.code(),
+                    compact(date)
+                ),
+            );
+            push_line(&mut output, &format!("DTSTAMP:{dtstamp}"));
+            push_line(
+                &mut output,
+                &format!("DTSTART;TZID={}:{}", tz.name(), local(date, close)),
+            );
+            push_line(
+                &mut output,
+                &format!(
+                    "DTEND;TZID={}:{}",
+                    tz.name(),
+                    local(date, hours.regular.end)
+                ),
+            );
+            push_line(
+                &mut output,
+                &format!(
+                    "SUMMARY:{}",
+                    escape_text(&format!(
+                        "{} early close: {}",
+                        calendar.code(),
+                        holiday.name
+                    ))
+                ),
+            );
+            push_line(
+                &mut output,
+                &format!(
+                    "DESCRIPTION:{}",
+                    escape_text(&format!(
+                        "{} closes at {} instead of {}",
+                        calendar.name(),
+                        close.format("%H:%M"),
+                        hours.regular.end.format("%H:%M")
+                    ))
+                ),
+            );
+            push_line(&mut output, "TRANSP:TRANSPARENT");
+            push_line(&mut output, "END:VEVENT");
+        }
+
+        push_line(&mut output, "END:VCALENDAR");
+        Ok(output)
+    }
+}
+
+impl TradingCalendar {
+    /// Export the closures and early closes of a range of years as iCalendar
+    ///
+    /// Shorthand for [`IcsExporter::new(self).export(years)`](IcsExporter::export).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the range extends beyond
+    /// [`supported_years`](Self::supported_years).
+    pub fn to_ics(&self, years: RangeInclusive<i32>) -> Result<String> {
+        IcsExporter::new(self).export(years)
+    }
+}
+
+/// Write a `VTIMEZONE` covering the UTC offset changes within `years`
+///
+/// Each transition is written as its own observance rather than as a
+/// recurrence rule, which keeps historical rule changes exact.
+fn write_vtimezone(output: &mut String, tz: Tz, years: &RangeInclusive<i32>) {
+    let start = Utc
+        .with_ymd_and_hms(*years.start(), 1, 1, 0, 0, 0)
+        .single()
+        .expect("Valid start of range");
+    let end = Utc
+        .with_ymd_and_hms(*years.end() + 1, 1, 1, 0, 0, 0)
+        .single()
+        .expect("Valid end of range");
+
+    push_line(output, "BEGIN:VTIMEZONE");
+    push_line(output, &format!("TZID:{}", tz.name()));
+
+    // The offset in effect when the range begins
+    let initial = tz.offset_from_utc_datetime(&start.naive_utc());
+    write_observance(output, "19700101T000000", initial, initial);
+
+    let mut previous = start;
+    let mut instant = start + Duration::days(1);
+    while instant <= end {
+        let before = tz.offset_from_utc_datetime(&previous.naive_utc());
+        let after = tz.offset_from_utc_datetime(&instant.naive_utc());
+        if before.fix() != after.fix() {
+            // Narrow the change down to the second it takes effect
+            let (mut low, mut high) = (previous, instant);
+            while high - low > Duration::seconds(1) {
+                let middle = low + (high - low) / 2;
+                if tz.offset_from_utc_datetime(&middle.naive_utc()).fix() == before.fix() {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            let onset = high.naive_utc() + Duration::seconds(before.fix().local_minus_utc().into());
+            write_observance(
+                output,
+                &onset.format("%Y%m%dT%H%M%S").to_string(),
+                before,
+                tz.offset_from_utc_datetime(&high.naive_utc()),
+            );
+        }
+        previous = instant;
+        instant += Duration::days(1);
+    }
+
+    push_line(output, "END:VTIMEZONE");
+}
+
+/// Write a `STANDARD` or `DAYLIGHT` observance
+fn write_observance(
+    output: &mut String,
+    onset: &str,
+    from: <Tz as TimeZone>::Offset,
+    to: <Tz as TimeZone>::Offset,
+) {
+    let kind = if to.dst_offset().is_zero() {
+        "STANDARD"
+    } else {
+        "DAYLIGHT"
+    };
+    push_line(output, &format!("BEGIN:{kind}"));
+    push_line(output, &format!("DTSTART:{onset}"));
+    push_line(
+        output,
+        &format!(
+            "TZOFFSETFROM:{}",
+            format_offset(from.fix().local_minus_utc())
+        ),
+    );
+    push_line(
+        output,
+        &format!("TZOFFSETTO:{}", format_offset(to.fix().local_minus_utc())),
+    );
+    if let Some(abbreviation) = to.abbreviation() {
+        push_line(output, &format!("TZNAME:{}", escape_text(abbreviation)));
+    }
+    push_line(output, &format!("END:{kind}"));
+}
+
+/// Format a UTC offset in seconds as `+HHMM` (or `+HHMMSS`)
+fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+    let (hours, minutes, rest) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if rest == 0 {
+        format!("{sign}{hours:02}{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}{minutes:02}{rest:02}")
+    }
+}
+
+fn compact(date: NaiveDate) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+fn local(date: NaiveDate, time: NaiveTime) -> String {
+    date.and_time(time).format("%Y%m%dT%H%M%S").to_string()
+}
+
+fn format_utc(instant: DateTime<Utc>) -> String {
+    instant.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Market;
+
+    fn export(market: Market, years: RangeInclusive<i32>) -> String {
+        let calendar = TradingCalendar::new(market).unwrap();
+        IcsExporter::new(&calendar)
+            .dtstamp(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+            .export(years)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_events() {
+        let ics = export(Market::NYSE, 2025..=2025);
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 11 + 3);
+        assert!(ics.contains(
+            "UID:NYSE-20251127-closed@trading-calendar\r\n\
+             DTSTAMP:20250101T000000Z\r\n\
+             DTSTART;VALUE=DATE:20251127\r\n\
+             DTEND;VALUE=DATE:20251128\r\n\
+             SUMMARY:NYSE closed: Thanksgiving Day\r\n"
+        ));
+        assert!(ics.contains(
+            "DTSTART;TZID=America/New_York:20251128T130000\r\n\
+             DTEND;TZID=America/New_York:20251128T160000\r\n"
+        ));
+    }
+
+    #[cfg(feature = "definitions")]
+    #[test]
+    fn test_definition_name_and_code() {
+        let definition = crate::CalendarDefinition::from_toml(
+            r#"
+            name = "Test Exchange"
+            code = "XTST"
+            timezone = "Asia/Dubai"
+            regular = { start = "10:00", end = "15:00" }
+
+            [[holidays]]
+            name = "New Year's Day"
+            rule = "fixed"
+            month = 1
+            day = 1
+            "#,
+        )
+        .unwrap();
+        let calendar = TradingCalendar::from_definition(definition).unwrap();
+        assert_eq!(calendar.name(), "Test Exchange");
+        assert_eq!(calendar.code(), "XTST");
+
+        let ics = IcsExporter::new(&calendar).export(2025..=2025).unwrap();
+        assert!(ics.contains("X-WR-CALNAME:Test Exchange\r\n"));
+        assert!(ics.contains("SUMMARY:XTST closed: New Year's Day\r\n"));
+    }
+
+    #[test]
+    fn test_vtimezone_transitions() {
+        let ics = export(Market::NYSE, 2025..=2025);
+        assert!(ics.contains(
+            "BEGIN:DAYLIGHT\r\n\
+             DTSTART:20250309T020000\r\n\
+             TZOFFSETFROM:-0500\r\n\
+             TZOFFSETTO:-0400\r\n\
+             TZNAME:EDT\r\n\
+             END:DAYLIGHT\r\n"
+        ));
+        assert!(ics.contains(
+            "BEGIN:STANDARD\r\n\
+             DTSTART:20251102T020000\r\n\
+             TZOFFSETFROM:-0400\r\n\
+             TZOFFSETTO:-0500\r\n"
+        ));
+
+        // Markets without early closes need no timezone definition
+        let ics = export(Market::TSE, 2025..=2025);
+        assert!(!ics.contains("VTIMEZONE"));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let calendar = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+        assert!(calendar.to_ics(2030..=2031).is_err());
+    }
+}
@@ -0,0 +1,819 @@
+//! iCalendar import
+
+use crate::builder::is_off_week;
+use crate::markets::{is_weekend, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{
+    CalendarError, Holiday, Market, Result, Session, TradingCalendar, TradingCalendarBuilder,
+    TradingHours,
+};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::RangeInclusive;
+
+/// Closures and early closes read from an iCalendar document
+///
+/// All-day events are read as closures, one per day they span. Timed events
+/// are read as early closes at their start time, in [`timezone`](Self::timezone).
+/// Cancelled events are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use trading_calendar::ical::IcsCalendar;
+/// use trading_calendar::{Market, TradingCalendar};
+/// use chrono::NaiveDate;
+///
+/// let ics = IcsCalendar::parse(
+///     "BEGIN:VCALENDAR\r\n\
+///      VERSION:2.0\r\n\
+///      BEGIN:VEVENT\r\n\
+///      DTSTART;VALUE=DATE:20250312\r\n\
+///      SUMMARY:Emergency closure\r\n\
+///      END:VEVENT\r\n\
+///      END:VCALENDAR\r\n",
+/// )?;
+///
+/// let nyse = ics.apply(TradingCalendar::builder(Market::NYSE))?.build()?;
+/// assert!(!nyse.is_trading_day(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap())?);
+/// # Ok::<(), trading_calendar::CalendarError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsCalendar {
+    /// Calendar name from `X-WR-CALNAME`, if present
+    pub name: Option<String>,
+    /// Timezone of the early close times, from `X-WR-TIMEZONE` or the first
+    /// `TZID` in the document
+    pub timezone: Option<Tz>,
+    /// Full-day closures, sorted by date
+    pub closures: Vec<Holiday>,
+    /// Early closes, sorted by date
+    pub early_closes: Vec<Holiday>,
+}
+
+/// Differences between an iCalendar document and a trading calendar
+///
+/// Only dates and close times are compared; names may differ freely.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IcsDiff {
+    /// Closures in the document that the calendar does not have
+    pub missing_closures: Vec<Holiday>,
+    /// Closures in the calendar that the document does not have
+    pub extra_closures: Vec<Holiday>,
+    /// Early closes in the document that the calendar does not have (or
+    /// has at a different time)
+    pub missing_early_closes: Vec<Holiday>,
+    /// Early closes in the calendar that the document does not have (or
+    /// has at a different time)
+    pub extra_early_closes: Vec<Holiday>,
+}
+
+impl IcsDiff {
+    /// Whether the document and the calendar agree
+    pub fn is_empty(&self) -> bool {
+        self.missing_closures.is_empty()
+            && self.extra_closures.is_empty()
+            && self.missing_early_closes.is_empty()
+            && self.extra_early_closes.is_empty()
+    }
+}
+
+/// An unfolded content line: `NAME;PARAM=VALUE:value`
+struct ContentLine {
+    number: usize,
+    name: String,
+    params: HashMap<String, String>,
+    value: String,
+}
+
+/// The properties of a `VEVENT` that matter for closures
+#[derive(Default)]
+struct Event {
+    line: usize,
+    properties: HashMap<String, ContentLine>,
+}
+
+/// When an event starts or ends
+enum EventTime {
+    Date(NaiveDate),
+    Local(NaiveDateTime, Option<Tz>),
+    Utc(NaiveDateTime),
+}
+
+impl IcsCalendar {
+    /// Parse an iCalendar document
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::MalformedIcs` if the document is not valid RFC 5545
+    ///   syntax (e.g. unbalanced `BEGIN`/`END` or a line without a value)
+    /// - `CalendarError::UnsupportedIcsEvent` if an event cannot be read as a
+    ///   closure (e.g. no `DTSTART`, a recurrence rule or an unknown `TZID`)
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut calendar = IcsCalendar {
+            name: None,
+            timezone: None,
+            closures: Vec::new(),
+            early_closes: Vec::new(),
+        };
+        let mut stack: Vec<(String, usize)> = Vec::new();
+        let mut events = Vec::new();
+        let mut event: Option<Event> = None;
+        let mut last_line = 0;
+
+        for line in unfold(input) {
+            let line = parse_line(line)?;
+            last_line = line.number;
+            match line.name.as_str() {
+                "BEGIN" => {
+                    let component = line.value.to_ascii_uppercase();
+                    if stack.is_empty() && component != "VCALENDAR" {
+                        return Err(malformed(line.number, "expected BEGIN:VCALENDAR"));
+                    }
+                    if component == "VEVENT" {
+                        event = Some(Event {
+                            line: line.number,
+                            ..Event::default()
+                        });
+                    }
+                    stack.push((component, line.number));
+                }
+                "END" => {
+                    let component = line.value.to_ascii_uppercase();
+                    match stack.pop() {
+                        Some((open, _)) if open == component => {}
+                        Some((open, _)) => {
+                            return Err(malformed(
+                                line.number,
+                                &format!("END:{component} does not match BEGIN:{open}"),
+                            ))
+                        }
+                        None => {
+                            return Err(malformed(
+                                line.number,
+                                &format!("END:{component} without BEGIN"),
+                            ))
+                        }
+                    }
+                    if component == "VEVENT" {
+                        events.extend(event.take());
+                    }
+                }
+                _ if stack.is_empty() => {
+                    return Err(malformed(line.number, "content outside VCALENDAR"));
+                }
+                name => {
+                    let component = stack.last().map(|(c, _)| c.as_str());
+                    match (component, name) {
+                        (Some("VCALENDAR"), "X-WR-CALNAME") => {
+                            calendar.name = Some(unescape_text(&line.value));
+                        }
+                        (Some("VCALENDAR"), "X-WR-TIMEZONE") => {
+                            calendar.timezone = Some(parse_tz(&line.value, line.number)?);
+                        }
+                        (Some("VEVENT"), _) => {
+                            if let Some(event) = &mut event {
+                                event.properties.insert(name.to_string(), line);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if let Some((component, line)) = stack.pop() {
+            return Err(malformed(
+                line,
+                &format!("BEGIN:{component} is never closed"),
+            ));
+        }
+        if last_line == 0 {
+            return Err(malformed(1, "empty document"));
+        }
+
+        // Early closes are read in the document's timezone, which may be
+        // given by the first TZID when X-WR-TIMEZONE is absent
+        if calendar.timezone.is_none() {
+            for event in &events {
+                if let Some(tzid) = event
+                    .properties
+                    .get("DTSTART")
+                    .and_then(|dtstart| dtstart.params.get("TZID"))
+                {
+                    calendar.timezone = Some(parse_tz(tzid, event.line)?);
+                    break;
+                }
+            }
+        }
+
+        let mut closures = BTreeMap::new();
+        let mut early_closes = BTreeMap::new();
+        for event in events {
+            calendar.read_event(event, &mut closures, &mut early_closes)?;
+        }
+        calendar.closures = closures.into_values().collect();
+        calendar.early_closes = early_closes.into_values().collect();
+        Ok(calendar)
+    }
+
+    /// Add an event's closures or early close
+    fn read_event(
+        &self,
+        event: Event,
+        closures: &mut BTreeMap<NaiveDate, Holiday>,
+        early_closes: &mut BTreeMap<NaiveDate, Holiday>,
+    ) -> Result<()> {
+        let unsupported = |message: &str| CalendarError::UnsupportedIcsEvent {
+            line: event.line,
+            message: message.to_string(),
+        };
+        let property = |name: &str| event.properties.get(name);
+
+        if property("STATUS").is_some_and(|status| status.value.eq_ignore_ascii_case("CANCELLED")) {
+            return Ok(());
+        }
+        if property("RRULE").is_some() || property("RDATE").is_some() {
+            return Err(unsupported("recurring events are not supported"));
+        }
+
+        let name = property("SUMMARY")
+            .map(|summary| unescape_text(&summary.value))
+            .unwrap_or_else(|| "Market closed".to_string());
+        let start = property("DTSTART").ok_or_else(|| unsupported("missing DTSTART"))?;
+
+        match parse_time(start)? {
+            EventTime::Date(first) => {
+                let end = match property("DTEND").map(parse_time).transpose()? {
+                    Some(EventTime::Date(end)) if end > first => end,
+                    Some(EventTime::Date(_)) | None => first + Duration::days(1),
+                    Some(_) => {
+                        return Err(unsupported("all-day DTSTART with a timed DTEND"));
+                    }
+                };
+                let mut date = first;
+                while date < end {
+                    closures.insert(date, Holiday::new(date, &name, true));
+                    date += Duration::days(1);
+                }
+            }
+            time => {
+                let local = self.localize(time, event.line)?;
+                early_closes.insert(
+                    local.date(),
+                    Holiday::with_early_close(local.date(), &name, local.time()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert an event time to the document's timezone
+    fn localize(&self, time: EventTime, line: usize) -> Result<NaiveDateTime> {
+        let unsupported = |message: &str| CalendarError::UnsupportedIcsEvent {
+            line,
+            message: message.to_string(),
+        };
+        match (time, self.timezone) {
+            (EventTime::Date(date), _) => Ok(date.and_time(NaiveTime::MIN)),
+            (EventTime::Local(local, None), _) => Ok(local),
+            (EventTime::Local(local, Some(from)), Some(to)) if from != to => from
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|instant| instant.with_timezone(&to).naive_local())
+                .ok_or_else(|| unsupported("local time does not exist in its timezone")),
+            (EventTime::Local(local, Some(_)), _) => Ok(local),
+            (EventTime::Utc(utc), Some(to)) => {
+                Ok(Utc.from_utc_datetime(&utc).with_timezone(&to).naive_local())
+            }
+            (EventTime::Utc(_), None) => Err(unsupported(
+                "UTC time in a document without a timezone (set X-WR-TIMEZONE)",
+            )),
+        }
+    }
+
+    /// The years the document's events fall in
+    pub fn years(&self) -> Option<RangeInclusive<i32>> {
+        let dates = self
+            .closures
+            .iter()
+            .chain(&self.early_closes)
+            .map(|day| day.date.year());
+        let first = dates.clone().min()?;
+        let last = dates.max()?;
+        Some(first..=last)
+    }
+
+    /// Early closes with their times converted to another timezone
+    ///
+    /// Floating times (a document without a timezone) are taken as-is.
+    fn early_closes_in(&self, tz: Tz) -> Result<Vec<Holiday>> {
+        let Some(from) = self.timezone.filter(|from| *from != tz) else {
+            return Ok(self.early_closes.clone());
+        };
+        self.early_closes
+            .iter()
+            .map(|day| {
+                let close = day.early_close.unwrap_or(NaiveTime::MIN);
+                let local = from
+                    .from_local_datetime(&day.date.and_time(close))
+                    .earliest()
+                    .map(|instant| instant.with_timezone(&tz).naive_local())
+                    .ok_or_else(|| {
+                        CalendarError::InvalidConfiguration(format!(
+                            "Early close on {} does not exist in {}",
+                            day.date,
+                            from.name()
+                        ))
+                    })?;
+                Ok(Holiday::with_early_close(
+                    local.date(),
+                    &day.name,
+                    local.time(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Add the document's closures and early closes to a calendar builder
+    ///
+    /// Early close times are converted to the builder's market timezone.
+    /// Events on the market's weekend are skipped, as the market is closed
+    /// anyway.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::InvalidConfiguration` if an early close time
+    /// cannot be converted to the market's timezone.
+    pub fn apply(&self, builder: TradingCalendarBuilder) -> Result<TradingCalendarBuilder> {
+        let market = builder.market().create_implementation()?;
+        let early_closes = self.early_closes_in(market.timezone())?;
+        let trades = |day: &&Holiday| !is_off_week(market.as_ref(), day.date);
+        let builder = self
+            .closures
+            .iter()
+            .filter(trades)
+            .fold(builder, |builder, day| {
+                builder.add_closure(day.date, &day.name)
+            });
+        Ok(early_closes
+            .iter()
+            .filter(trades)
+            .fold(builder, |builder, day| {
+                let close = day.early_close.unwrap_or(NaiveTime::MIN);
+                builder.add_early_close(day.date, close, &day.name)
+            }))
+    }
+
+    /// Build a complete calendar from the document alone
+    ///
+    /// The calendar trades Monday to Friday in `regular` hours outside the
+    /// document's closures, and covers only the years its events fall in.
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::InvalidConfiguration` if the document has no events
+    ///   or no timezone, or an early close is outside the regular session
+    /// - `CalendarError::InvalidSession` if `regular` is an overnight session
+    pub fn into_calendar(self, regular: Session) -> Result<TradingCalendar> {
+        let years = self.years().ok_or_else(|| {
+            CalendarError::InvalidConfiguration("iCalendar document has no events".to_string())
+        })?;
+        let timezone = self.timezone.ok_or_else(|| {
+            CalendarError::InvalidConfiguration(
+                "iCalendar document has no timezone (set X-WR-TIMEZONE)".to_string(),
+            )
+        })?;
+        if regular.start >= regular.end {
+            return Err(CalendarError::InvalidSession);
+        }
+        for day in &self.early_closes {
+            let close = day.early_close.unwrap_or(NaiveTime::MIN);
+            if close <= regular.start || close >= regular.end {
+                return Err(CalendarError::InvalidConfiguration(format!(
+                    "Early close at {close} on {} is outside the regular session {regular}",
+                    day.date
+                )));
+            }
+        }
+
+        let market = IcsMarket {
+            timezone,
+            regular,
+            years: years.clone(),
+            closures: self.closures,
+            early_closes: self.early_closes,
+            cache: HolidayCache::default(),
+        };
+        Ok(TradingCalendar::from_parts(
+            Market::Custom,
+            Box::new(market),
+            years,
+        ))
+    }
+
+    /// Compare the document with a calendar over the years it covers
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the document covers years
+    /// outside the calendar's supported range, or
+    /// `CalendarError::InvalidConfiguration` if an early close time cannot be
+    /// converted to the calendar's timezone.
+    pub fn diff(&self, calendar: &TradingCalendar) -> Result<IcsDiff> {
+        let mut diff = IcsDiff::default();
+        let Some(years) = self.years() else {
+            return Ok(diff);
+        };
+
+        let mut closures = Vec::new();
+        let mut early_closes = Vec::new();
+        for year in years {
+            closures.extend(calendar.holidays(year)?);
+            early_closes.extend(calendar.early_closes(year)?);
+        }
+        let file_early_closes = self.early_closes_in(calendar.timezone())?;
+
+        let same_date = |a: &Holiday, b: &Holiday| a.date == b.date;
+        let same_close =
+            |a: &Holiday, b: &Holiday| a.date == b.date && a.early_close == b.early_close;
+        diff.missing_closures = missing(&self.closures, &closures, same_date);
+        diff.extra_closures = missing(&closures, &self.closures, same_date);
+        diff.missing_early_closes = missing(&file_early_closes, &early_closes, same_close);
+        diff.extra_early_closes = missing(&early_closes, &file_early_closes, same_close);
+        Ok(diff)
+    }
+}
+
+/// Entries of `from` with no match in `other`
+fn missing(
+    from: &[Holiday],
+    other: &[Holiday],
+    same: impl Fn(&Holiday, &Holiday) -> bool,
+) -> Vec<Holiday> {
+    from.iter()
+        .filter(|a| !other.iter().any(|b| same(a, b)))
+        .cloned()
+        .collect()
+}
+
+/// Market implementation holding explicit closures read from iCalendar
+struct IcsMarket {
+    timezone: Tz,
+    regular: Session,
+    years: RangeInclusive<i32>,
+    closures: Vec<Holiday>,
+    early_closes: Vec<Holiday>,
+    cache: HolidayCache,
+}
+
+impl MarketImpl for IcsMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self.cache.get_or_compute(year, || {
+            self.holidays(year)
+                .into_iter()
+                .map(|holiday| holiday.date)
+                .collect()
+        });
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        self.closures
+            .iter()
+            .filter(|day| day.date.year() == year && !is_weekend(day.date))
+            .cloned()
+            .collect()
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        self.early_closes
+            .iter()
+            .filter(|day| day.date.year() == year && !is_weekend(day.date))
+            .cloned()
+            .collect()
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(date, self.regular.clone(), None, None);
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        self.years.clone()
+    }
+}
+
+fn malformed(line: usize, message: &str) -> CalendarError {
+    CalendarError::MalformedIcs {
+        line,
+        message: message.to_string(),
+    }
+}
+
+/// Join folded lines, keeping the number of each logical line's first line
+fn unfold(input: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, raw) in input.lines().enumerate() {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, line))) => line.push_str(continuation),
+            _ if raw.trim().is_empty() => {}
+            _ => lines.push((index + 1, raw.to_string())),
+        }
+    }
+    lines
+}
+
+/// Split a content line into its name, parameters and value
+fn parse_line((number, line): (usize, String)) -> Result<ContentLine> {
+    let mut in_quotes = false;
+    let mut colon = None;
+    let mut separators = Vec::new();
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => separators.push(index),
+            ':' if !in_quotes => {
+                colon = Some(index);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let colon = colon.ok_or_else(|| malformed(number, "content line without ':'"))?;
+
+    let head = &line[..colon];
+    let mut bounds = separators.clone();
+    bounds.push(colon);
+    let name = head[..bounds[0]].trim().to_ascii_uppercase();
+    if name.is_empty() {
+        return Err(malformed(number, "content line without a name"));
+    }
+
+    let mut params = HashMap::new();
+    for pair in bounds.windows(2) {
+        let param = &line[pair[0] + 1..pair[1]];
+        let (key, value) = param
+            .split_once('=')
+            .ok_or_else(|| malformed(number, &format!("parameter '{param}' without '='")))?;
+        params.insert(
+            key.trim().to_ascii_uppercase(),
+            value.trim_matches('"').to_string(),
+        );
+    }
+
+    Ok(ContentLine {
+        number,
+        name,
+        params,
+        value: line[colon + 1..].to_string(),
+    })
+}
+
+/// Read a `DTSTART`/`DTEND` value
+fn parse_time(property: &ContentLine) -> Result<EventTime> {
+    let value = property.value.trim();
+    let invalid = || CalendarError::UnsupportedIcsEvent {
+        line: property.number,
+        message: format!("invalid {} value '{value}'", property.name),
+    };
+    let is_date = property
+        .params
+        .get("VALUE")
+        .is_some_and(|kind| kind.eq_ignore_ascii_case("DATE"))
+        || value.len() == 8;
+
+    if is_date {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map(EventTime::Date)
+            .map_err(|_| invalid());
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .map(EventTime::Utc)
+            .map_err(|_| invalid());
+    }
+    let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+    let tz = property
+        .params
+        .get("TZID")
+        .map(|tzid| parse_tz(tzid, property.number))
+        .transpose()?;
+    Ok(EventTime::Local(local, tz))
+}
+
+fn parse_tz(name: &str, line: usize) -> Result<Tz> {
+    // Some producers prefix the TZID with a '/' to mark a global identifier
+    let name = name.trim().trim_start_matches('/');
+    name.parse()
+        .map_err(|_| CalendarError::UnsupportedIcsEvent {
+            line,
+            message: format!("unknown timezone '{name}'"),
+        })
+}
+
+/// Reverse the TEXT escaping of RFC 5545
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{date, time};
+
+    fn document(events: &str) -> String {
+        format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nX-WR-TIMEZONE:America/New_York\r\n{events}END:VCALENDAR\r\n")
+    }
+
+    #[test]
+    fn test_round_trip_matches_rules() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+        let ics = IcsCalendar::parse(&nyse.to_ics(2024..=2026).unwrap()).unwrap();
+
+        assert_eq!(ics.name.as_deref(), Some("New York Stock Exchange"));
+        assert_eq!(ics.timezone, Some(chrono_tz::America::New_York));
+        assert_eq!(ics.years(), Some(2024..=2026));
+        assert!(ics.diff(&nyse).unwrap().is_empty());
+
+        // The exported closures rebuild the same calendar
+        let regular = Session::new(time(9, 30), time(16, 0)).unwrap();
+        let rebuilt = ics.into_calendar(regular).unwrap();
+        assert_eq!(rebuilt.market(), Market::Custom);
+        for year in 2024..=2026 {
+            let dates = |cal: &TradingCalendar| -> Vec<NaiveDate> {
+                cal.holidays(year)
+                    .unwrap()
+                    .into_iter()
+                    .map(|h| h.date)
+                    .collect()
+            };
+            assert_eq!(dates(&rebuilt), dates(&nyse));
+        }
+        assert_eq!(
+            rebuilt.trading_hours(date(2025, 12, 24)).market_close(),
+            time(13, 0)
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_differences() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+        let ics = IcsCalendar::parse(&document(
+            "BEGIN:VEVENT\r\n\
+             DTSTART;VALUE=DATE:20250101\r\n\
+             SUMMARY:New Year's Day\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             DTSTART;TZID=America/New_York:20251224T120000\r\n\
+             SUMMARY:Christmas Eve\r\n\
+             END:VEVENT\r\n",
+        ))
+        .unwrap();
+
+        let diff = ics.diff(&nyse).unwrap();
+        assert!(diff.missing_closures.is_empty());
+        assert_eq!(diff.extra_closures.len(), 10);
+        assert_eq!(diff.missing_early_closes[0].early_close, Some(time(12, 0)));
+        assert_eq!(diff.extra_early_closes.len(), 3);
+    }
+
+    #[test]
+    fn test_event_forms() {
+        let ics = IcsCalendar::parse(&document(
+            "BEGIN:VEVENT\r\n\
+             DTSTART;VALUE=DATE:20250414\r\n\
+             DTEND;VALUE=DATE:20250417\r\n\
+             SUMMARY:Systems migration\\, phase 1\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             DTSTART:20250418T170000Z\r\n\
+             SUMMARY:Early close\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             STATUS:CANCELLED\r\n\
+             DTSTART;VALUE=DATE:20250501\r\n\
+             END:VEVENT\r\n",
+        ))
+        .unwrap();
+
+        let dates: Vec<_> = ics.closures.iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [date(2025, 4, 14), date(2025, 4, 15), date(2025, 4, 16)]
+        );
+        assert_eq!(ics.closures[0].name, "Systems migration, phase 1");
+        assert_eq!(ics.early_closes[0].early_close, Some(time(13, 0)));
+    }
+
+    #[test]
+    fn test_apply_converts_timezones() {
+        let ics = IcsCalendar::parse(&document(
+            "BEGIN:VEVENT\r\n\
+             DTSTART;TZID=Europe/London:20250312T120000\r\n\
+             SUMMARY:Early close\r\n\
+             END:VEVENT\r\n",
+        ))
+        .unwrap();
+        // 12:00 in London is 08:00 in New York on March 12th, 2025
+        assert_eq!(ics.early_closes[0].early_close, Some(time(8, 0)));
+
+        let lse = ics
+            .apply(TradingCalendar::builder(Market::LSE))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            lse.trading_hours(date(2025, 3, 12)).market_close(),
+            time(12, 0)
+        );
+
+        // A closure on the weekend is skipped rather than rejected
+        let ics = IcsCalendar::parse(&document(
+            "BEGIN:VEVENT\r\n\
+             DTSTART;VALUE=DATE:20250315\r\n\
+             SUMMARY:Saturday closure\r\n\
+             END:VEVENT\r\n",
+        ))
+        .unwrap();
+        let nyse = ics
+            .apply(TradingCalendar::builder(Market::NYSE))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(nyse.holiday_info(date(2025, 3, 15)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let error = |input: &str| IcsCalendar::parse(input).unwrap_err();
+
+        assert!(matches!(
+            error("BEGIN:VEVENT\r\nEND:VEVENT\r\n"),
+            CalendarError::MalformedIcs { line: 1, .. }
+        ));
+        assert!(matches!(
+            error("BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nEND:VCALENDAR\r\n"),
+            CalendarError::MalformedIcs { line: 3, .. }
+        ));
+        assert!(matches!(
+            error("BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\n"),
+            CalendarError::MalformedIcs { line: 2, .. }
+        ));
+        assert!(matches!(
+            error(&document("BEGIN:VEVENT\r\nSUMMARY\r\nEND:VEVENT\r\n")),
+            CalendarError::MalformedIcs { line: 5, .. }
+        ));
+        assert!(matches!(error(""), CalendarError::MalformedIcs { .. }));
+
+        assert!(matches!(
+            error(&document(
+                "BEGIN:VEVENT\r\nSUMMARY:No date\r\nEND:VEVENT\r\n"
+            )),
+            CalendarError::UnsupportedIcsEvent { line: 4, .. }
+        ));
+        assert!(matches!(
+            error(&document(
+                "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20250230\r\nEND:VEVENT\r\n"
+            )),
+            CalendarError::UnsupportedIcsEvent { line: 5, .. }
+        ));
+        assert!(matches!(
+            error(&document(
+                "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20250101\r\nRRULE:FREQ=YEARLY\r\nEND:VEVENT\r\n"
+            )),
+            CalendarError::UnsupportedIcsEvent { .. }
+        ));
+        assert!(matches!(
+            error(&document(
+                "BEGIN:VEVENT\r\nDTSTART;TZID=Mars/Olympus:20250101T120000\r\nEND:VEVENT\r\n"
+            )),
+            CalendarError::UnsupportedIcsEvent { .. }
+        ));
+    }
+}
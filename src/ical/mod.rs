@@ -0,0 +1,102 @@
+//! iCalendar (RFC 5545) support
+//!
+//! Exports a market's closures and half days as `VEVENT`s so they can be
+//! published to shared calendars. Closures become all-day events; early
+//! closes become timed events in the market's timezone, running from the
+//! early close to the regular close, with a matching `VTIMEZONE`.
+//!
+//! Imports documents the same way round with [`IcsCalendar`]: all-day events
+//! are read as closures and timed events as early closes. The result can
+//! patch an existing market, stand alone as a custom calendar, or be diffed
+//! against the crate's computed rules to check them against an exchange's
+//! published file.
+//!
+//! # Example
+//!
+//! ```
+//! use trading_calendar::{Market, TradingCalendar};
+//!
+//! let nyse = TradingCalendar::new(Market::NYSE)?;
+//! let ics = nyse.to_ics(2025..=2026)?;
+//! assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+//! assert!(ics.contains("DTSTART;VALUE=DATE:20251225\r\n"));
+//! assert!(ics.contains("DTSTART;TZID=America/New_York:20251224T130000\r\n"));
+//! # Ok::<(), trading_calendar::CalendarError>(())
+//! ```
+
+mod export;
+mod import;
+
+pub use export::IcsExporter;
+pub use import::{IcsCalendar, IcsDiff};
+
+/// Maximum length of a content line in octets, excluding the line break
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Escape a TEXT property value
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folding it at 75 octets without splitting a
+/// UTF-8 sequence
+fn push_line(output: &mut String, line: &str) {
+    let mut start = 0;
+    let mut limit = MAX_LINE_OCTETS;
+    while line.len() - start > limit {
+        let mut end = start + limit;
+        while !line.is_char_boundary(end) {
+            end -= 1;
+        }
+        output.push_str(&line[start..end]);
+        output.push_str("\r\n ");
+        start = end;
+        // Continuation lines begin with a space, which counts toward the limit
+        limit = MAX_LINE_OCTETS - 1;
+    }
+    output.push_str(&line[start..]);
+    output.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(
+            escape_text("Closed; see notice, \\ details\nhere"),
+            "Closed\\; see notice\\, \\\\ details\\nhere"
+        );
+    }
+
+    #[test]
+    fn test_line_folding() {
+        let mut output = String::new();
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        push_line(&mut output, &line);
+
+        let lines: Vec<&str> = output.split("\r\n").filter(|l| !l.is_empty()).collect();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|l| l.len() <= MAX_LINE_OCTETS));
+        assert!(lines[1..].iter().all(|l| l.starts_with(' ')));
+
+        let unfolded: String = lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| if i == 0 { *l } else { &l[1..] })
+            .collect();
+        assert_eq!(unfolded, line);
+    }
+}
@@ -0,0 +1,109 @@
+//! Per-year index of trading days
+//!
+//! Trading-day arithmetic on [`TradingCalendar`](crate::TradingCalendar)
+//! looks dates up in a table built once per year instead of stepping one
+//! day at a time, so moving a date by a year of trading days touches only
+//! a handful of tables.
+
+use chrono::{Datelike, NaiveDate};
+use dashmap::DashMap;
+use std::sync::Arc;
+
+/// Lazily built trading day tables, one per year
+#[derive(Default)]
+pub(crate) struct TradingDayIndex {
+    years: DashMap<i32, Arc<YearIndex>>,
+}
+
+impl TradingDayIndex {
+    /// Get the table for a year, building it with `is_trading_day` if needed
+    pub(crate) fn year<F>(&self, year: i32, is_trading_day: F) -> Arc<YearIndex>
+    where
+        F: Fn(NaiveDate) -> bool,
+    {
+        if let Some(index) = self.years.get(&year) {
+            return Arc::clone(&index);
+        }
+        // Build outside the map's lock; a concurrent build of the same year
+        // produces an identical table, so either may win
+        let index = Arc::new(YearIndex::build(year, is_trading_day));
+        Arc::clone(self.years.entry(year).or_insert(index).value())
+    }
+}
+
+/// The trading days of one year
+pub(crate) struct YearIndex {
+    /// Trading days in order
+    days: Vec<NaiveDate>,
+    /// Number of trading days before each day of the year, by ordinal
+    before: Vec<u16>,
+}
+
+impl YearIndex {
+    fn build<F>(year: i32, is_trading_day: F) -> Self
+    where
+        F: Fn(NaiveDate) -> bool,
+    {
+        let mut days = Vec::with_capacity(262);
+        let mut before = Vec::with_capacity(366);
+        let mut date = NaiveDate::from_yo_opt(year, 1);
+        while let Some(current) = date.filter(|current| current.year() == year) {
+            before.push(days.len() as u16);
+            if is_trading_day(current) {
+                days.push(current);
+            }
+            date = current.succ_opt();
+        }
+        YearIndex { days, before }
+    }
+
+    /// Trading days in order
+    pub(crate) fn days(&self) -> &[NaiveDate] {
+        &self.days
+    }
+
+    /// Number of trading days in the year
+    pub(crate) fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    /// Number of trading days in the year strictly before `date`
+    ///
+    /// `date` must fall in the indexed year.
+    pub(crate) fn before(&self, date: NaiveDate) -> usize {
+        usize::from(self.before[date.ordinal0() as usize])
+    }
+
+    /// Whether `date` is a trading day
+    pub(crate) fn contains(&self, date: NaiveDate) -> bool {
+        self.days.get(self.before(date)) == Some(&date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Weekday};
+
+    fn weekday(date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    #[test]
+    fn test_year_index() {
+        let index = TradingDayIndex::default();
+        let year = index.year(2024, weekday);
+        assert_eq!(year.len(), 262);
+
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        assert_eq!(year.before(date(1, 1)), 0);
+        assert_eq!(year.before(date(1, 8)), 5);
+        assert_eq!(year.before(date(12, 31)), 261);
+        assert!(year.contains(date(12, 31)));
+        assert!(!year.contains(date(12, 29)));
+        assert_eq!(year.days()[5], date(1, 8));
+
+        // Tables are built once
+        assert!(Arc::ptr_eq(&year, &index.year(2024, |_| false)));
+    }
+}
@@ -8,14 +8,16 @@
 //!
 //! ## Features
 //!
-//! - 🌍 **Multiple Markets**: NYSE, NASDAQ, LSE, TSE, TSX
+//! - 🌍 **Multiple Markets**: NYSE, NASDAQ, LSE, TSE, TSX, Euronext, Xetra, SIX, Borsa
+//!   Italiana, BME, Nasdaq Nordic, HKEX, SSE/SZSE, KRX, ASX, NSE, SGX, Saudi Exchange, DFM,
+//!   ADX, JSE, EGX, B3, BMV, CME Globex, Cboe
 //! - ⏰ **Trading Hours**: Regular, pre-market, and after-hours sessions
 //! - 📅 **Holiday Detection**: All market holidays with weekend adjustments
 //! - 🕐 **Early Closes**: Half-day schedules (Christmas Eve, Black Friday, etc.)
 //! - 🌐 **Timezone Support**: Automatic handling of market timezones
 //! - 🚀 **Performance**: Efficient LRU caching
 //! - 🔒 **Thread Safe**: Concurrent access support
-//! - 📆 **2020-2030 Support**: Comprehensive holiday calendars
+//! - 📆 **1990-2100 Support**: Rule-based holiday calendars with historical rule eras
 //!
 //! ## Quick Start
 //!
@@ -84,8 +86,31 @@
 //! | NYSE | 9:30 AM - 4:00 PM ET | 4:00 AM - 9:30 AM | 4:00 PM - 8:00 PM | ✅ Full Support |
 //! | NASDAQ | 9:30 AM - 4:00 PM ET | 4:00 AM - 9:30 AM | 4:00 PM - 8:00 PM | ✅ Full Support |
 //! | LSE | 8:00 AM - 4:30 PM GMT | - | - | ✅ Full Support |
-//! | TSE | 9:00 AM - 3:00 PM JST | - | - | ✅ Full Support |
+//! | TSE | 9:00 AM - 3:30 PM JST (lunch 11:30 AM - 12:30 PM) | - | - | ✅ Full Support |
 //! | TSX | 9:30 AM - 4:00 PM ET | - | - | ✅ Full Support |
+//! | EURONEXT | 9:00 AM - 5:30 PM CET (Lisbon an hour earlier locally) | - | - | ✅ Full Support |
+//! | XETRA | 9:00 AM - 5:30 PM CET | - | - | ✅ Full Support |
+//! | SIX | 9:00 AM - 5:30 PM CET | - | - | ✅ Full Support |
+//! | BIT | 9:00 AM - 5:30 PM CET | - | - | ✅ Full Support |
+//! | BME | 9:00 AM - 5:30 PM CET | - | - | ✅ Full Support |
+//! | OMXS | 9:00 AM - 5:30 PM CET | - | - | ✅ Full Support |
+//! | OMXH | 10:00 AM - 6:30 PM EET | - | - | ✅ Full Support |
+//! | OMXC | 9:00 AM - 5:00 PM CET | - | - | ✅ Full Support |
+//! | HKEX | 9:30 AM - 4:00 PM HKT (lunch 12:00 PM - 1:00 PM) | - | - | ✅ Full Support |
+//! | SSE/SZSE | 9:30 AM - 3:00 PM CST (lunch 11:30 AM - 1:00 PM) | - | - | ✅ Full Support |
+//! | KRX | 9:00 AM - 3:30 PM KST | - | - | ✅ Full Support |
+//! | ASX | 10:00 AM - 4:00 PM AET | - | - | ✅ Full Support |
+//! | NSE | 9:15 AM - 3:30 PM IST | 9:00 AM - 9:15 AM (pre-open) | - | ✅ Full Support |
+//! | SGX | 9:00 AM - 5:00 PM SGT (lunch 12:00 PM - 1:00 PM) | - | - | ✅ Full Support |
+//! | TADAWUL | 10:00 AM - 3:00 PM AST, Sunday to Thursday | 9:30 AM - 10:00 AM (pre-open) | - | ✅ Full Support |
+//! | DFM/ADX | 10:00 AM - 3:00 PM GST | - | - | ✅ Full Support |
+//! | JSE | 9:00 AM - 5:00 PM SAST | - | - | ✅ Full Support |
+//! | EGX | 10:00 AM - 2:30 PM EET, Sunday to Thursday | - | - | ✅ Full Support |
+//! | B3 | 10:00 AM - 5:00 PM BRT (1:00 PM open on Ash Wednesday) | - | - | ✅ Full Support |
+//! | BMV | 8:30 AM - 3:00 PM CST | - | - | ✅ Full Support |
+//! | CME | 8:30 AM - 3:15 PM CT | 5:00 PM (previous day) - 8:30 AM | 3:15 PM - 4:00 PM | ✅ Full Support |
+//! | CBOE | 9:30 AM - 4:00 PM ET | - | - | ✅ Full Support |
+//! | CBOE_INDEX | 9:30 AM - 4:15 PM ET | - | - | ✅ Full Support |
 //!
 //! ## Thread Safety
 //!
@@ -119,7 +144,7 @@
 //!     let calendar = TradingCalendar::new(Market::NYSE)?;
 //!
 //!     // Check for unsupported years
-//!     match calendar.is_trading_day(chrono::NaiveDate::from_ymd_opt(2019, 1, 1).unwrap()) {
+//!     match calendar.is_trading_day(chrono::NaiveDate::from_ymd_opt(1989, 1, 1).unwrap()) {
 //!         Ok(is_trading) => println!("Is trading day: {}", is_trading),
 //!         Err(CalendarError::DateOutOfRange(date)) => println!("Date {} not supported", date),
 //!         Err(e) => eprintln!("Error: {}", e),
@@ -149,27 +174,43 @@
 #![deny(unsafe_code)]
 #![forbid(unsafe_code)]
 
+pub mod builder;
 pub mod calendar;
+pub mod clock;
+pub mod composite;
 pub mod constants;
+pub mod conventions;
+#[cfg(feature = "definitions")]
+pub mod definition;
 pub mod error;
+#[cfg(feature = "ical")]
+pub mod ical;
+mod index;
 pub mod markets;
 pub mod schedule;
+pub mod settlement;
 pub mod utils;
 
 // Re-export main types
+pub use builder::TradingCalendarBuilder;
 pub use calendar::TradingCalendar;
+pub use clock::Clock;
+pub use composite::{CompositeCalendar, JoinRule};
+pub use conventions::BusinessDayConvention;
+#[cfg(feature = "definitions")]
+pub use definition::CalendarDefinition;
 pub use error::{CalendarError, Result};
-pub use markets::Market;
-pub use schedule::{Session, TradingHours};
+pub use markets::{Market, WeekendMask};
+pub use schedule::{Session, SessionKind, SessionLocation, SessionWindow, TradingHours};
 
 // Re-export chrono types for convenience
 pub use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 
 // Global constants
-/// Minimum supported year
-pub const MIN_YEAR: i32 = 2020;
-/// Maximum supported year
-pub const MAX_YEAR: i32 = 2030;
+/// Minimum year covered by the built-in holiday rules
+pub const MIN_YEAR: i32 = 1990;
+/// Maximum year covered by the built-in holiday rules
+pub const MAX_YEAR: i32 = 2100;
 
 /// Holiday information
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -209,3 +250,97 @@ impl Holiday {
         }
     }
 }
+
+/// A one-off closure or early close outside a market's regular holiday rules
+///
+/// Special days cover events such as national days of mourning, weather
+/// closures and royal occasions. They are merged into the market's holiday
+/// and early close listings; `source` records who announced the change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct SpecialDay {
+    /// The date of the closure
+    pub date: NaiveDate,
+    /// The reason for the closure
+    pub name: &'static str,
+    /// The authority or announcement the closure is based on
+    pub source: &'static str,
+    /// Early close time, or `None` if the market was closed all day
+    pub early_close: Option<NaiveTime>,
+}
+
+impl SpecialDay {
+    /// Create a full-day special closure
+    pub const fn closed(date: NaiveDate, name: &'static str, source: &'static str) -> Self {
+        SpecialDay {
+            date,
+            name,
+            source,
+            early_close: None,
+        }
+    }
+
+    /// Create a special early close
+    pub const fn early_close(
+        date: NaiveDate,
+        close: NaiveTime,
+        name: &'static str,
+        source: &'static str,
+    ) -> Self {
+        SpecialDay {
+            date,
+            name,
+            source,
+            early_close: Some(close),
+        }
+    }
+
+    /// Whether the market was closed for the whole day
+    pub fn is_closed(&self) -> bool {
+        self.early_close.is_none()
+    }
+
+    /// Convert into a `Holiday` entry
+    pub fn to_holiday(&self) -> Holiday {
+        match self.early_close {
+            Some(close) => Holiday::with_early_close(self.date, self.name, close),
+            None => Holiday::new(self.date, self.name, true),
+        }
+    }
+}
+
+/// A weekend day declared an official working day
+///
+/// Governments move working days onto weekends to bridge public holidays
+/// (China's make-up working days) or to hold one-off sessions. Banks and
+/// offices open on such days; `trading` records whether the exchange does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+pub struct WorkingDay {
+    /// The date of the working day
+    pub date: NaiveDate,
+    /// The reason for the working day
+    pub name: &'static str,
+    /// Whether the exchange holds a session
+    pub trading: bool,
+}
+
+impl WorkingDay {
+    /// Create a working day on which the exchange trades
+    pub const fn trading(date: NaiveDate, name: &'static str) -> Self {
+        WorkingDay {
+            date,
+            name,
+            trading: true,
+        }
+    }
+
+    /// Create a working day on which the exchange stays closed
+    pub const fn closed(date: NaiveDate, name: &'static str) -> Self {
+        WorkingDay {
+            date,
+            name,
+            trading: false,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Australian holiday rules and calculations
+//!
+//! ASX follows the New South Wales public holidays it is subject to. New
+//! Year's Day, Australia Day and the Christmas holidays move off weekends;
+//! Anzac Day does not.
+
+use crate::constants::AU_EARLY_CLOSE;
+use crate::markets::is_weekend;
+use crate::utils::{calculate_easter_monday, calculate_good_friday, nth_weekday_of_month};
+use crate::Holiday;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for, when Australia Day began
+/// to be observed on the day itself rather than the following Monday
+pub const ASX_FIRST_YEAR: i32 = 1994;
+
+/// Get all ASX holidays for a given year
+pub fn get_asx_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_asx_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all ASX holidays for a given year with their names, sorted by date
+pub fn get_asx_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut holidays = vec![
+        Holiday::new(next_monday(fixed(1, 1)), "New Year's Day", true),
+        Holiday::new(next_monday(fixed(1, 26)), "Australia Day", true),
+    ];
+
+    if let Ok(date) = calculate_good_friday(year) {
+        holidays.push(Holiday::new(date, "Good Friday", true));
+    }
+    if let Ok(date) = calculate_easter_monday(year) {
+        holidays.push(Holiday::new(date, "Easter Monday", true));
+    }
+
+    // Anzac Day is not moved off a weekend
+    let anzac = fixed(4, 25);
+    if !is_weekend(anzac) {
+        holidays.push(Holiday::new(anzac, "Anzac Day", true));
+    }
+
+    // Sovereign's Birthday (second Monday of June)
+    let sovereign = if year >= 2023 {
+        "King's Birthday"
+    } else {
+        "Queen's Birthday"
+    };
+    if let Some(date) = nth_weekday_of_month(year, 6, Weekday::Mon, 2) {
+        holidays.push(Holiday::new(date, sovereign, true));
+    }
+
+    // Christmas Day and Boxing Day take the following weekdays in turn
+    let mut next_free = fixed(12, 25);
+    for name in ["Christmas Day", "Boxing Day"] {
+        while is_weekend(next_free) {
+            next_free += Duration::days(1);
+        }
+        holidays.push(Holiday::new(next_free, name, true));
+        next_free += Duration::days(1);
+    }
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Get all ASX half days (14:10 close) for a given year, sorted by date
+///
+/// The last trading days before Christmas and before the new year close
+/// early.
+pub fn get_asx_early_closes(year: i32) -> Vec<Holiday> {
+    let holidays = get_asx_holidays(year);
+    let last_trading_day_before = |date: NaiveDate| {
+        let mut day = date - Duration::days(1);
+        while is_weekend(day) || holidays.contains(&day) {
+            day -= Duration::days(1);
+        }
+        day
+    };
+
+    let christmas = NaiveDate::from_ymd_opt(year, 12, 25).expect("Valid date");
+    let new_year = NaiveDate::from_ymd_opt(year + 1, 1, 1).expect("Valid date");
+    vec![
+        Holiday::with_early_close(
+            last_trading_day_before(christmas),
+            "Last trading day before Christmas",
+            AU_EARLY_CLOSE,
+        ),
+        Holiday::with_early_close(
+            last_trading_day_before(new_year),
+            "Last trading day of the year",
+            AU_EARLY_CLOSE,
+        ),
+    ]
+}
+
+fn next_monday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date + Duration::days(2),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_asx_holidays_2025() {
+        let dates: Vec<_> = get_asx_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 1, 27), // Australia Day on Sunday
+                date(2025, 4, 18),
+                date(2025, 4, 21),
+                date(2025, 4, 25),
+                date(2025, 6, 9),
+                date(2025, 12, 25),
+                date(2025, 12, 26),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekend_rules() {
+        // Anzac Day 2026 falls on a Saturday and is not moved
+        assert!(!get_asx_holidays(2026).contains(&date(2026, 4, 27)));
+
+        // Christmas 2021 on Saturday, Boxing Day on Sunday
+        let holidays = get_asx_holidays(2021);
+        assert!(holidays.contains(&date(2021, 12, 27)));
+        assert!(holidays.contains(&date(2021, 12, 28)));
+
+        let names: Vec<_> = get_asx_holiday_list(2022)
+            .into_iter()
+            .map(|h| h.name)
+            .collect();
+        assert!(names.contains(&"Queen's Birthday".to_string()));
+    }
+
+    #[test]
+    fn test_asx_early_closes() {
+        let early_closes = get_asx_early_closes(2025);
+        assert_eq!(early_closes[0].date, date(2025, 12, 24));
+        assert_eq!(early_closes[1].date, date(2025, 12, 31));
+        assert_eq!(early_closes[1].early_close, Some(AU_EARLY_CLOSE));
+
+        // 2022: Christmas Eve and New Year's Eve on Saturdays
+        let early_closes = get_asx_early_closes(2022);
+        assert_eq!(early_closes[0].date, date(2022, 12, 23));
+        assert_eq!(early_closes[1].date, date(2022, 12, 30));
+    }
+}
@@ -0,0 +1,72 @@
+//! Australian market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Australian market implementation (ASX)
+pub struct ASXMarket {
+    cache: HolidayCache,
+}
+
+impl ASXMarket {
+    /// Create a new ASX market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for ASXMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for ASXMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_asx_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_asx_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_asx_early_closes(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(AU_REGULAR_OPEN, AU_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Australia::Sydney
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::ASX_FIRST_YEAR..=MAX_YEAR
+    }
+}
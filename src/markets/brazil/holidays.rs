@@ -0,0 +1,107 @@
+//! Brazilian holiday rules and calculations
+//!
+//! B3 closes on the national holidays, on Carnival Monday and Tuesday and
+//! Corpus Christi, and on Christmas Eve and New Year's Eve. It opens late
+//! on Ash Wednesday. The São Paulo city and state holidays closed the
+//! exchange until 2021; Black Consciousness Day returned as a national
+//! holiday in 2024.
+
+use crate::markets::weekday_holidays;
+use crate::utils::easter::calculate_easter;
+use crate::Holiday;
+use chrono::{Duration, NaiveDate};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const B3_FIRST_YEAR: i32 = 2004;
+
+/// Last year B3 closed on the São Paulo city and state holidays
+const SAO_PAULO_HOLIDAYS_LAST_YEAR: i32 = 2021;
+
+/// First year Black Consciousness Day was a national holiday
+const BLACK_CONSCIOUSNESS_NATIONAL_YEAR: i32 = 2024;
+
+/// Get all B3 holidays for a given year
+pub fn get_b3_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_b3_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all B3 holidays for a given year with their names, sorted by date
+pub fn get_b3_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    let easter = |offset| {
+        calculate_easter(year)
+            .ok()
+            .map(|easter| easter + Duration::days(offset))
+    };
+    let sao_paulo = |month, day| fixed(month, day).filter(|_| year <= SAO_PAULO_HOLIDAYS_LAST_YEAR);
+    let black_consciousness = fixed(11, 20).filter(|_| {
+        year <= SAO_PAULO_HOLIDAYS_LAST_YEAR || year >= BLACK_CONSCIOUSNESS_NATIONAL_YEAR
+    });
+
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("São Paulo City Anniversary", sao_paulo(1, 25)),
+        ("Carnival Monday", easter(-48)),
+        ("Carnival Tuesday", easter(-47)),
+        ("Good Friday", easter(-2)),
+        ("Tiradentes Day", fixed(4, 21)),
+        ("Labour Day", fixed(5, 1)),
+        ("Corpus Christi", easter(60)),
+        ("Constitutionalist Revolution Day", sao_paulo(7, 9)),
+        ("Independence Day", fixed(9, 7)),
+        ("Our Lady of Aparecida", fixed(10, 12)),
+        ("All Souls' Day", fixed(11, 2)),
+        ("Republic Proclamation Day", fixed(11, 15)),
+        ("Black Consciousness Day", black_consciousness),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+/// Ash Wednesday (46 days before Easter), on which B3 opens at 13:00
+pub fn ash_wednesday(year: i32) -> Option<NaiveDate> {
+    calculate_easter(year)
+        .ok()
+        .map(|easter| easter - Duration::days(46))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_b3_holidays_2025() {
+        let dates: Vec<_> = get_b3_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 3, 3), // Carnival Monday
+                date(2025, 3, 4),
+                date(2025, 4, 18),
+                date(2025, 4, 21),
+                date(2025, 5, 1),
+                date(2025, 6, 19), // Corpus Christi
+                date(2025, 11, 20),
+                date(2025, 12, 24),
+                date(2025, 12, 25),
+                date(2025, 12, 31),
+            ]
+        );
+        assert_eq!(ash_wednesday(2025), Some(date(2025, 3, 5)));
+    }
+
+    #[test]
+    fn test_sao_paulo_holidays() {
+        assert!(get_b3_holidays(2021).contains(&date(2021, 1, 25)));
+        assert!(!get_b3_holidays(2022).contains(&date(2022, 1, 25)));
+        assert!(!get_b3_holidays(2023).contains(&date(2023, 11, 20)));
+        assert!(get_b3_holidays(2024).contains(&date(2024, 11, 20)));
+    }
+}
@@ -0,0 +1,72 @@
+//! Brazilian market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Brazilian market implementation (B3 - Brasil, Bolsa, Balcão)
+pub struct B3Market {
+    cache: HolidayCache,
+}
+
+impl B3Market {
+    /// Create a new B3 market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for B3Market {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for B3Market {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_b3_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_b3_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        // Trading opens late on Ash Wednesday, the day after Carnival
+        let open = if holidays::ash_wednesday(date.year()) == Some(date) {
+            BR_LATE_OPEN
+        } else {
+            BR_REGULAR_OPEN
+        };
+        TradingHours::new(
+            date,
+            Session::new_unchecked(open, BR_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::America::Sao_Paulo
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::B3_FIRST_YEAR..=MAX_YEAR
+    }
+}
@@ -1,83 +1,108 @@
 //! Canadian holiday rules and calculations
 
+use crate::constants::CA_EARLY_CLOSE;
+use crate::markets::is_weekend;
 use crate::utils::{calculate_good_friday, nth_weekday_of_month};
+use crate::Holiday;
 use chrono::{Datelike, NaiveDate, Weekday};
 use std::collections::HashSet;
 
 /// Get all Canadian holidays for a given year
 pub fn get_canada_holidays(year: i32) -> HashSet<NaiveDate> {
-    let mut holidays = HashSet::with_capacity(9); // Reduced from 12
+    get_canada_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all Canadian holidays for a given year with their names, sorted by date
+pub fn get_canada_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays = Vec::with_capacity(9);
 
     // New Year's Day
-    holidays.insert(adjust_for_weekend(
-        NaiveDate::from_ymd_opt(year, 1, 1).expect("Valid date"),
+    let new_year = NaiveDate::from_ymd_opt(year, 1, 1).expect("Valid date");
+    holidays.push(observed(
+        new_year,
+        adjust_for_weekend(new_year),
+        "New Year's Day",
     ));
 
     // Family Day - TSX observes this starting from 2008
     if year >= 2008 {
         if let Some(date) = nth_weekday_of_month(year, 2, Weekday::Mon, 3) {
-            holidays.insert(date);
+            holidays.push(Holiday::new(date, "Family Day", true));
         }
     }
 
     // Good Friday
     if let Ok(date) = calculate_good_friday(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Good Friday", true));
     }
 
     // Victoria Day (Monday on or before May 24)
     if let Some(date) = victoria_day(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Victoria Day", true));
     }
 
     // Canada Day (July 1)
-    holidays.insert(adjust_for_weekend(
-        NaiveDate::from_ymd_opt(year, 7, 1).expect("Valid date"),
+    let canada_day = NaiveDate::from_ymd_opt(year, 7, 1).expect("Valid date");
+    holidays.push(observed(
+        canada_day,
+        adjust_for_weekend(canada_day),
+        "Canada Day",
     ));
 
     // NO Civic Holiday - TSX is OPEN
 
     // Labour Day (1st Monday of September)
     if let Some(date) = nth_weekday_of_month(year, 9, Weekday::Mon, 1) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Labour Day", true));
     }
 
     // Thanksgiving (2nd Monday of October)
     if let Some(date) = nth_weekday_of_month(year, 10, Weekday::Mon, 2) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Thanksgiving Day", true));
     }
 
     // Christmas Day
     let christmas = NaiveDate::from_ymd_opt(year, 12, 25).expect("Valid date");
-    holidays.insert(adjust_for_weekend(christmas));
+    let observed_christmas = adjust_for_weekend(christmas);
+    holidays.push(observed(christmas, observed_christmas, "Christmas Day"));
 
-    // Boxing Day (special rules for Canada)
+    // Boxing Day: the next weekday after Christmas is observed
     let boxing = NaiveDate::from_ymd_opt(year, 12, 26).expect("Valid date");
-    match (christmas.weekday(), boxing.weekday()) {
-        (Weekday::Fri, Weekday::Sat) => {
-            // Christmas on Friday, Boxing Day on Saturday
-            // Christmas observed on Friday, Boxing Day observed on Monday
-            holidays.insert(boxing + chrono::Duration::days(2));
-        }
-        (Weekday::Sat, Weekday::Sun) => {
-            // Christmas on Saturday, Boxing Day on Sunday
-            // Christmas observed on Monday, Boxing Day observed on Tuesday
-            holidays.insert(boxing + chrono::Duration::days(2));
-        }
-        (_, Weekday::Sat) => {
-            holidays.insert(boxing + chrono::Duration::days(2));
-        }
-        (_, Weekday::Sun) => {
-            holidays.insert(boxing + chrono::Duration::days(1));
-        }
-        _ => {
-            holidays.insert(boxing);
-        }
-    };
+    let mut observed_boxing = boxing;
+    while is_weekend(observed_boxing) || observed_boxing == observed_christmas {
+        observed_boxing += chrono::Duration::days(1);
+    }
+    holidays.push(observed(boxing, observed_boxing, "Boxing Day"));
 
+    holidays.sort_by_key(|holiday| holiday.date);
     holidays
 }
 
+/// Get all TSX early close days (13:00 close) for a given year, sorted by date
+pub fn get_canada_early_closes(year: i32) -> Vec<Holiday> {
+    let christmas_eve = NaiveDate::from_ymd_opt(year, 12, 24).expect("Valid date");
+    if is_weekend(christmas_eve) || get_canada_holidays(year).contains(&christmas_eve) {
+        return Vec::new();
+    }
+    vec![Holiday::with_early_close(
+        christmas_eve,
+        "Christmas Eve",
+        CA_EARLY_CLOSE,
+    )]
+}
+
+/// Name a holiday, marking it as observed when it was moved
+fn observed(actual: NaiveDate, observed: NaiveDate, name: &str) -> Holiday {
+    if actual == observed {
+        Holiday::new(observed, name, true)
+    } else {
+        Holiday::new(observed, &format!("{name} (observed)"), true)
+    }
+}
+
 fn victoria_day(year: i32) -> Option<NaiveDate> {
     let may_24 = NaiveDate::from_ymd_opt(year, 5, 24)?;
     let mut victoria = may_24;
@@ -165,5 +190,41 @@ mod tests {
         let holidays_2022 = get_canada_holidays(2022);
         // Christmas Day 2022 was Sunday, so should be observed on Monday Dec 26
         assert!(holidays_2022.contains(&NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()));
+        // ... and Boxing Day on Tuesday Dec 27
+        assert!(holidays_2022.contains(&NaiveDate::from_ymd_opt(2022, 12, 27).unwrap()));
+    }
+
+    #[test]
+    fn test_canada_holiday_names() {
+        let holidays = get_canada_holiday_list(2025);
+        assert_eq!(holidays.len(), 9);
+
+        let victoria = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2025, 5, 19).unwrap())
+            .unwrap();
+        assert_eq!(victoria.name, "Victoria Day");
+
+        // Canada Day 2023 was a Saturday, observed on Monday
+        let holidays = get_canada_holiday_list(2023);
+        let canada_day = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2023, 7, 3).unwrap())
+            .unwrap();
+        assert_eq!(canada_day.name, "Canada Day (observed)");
+    }
+
+    #[test]
+    fn test_canada_early_closes() {
+        let early_closes = get_canada_early_closes(2025);
+        assert_eq!(early_closes.len(), 1);
+        assert_eq!(
+            early_closes[0].date,
+            NaiveDate::from_ymd_opt(2025, 12, 24).unwrap()
+        );
+        assert_eq!(early_closes[0].early_close, Some(CA_EARLY_CLOSE));
+
+        // Christmas Eve 2023 was a Sunday
+        assert!(get_canada_early_closes(2023).is_empty());
     }
 }
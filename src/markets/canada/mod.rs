@@ -5,7 +5,7 @@ pub mod holidays;
 use crate::constants::*;
 use crate::markets::MarketImpl;
 use crate::utils::HolidayCache;
-use crate::{Session, TradingHours};
+use crate::{Holiday, Session, TradingHours};
 use chrono::{Datelike, NaiveDate};
 use chrono_tz::Tz;
 
@@ -38,16 +38,30 @@ impl MarketImpl for TSXMarket {
         holidays.contains(&date)
     }
 
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_canada_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_canada_early_closes(year)
+    }
+
     fn trading_hours(&self, date: NaiveDate) -> TradingHours {
-        TradingHours::new(
+        let mut hours = TradingHours::new(
             date,
             Session::new_unchecked(CA_REGULAR_OPEN, CA_REGULAR_CLOSE),
             None, // No pre-market
             None, // No after-hours
-        )
+        );
+        hours.early_close = self.early_close(date);
+        hours
     }
 
     fn timezone(&self) -> Tz {
         chrono_tz::America::Toronto
     }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
 }
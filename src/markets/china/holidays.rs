@@ -0,0 +1,173 @@
+//! Chinese (Shanghai/Shenzhen) holiday rules and calculations
+//!
+//! Public holidays on the mainland are set each year by the State Council,
+//! which joins them into week-long breaks by moving working days onto the
+//! neighbouring weekends. The exchanges close on the weekdays of each break
+//! and stay closed on the make-up weekends, which are listed as working
+//! days for settlement and banking purposes.
+
+use crate::constants::date;
+use crate::markets::is_weekend;
+use crate::{Holiday, WorkingDay};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const CHINA_FIRST_YEAR: i32 = 2023;
+
+/// Last year the holiday rules are defined for
+pub const CHINA_LAST_YEAR: i32 = 2026;
+
+/// Holiday periods as announced by the State Council and the exchanges
+///
+/// Each entry is the first and last calendar day of a break; the
+/// exchanges are closed on its weekdays.
+const CN_HOLIDAY_PERIODS: &[(&str, NaiveDate, NaiveDate)] = &[
+    ("New Year's Day", date(2023, 1, 2), date(2023, 1, 2)),
+    ("Spring Festival", date(2023, 1, 21), date(2023, 1, 27)),
+    ("Qingming Festival", date(2023, 4, 5), date(2023, 4, 5)),
+    ("Labour Day", date(2023, 4, 29), date(2023, 5, 3)),
+    ("Dragon Boat Festival", date(2023, 6, 22), date(2023, 6, 24)),
+    (
+        "Mid-Autumn Festival and National Day",
+        date(2023, 9, 29),
+        date(2023, 10, 6),
+    ),
+    ("New Year's Day", date(2024, 1, 1), date(2024, 1, 1)),
+    ("Spring Festival", date(2024, 2, 9), date(2024, 2, 17)),
+    ("Qingming Festival", date(2024, 4, 4), date(2024, 4, 6)),
+    ("Labour Day", date(2024, 5, 1), date(2024, 5, 5)),
+    ("Dragon Boat Festival", date(2024, 6, 10), date(2024, 6, 10)),
+    ("Mid-Autumn Festival", date(2024, 9, 15), date(2024, 9, 17)),
+    ("National Day", date(2024, 10, 1), date(2024, 10, 7)),
+    ("New Year's Day", date(2025, 1, 1), date(2025, 1, 1)),
+    ("Spring Festival", date(2025, 1, 28), date(2025, 2, 4)),
+    ("Qingming Festival", date(2025, 4, 4), date(2025, 4, 6)),
+    ("Labour Day", date(2025, 5, 1), date(2025, 5, 5)),
+    ("Dragon Boat Festival", date(2025, 5, 31), date(2025, 6, 2)),
+    (
+        "National Day and Mid-Autumn Festival",
+        date(2025, 10, 1),
+        date(2025, 10, 8),
+    ),
+    ("New Year's Day", date(2026, 1, 1), date(2026, 1, 3)),
+    ("Spring Festival", date(2026, 2, 15), date(2026, 2, 23)),
+    ("Qingming Festival", date(2026, 4, 4), date(2026, 4, 6)),
+    ("Labour Day", date(2026, 5, 1), date(2026, 5, 5)),
+    ("Dragon Boat Festival", date(2026, 6, 19), date(2026, 6, 21)),
+    ("Mid-Autumn Festival", date(2026, 9, 25), date(2026, 9, 27)),
+    ("National Day", date(2026, 10, 1), date(2026, 10, 7)),
+];
+
+/// Weekend days the State Council moved working days onto
+///
+/// Banks and offices open on these days; the exchanges stay closed.
+pub const CN_WORKING_DAYS: &[WorkingDay] = &[
+    WorkingDay::closed(date(2023, 1, 28), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2023, 1, 29), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2023, 4, 23), "Labour Day make-up working day"),
+    WorkingDay::closed(date(2023, 5, 6), "Labour Day make-up working day"),
+    WorkingDay::closed(
+        date(2023, 6, 25),
+        "Dragon Boat Festival make-up working day",
+    ),
+    WorkingDay::closed(date(2023, 10, 7), "National Day make-up working day"),
+    WorkingDay::closed(date(2023, 10, 8), "National Day make-up working day"),
+    WorkingDay::closed(date(2024, 2, 4), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2024, 2, 18), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2024, 4, 7), "Qingming Festival make-up working day"),
+    WorkingDay::closed(date(2024, 4, 28), "Labour Day make-up working day"),
+    WorkingDay::closed(date(2024, 5, 11), "Labour Day make-up working day"),
+    WorkingDay::closed(date(2024, 9, 14), "Mid-Autumn Festival make-up working day"),
+    WorkingDay::closed(date(2024, 9, 29), "National Day make-up working day"),
+    WorkingDay::closed(date(2024, 10, 12), "National Day make-up working day"),
+    WorkingDay::closed(date(2025, 1, 26), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2025, 2, 8), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2025, 4, 27), "Labour Day make-up working day"),
+    WorkingDay::closed(date(2025, 9, 28), "National Day make-up working day"),
+    WorkingDay::closed(date(2025, 10, 11), "National Day make-up working day"),
+    WorkingDay::closed(date(2026, 1, 4), "New Year's Day make-up working day"),
+    WorkingDay::closed(date(2026, 2, 14), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2026, 2, 28), "Spring Festival make-up working day"),
+    WorkingDay::closed(date(2026, 5, 9), "Labour Day make-up working day"),
+    WorkingDay::closed(date(2026, 9, 20), "National Day make-up working day"),
+    WorkingDay::closed(date(2026, 10, 10), "National Day make-up working day"),
+];
+
+/// Get the make-up working days of a given year, sorted by date
+pub fn get_china_working_days(year: i32) -> Vec<WorkingDay> {
+    CN_WORKING_DAYS
+        .iter()
+        .filter(|day| day.date.year() == year)
+        .copied()
+        .collect()
+}
+
+/// Get all Shanghai/Shenzhen holidays for a given year
+pub fn get_china_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_china_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all Shanghai/Shenzhen holidays for a given year with their names,
+/// sorted by date
+pub fn get_china_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays = Vec::new();
+    for &(name, first, last) in CN_HOLIDAY_PERIODS {
+        let mut day = first;
+        while day <= last {
+            if day.year() == year && !is_weekend(day) {
+                holidays.push(Holiday::new(day, name, true));
+            }
+            day += Duration::days(1);
+        }
+    }
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_china_holidays_2025() {
+        let holidays = get_china_holiday_list(2025);
+        let spring: Vec<_> = holidays
+            .iter()
+            .filter(|h| h.name == "Spring Festival")
+            .map(|h| h.date)
+            .collect();
+        assert_eq!(
+            spring,
+            [
+                date(2025, 1, 28),
+                date(2025, 1, 29),
+                date(2025, 1, 30),
+                date(2025, 1, 31),
+                date(2025, 2, 3),
+                date(2025, 2, 4),
+            ]
+        );
+        assert_eq!(holidays.len(), 18);
+    }
+
+    #[test]
+    fn test_make_up_weekends_stay_closed() {
+        // Sunday January 26th and Saturday February 8th 2025 were make-up
+        // working days, but the exchanges do not trade on weekends
+        let holidays = get_china_holidays(2025);
+        assert!(!holidays.contains(&date(2025, 1, 26)));
+        assert!(!holidays.contains(&date(2025, 2, 8)));
+        assert!(!holidays.contains(&date(2025, 2, 5)));
+
+        let working_days = get_china_working_days(2025);
+        assert_eq!(working_days.len(), 5);
+        assert_eq!(working_days[1].date, date(2025, 2, 8));
+        assert!(CN_WORKING_DAYS
+            .iter()
+            .all(|day| is_weekend(day.date) && !day.trading));
+    }
+}
@@ -0,0 +1,75 @@
+//! Chinese market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, WorkingDay};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Chinese market implementation (Shanghai and Shenzhen stock exchanges)
+pub struct ChinaMarket {
+    cache: HolidayCache,
+}
+
+impl ChinaMarket {
+    /// Create a new Shanghai/Shenzhen market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for ChinaMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for ChinaMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_china_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_china_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(CN_REGULAR_OPEN, CN_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        // Lunch break between the morning and afternoon sessions
+        hours
+            .breaks
+            .push(Session::new_unchecked(CN_LUNCH_START, CN_LUNCH_END));
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Shanghai
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::CHINA_FIRST_YEAR..=holidays::CHINA_LAST_YEAR
+    }
+
+    fn special_working_days(&self, year: i32) -> Vec<WorkingDay> {
+        holidays::get_china_working_days(year)
+    }
+}
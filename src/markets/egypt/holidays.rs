@@ -0,0 +1,118 @@
+//! Egyptian holiday rules and calculations
+//!
+//! The Egyptian Exchange trades Sunday to Thursday and closes on the
+//! official holidays: the national days, Coptic Christmas, Sham El-Nessim
+//! (the Monday after Orthodox Easter) and the Islamic holidays, whose
+//! dates follow the Umm al-Qura calendar. The Prime Minister frequently
+//! moves a national day to a Thursday; such moves are recorded as
+//! announced dates.
+
+use crate::constants::date;
+use crate::markets::{apply_announced_periods, WeekendMask};
+use crate::utils::calculate_orthodox_easter;
+use crate::utils::hijri::{hijri_dates_in, hijri_days_in, DHU_AL_HIJJAH, SHAWWAL};
+use crate::Holiday;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const EGX_FIRST_YEAR: i32 = 2020;
+
+/// National days the Cabinet moved to another day
+const EG_ANNOUNCED_CLOSURES: &[(&str, NaiveDate, NaiveDate)] = &[
+    ("June 30 Revolution Day", date(2024, 7, 4), date(2024, 7, 4)),
+    (
+        "July 23 Revolution Day",
+        date(2024, 7, 25),
+        date(2024, 7, 25),
+    ),
+];
+
+/// Get the Egyptian Exchange weekend
+pub fn egx_weekend(_date: NaiveDate) -> WeekendMask {
+    WeekendMask::FRIDAY_SATURDAY
+}
+
+/// Get all EGX holidays for a given year
+pub fn get_egx_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_egx_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all EGX holidays for a given year with their names, sorted by date
+pub fn get_egx_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut rules = vec![
+        ("Coptic Christmas", fixed(1, 7)),
+        ("January 25 Revolution and Police Day", fixed(1, 25)),
+        ("Sinai Liberation Day", fixed(4, 25)),
+        ("Labour Day", fixed(5, 1)),
+        ("June 30 Revolution Day", fixed(6, 30)),
+        ("July 23 Revolution Day", fixed(7, 23)),
+        ("Armed Forces Day", fixed(10, 6)),
+    ];
+    if let Ok(easter) = calculate_orthodox_easter(year) {
+        rules.push(("Sham El-Nessim", easter + Duration::days(1)));
+    }
+    for day in hijri_days_in(year, SHAWWAL, 1, 3) {
+        rules.push(("Eid al-Fitr", day));
+    }
+    for day in hijri_dates_in(year, DHU_AL_HIJJAH, 9) {
+        rules.push(("Arafat Day", day));
+    }
+    for day in hijri_days_in(year, DHU_AL_HIJJAH, 10, 3) {
+        rules.push(("Eid al-Adha", day));
+    }
+    for day in hijri_dates_in(year, 1, 1) {
+        rules.push(("Islamic New Year", day));
+    }
+    for day in hijri_dates_in(year, 3, 12) {
+        rules.push(("Prophet's Birthday", day));
+    }
+    apply_announced_periods(&mut rules, EG_ANNOUNCED_CLOSURES, year);
+
+    let mut holidays: Vec<Holiday> = rules
+        .into_iter()
+        .filter(|(_, date)| date.year() == year && !egx_weekend(*date).contains(date.weekday()))
+        .map(|(name, date)| Holiday::new(date, name, true))
+        .collect();
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays.dedup_by_key(|holiday| holiday.date);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_egx_holidays_2025() {
+        let dates: Vec<_> = get_egx_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 7),
+                date(2025, 3, 30),
+                date(2025, 3, 31),
+                date(2025, 4, 1),
+                date(2025, 4, 21), // Sham El-Nessim
+                date(2025, 5, 1),
+                date(2025, 6, 5), // Arafat Day
+                date(2025, 6, 8),
+                date(2025, 6, 30),
+                date(2025, 7, 23),
+                date(2025, 10, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_announced_moves() {
+        let holidays = get_egx_holidays(2024);
+        assert!(holidays.contains(&date(2024, 7, 4)));
+        assert!(!holidays.contains(&date(2024, 7, 23)));
+        assert!(holidays.contains(&date(2024, 7, 25)));
+    }
+}
@@ -0,0 +1,70 @@
+//! Egyptian market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{MarketImpl, WeekendMask};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Egyptian market implementation (Egyptian Exchange)
+pub struct EGXMarket {
+    cache: HolidayCache,
+}
+
+impl EGXMarket {
+    /// Create a new EGX market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for EGXMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for EGXMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_egx_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_egx_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(EG_REGULAR_OPEN, EG_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Africa::Cairo
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::EGX_FIRST_YEAR..=MAX_YEAR
+    }
+
+    fn weekend(&self, date: NaiveDate) -> WeekendMask {
+        holidays::egx_weekend(date)
+    }
+}
@@ -0,0 +1,98 @@
+//! Euronext holiday rules and calculations
+//!
+//! Euronext Paris, Amsterdam, Brussels and Lisbon close on the same days,
+//! which match the TARGET2 closing days. Holidays on a weekend are not
+//! moved.
+
+use crate::constants::EURONEXT_EARLY_CLOSE;
+use crate::markets::{weekday_early_closes, weekday_holidays};
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::Holiday;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const EURONEXT_FIRST_YEAR: i32 = 2010;
+
+/// Get all Euronext holidays for a given year
+pub fn get_euronext_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_euronext_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all Euronext holidays for a given year with their names, sorted by date
+pub fn get_euronext_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Boxing Day", fixed(12, 26)),
+    ])
+}
+
+/// Get all Euronext half days (14:05 close) for a given year, sorted by date
+pub fn get_euronext_early_closes(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_early_closes(
+        [
+            ("Christmas Eve", fixed(12, 24)),
+            ("New Year's Eve", fixed(12, 31)),
+        ],
+        EURONEXT_EARLY_CLOSE,
+        &get_euronext_holiday_list(year),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_euronext_holidays_2025() {
+        let holidays = get_euronext_holiday_list(2025);
+        let dates: Vec<_> = holidays.iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 4, 18),
+                date(2025, 4, 21),
+                date(2025, 5, 1),
+                date(2025, 12, 25),
+                date(2025, 12, 26),
+            ]
+        );
+
+        // National holidays such as Bastille Day and King's Day are trading days
+        let holidays = get_euronext_holidays(2025);
+        assert!(!holidays.contains(&date(2025, 7, 14)));
+        assert!(!holidays.contains(&date(2025, 4, 28)));
+        assert!(!holidays.contains(&date(2025, 6, 9))); // Whit Monday
+    }
+
+    #[test]
+    fn test_weekend_holidays_are_not_moved() {
+        // Christmas 2022 on Sunday, Boxing Day on Monday
+        let holidays = get_euronext_holiday_list(2022);
+        assert!(holidays.iter().all(|h| h.date != date(2022, 12, 25)));
+        assert!(holidays.iter().any(|h| h.date == date(2022, 12, 26)));
+        assert!(!get_euronext_holidays(2022).contains(&date(2022, 12, 27)));
+    }
+
+    #[test]
+    fn test_euronext_early_closes() {
+        let early_closes = get_euronext_early_closes(2025);
+        assert_eq!(early_closes.len(), 2);
+        assert_eq!(early_closes[0].date, date(2025, 12, 24));
+        assert_eq!(early_closes[1].early_close, Some(EURONEXT_EARLY_CLOSE));
+
+        // 2022: both fell on Saturdays
+        assert!(get_euronext_early_closes(2022).is_empty());
+    }
+}
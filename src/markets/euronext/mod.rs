@@ -0,0 +1,75 @@
+//! Euronext market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Euronext market implementation (Paris, Amsterdam, Brussels, Lisbon)
+///
+/// Times are given in Paris time, which is also the time in Amsterdam and
+/// Brussels; Lisbon trades the same hours an hour earlier on its own clock.
+pub struct EuronextMarket {
+    cache: HolidayCache,
+}
+
+impl EuronextMarket {
+    /// Create a new Euronext market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for EuronextMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for EuronextMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_euronext_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_euronext_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_euronext_early_closes(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(EURONEXT_REGULAR_OPEN, EURONEXT_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Europe::Paris
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::EURONEXT_FIRST_YEAR..=MAX_YEAR
+    }
+}
@@ -0,0 +1,73 @@
+//! German (Xetra) holiday rules and calculations
+//!
+//! Xetra closes on Christmas Eve and New Year's Eve rather than trading a
+//! half day, and stays open on Whit Monday and German Unity Day.
+
+use crate::markets::weekday_holidays;
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::Holiday;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const XETRA_FIRST_YEAR: i32 = 2015;
+
+/// Get all Xetra holidays for a given year
+pub fn get_xetra_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_xetra_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all Xetra holidays for a given year with their names, sorted by date
+pub fn get_xetra_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Boxing Day", fixed(12, 26)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_xetra_holidays_2025() {
+        let holidays = get_xetra_holidays(2025);
+        assert_eq!(holidays.len(), 8);
+        assert!(holidays.contains(&date(2025, 12, 24)));
+        assert!(holidays.contains(&date(2025, 12, 31)));
+
+        // Whit Monday and German Unity Day are trading days
+        assert!(!holidays.contains(&date(2025, 6, 9)));
+        assert!(!holidays.contains(&date(2025, 10, 3)));
+    }
+
+    #[test]
+    fn test_xetra_holiday_names() {
+        let holidays = get_xetra_holiday_list(2024);
+        let names: Vec<_> = holidays.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "New Year's Day",
+                "Good Friday",
+                "Easter Monday",
+                "Labour Day",
+                "Christmas Eve",
+                "Christmas Day",
+                "Boxing Day",
+                "New Year's Eve",
+            ]
+        );
+    }
+}
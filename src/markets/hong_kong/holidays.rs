@@ -0,0 +1,315 @@
+//! Hong Kong holiday rules and calculations
+//!
+//! HKEX closes on the general holidays that fall on a weekday. A general
+//! holiday on a Sunday moves to the next day that is neither a Sunday nor
+//! another holiday; one on a Saturday is not replaced.
+
+use crate::constants::{date, HK_EARLY_CLOSE};
+use crate::markets::{is_weekend, special_days_in};
+use crate::utils::astronomy::solar_term_date;
+use crate::utils::lunar::{LunarDate, Lunisolar};
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::{Holiday, SpecialDay};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// Hong Kong Time offset from UTC in hours
+const HKT_OFFSET_HOURS: i32 = 8;
+
+/// Gregorian dates of the Chinese festivals observed in Hong Kong
+struct LunarDates {
+    /// First day of the first month
+    new_year: NaiveDate,
+    /// Eighth day of the fourth month
+    buddha: NaiveDate,
+    /// Fifth day of the fifth month
+    tuen_ng: NaiveDate,
+    /// Fifteenth day of the eighth month
+    mid_autumn: NaiveDate,
+    /// Ninth day of the ninth month
+    chung_yeung: NaiveDate,
+}
+
+/// First year the holiday rules are defined for
+pub const HKEX_FIRST_YEAR: i32 = 2020;
+
+/// Trading days HKEX cancelled under severe weather warnings
+///
+/// Since September 23rd, 2024 HKEX keeps trading through typhoon signal
+/// No. 8 and black rainstorm warnings, so no further entries are expected.
+pub const HK_SPECIAL_DAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(2023, 9, 1),
+        "Typhoon Signal No. 8 (Typhoon Saola)",
+        "HKEX severe weather trading arrangements",
+    ),
+    SpecialDay::closed(
+        date(2023, 9, 8),
+        "Black Rainstorm Warning",
+        "HKEX severe weather trading arrangements",
+    ),
+];
+
+/// Get all HKEX holidays for a given year
+pub fn get_hk_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_hk_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all HKEX holidays for a given year with their names, sorted by date
+pub fn get_hk_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> = general_holidays(year)
+        .into_iter()
+        .filter(|holiday| !is_weekend(holiday.date))
+        .collect();
+
+    holidays.extend(
+        special_days_in(HK_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed())
+            .map(SpecialDay::to_holiday),
+    );
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Get all HKEX half days (morning session only) for a given year, sorted
+/// by date
+pub fn get_hk_early_closes(year: i32) -> Vec<Holiday> {
+    let holidays = get_hk_holidays(year);
+    let mut early_closes = Vec::new();
+
+    early_closes.push(Holiday::with_early_close(
+        Lunisolar::Chinese.new_year(year) - Duration::days(1),
+        "Lunar New Year's Eve",
+        HK_EARLY_CLOSE,
+    ));
+    for (day, name) in [(24, "Christmas Eve"), (31, "New Year's Eve")] {
+        early_closes.push(Holiday::with_early_close(
+            NaiveDate::from_ymd_opt(year, 12, day).expect("Valid date"),
+            name,
+            HK_EARLY_CLOSE,
+        ));
+    }
+
+    early_closes.retain(|day| !is_weekend(day.date) && !holidays.contains(&day.date));
+    early_closes.sort_by_key(|day| day.date);
+    early_closes
+}
+
+fn lunar_dates(year: i32) -> LunarDates {
+    let festival = |month, day| {
+        Lunisolar::Chinese
+            .to_gregorian(LunarDate::new(year, month, day))
+            .expect("Festival falls in a regular month")
+    };
+    LunarDates {
+        new_year: festival(1, 1),
+        buddha: festival(4, 8),
+        tuen_ng: festival(5, 5),
+        mid_autumn: festival(8, 15),
+        chung_yeung: festival(9, 9),
+    }
+}
+
+/// General holidays of a year after Sunday substitution, weekends included
+fn general_holidays(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut rules = vec![("The first day of January", fixed(1, 1))];
+
+    let lunar = lunar_dates(year);
+    let new_year = lunar.new_year;
+    let lunar_new_year = [
+        ("Lunar New Year's Day", new_year),
+        (
+            "The second day of Lunar New Year",
+            new_year + Duration::days(1),
+        ),
+        (
+            "The third day of Lunar New Year",
+            new_year + Duration::days(2),
+        ),
+    ];
+    // A Sunday within the first three days is replaced by the fourth day
+    // rather than given a substitute day of its own
+    if lunar_new_year
+        .iter()
+        .any(|(_, date)| date.weekday() == Weekday::Sun)
+    {
+        rules.extend(
+            lunar_new_year
+                .into_iter()
+                .filter(|(_, date)| date.weekday() != Weekday::Sun),
+        );
+        rules.push((
+            "The fourth day of Lunar New Year",
+            new_year + Duration::days(3),
+        ));
+    } else {
+        rules.extend(lunar_new_year);
+    }
+    rules.push(("The Birthday of the Buddha", lunar.buddha));
+    rules.push(("Tuen Ng Festival", lunar.tuen_ng));
+    rules.push((
+        "The day following the Chinese Mid-Autumn Festival",
+        lunar.mid_autumn + Duration::days(1),
+    ));
+    rules.push(("Chung Yeung Festival", lunar.chung_yeung));
+
+    rules.push((
+        "Ching Ming Festival",
+        solar_term_date(year, 15.0, HKT_OFFSET_HOURS),
+    ));
+    if let Ok(good_friday) = calculate_good_friday(year) {
+        rules.push(("Good Friday", good_friday));
+        rules.push((
+            "The day following Good Friday",
+            good_friday + Duration::days(1),
+        ));
+    }
+    if let Ok(easter_monday) = calculate_easter_monday(year) {
+        rules.push(("Easter Monday", easter_monday));
+    }
+    rules.push(("Labour Day", fixed(5, 1)));
+    rules.push((
+        "Hong Kong Special Administrative Region Establishment Day",
+        fixed(7, 1),
+    ));
+    rules.push(("National Day", fixed(10, 1)));
+    rules.push(("Christmas Day", fixed(12, 25)));
+    let mut boxing = fixed(12, 26);
+    if boxing.weekday() == Weekday::Sun {
+        boxing += Duration::days(1);
+    }
+    rules.push(("The first weekday after Christmas Day", boxing));
+
+    // Holidays that keep their date take precedence; a holiday on a Sunday
+    // or on a date already taken moves to the next free day
+    let mut taken = HashSet::new();
+    let mut displaced = Vec::new();
+    let mut holidays = Vec::with_capacity(rules.len());
+    for (name, date) in rules {
+        if date.weekday() != Weekday::Sun && taken.insert(date) {
+            holidays.push(Holiday::new(date, name, true));
+        } else {
+            displaced.push((name, date));
+        }
+    }
+    displaced.sort_by_key(|(_, date)| *date);
+    for (name, date) in displaced {
+        let mut substitute = date + Duration::days(1);
+        while substitute.weekday() == Weekday::Sun || taken.contains(&substitute) {
+            substitute += Duration::days(1);
+        }
+        taken.insert(substitute);
+        holidays.push(Holiday::new(
+            substitute,
+            &format!("{name} (substitute day)"),
+            true,
+        ));
+    }
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hk_holidays_2025() {
+        let holidays = get_hk_holidays(2025);
+        for (m, d) in [
+            (1, 1),
+            (1, 29),
+            (1, 30),
+            (1, 31),
+            (4, 4), // Ching Ming
+            (4, 18),
+            (4, 21),
+            (5, 1),
+            (5, 5), // Buddha's Birthday
+            (7, 1),
+            (10, 1),
+            (10, 7), // Day following Mid-Autumn
+            (10, 29),
+            (12, 25),
+            (12, 26),
+        ] {
+            assert!(holidays.contains(&date(2025, m, d)), "2025-{m}-{d}");
+        }
+        // Tuen Ng 2025 falls on a Saturday and is not replaced
+        assert_eq!(holidays.len(), 15);
+    }
+
+    #[test]
+    fn test_sunday_substitution() {
+        // 2023: Lunar New Year's Day on Sunday, fourth day observed
+        let holidays = get_hk_holidays(2023);
+        assert!(holidays.contains(&date(2023, 1, 23)));
+        assert!(holidays.contains(&date(2023, 1, 24)));
+        assert!(holidays.contains(&date(2023, 1, 25)));
+        assert!(!holidays.contains(&date(2023, 1, 26)));
+
+        // 2024: the third and fourth days replace the Saturday and Sunday
+        let lunar_new_year: Vec<_> = get_hk_holiday_list(2024)
+            .into_iter()
+            .filter(|h| h.name.contains("Lunar New Year"))
+            .map(|h| h.date)
+            .collect();
+        assert_eq!(lunar_new_year, [date(2024, 2, 12), date(2024, 2, 13)]);
+        assert!(!get_hk_holidays(2024).contains(&date(2024, 2, 14)));
+
+        // 2021: the Sunday third day gets no substitute of its own
+        assert!(get_hk_holidays(2021).contains(&date(2021, 2, 15)));
+        assert!(!get_hk_holidays(2021).contains(&date(2021, 2, 16)));
+
+        // 2026: Ching Ming on Easter Sunday moves past Easter Monday
+        let holidays = get_hk_holiday_list(2026);
+        let ching_ming = holidays
+            .iter()
+            .find(|h| h.name.starts_with("Ching Ming"))
+            .unwrap();
+        assert_eq!(ching_ming.date, date(2026, 4, 7));
+
+        // 2022: Christmas on Sunday, first weekday after Christmas on Monday
+        let holidays = get_hk_holidays(2022);
+        assert!(holidays.contains(&date(2022, 12, 26)));
+        assert!(holidays.contains(&date(2022, 12, 27)));
+
+        // 2022: Mid-Autumn on Saturday, the day following on Sunday
+        assert!(holidays.contains(&date(2022, 9, 12)));
+    }
+
+    #[test]
+    fn test_hk_early_closes() {
+        let early_closes = get_hk_early_closes(2025);
+        let dates: Vec<_> = early_closes.iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [date(2025, 1, 28), date(2025, 12, 24), date(2025, 12, 31)]
+        );
+        assert_eq!(early_closes[0].early_close, Some(HK_EARLY_CLOSE));
+    }
+
+    #[test]
+    fn test_computed_lunar_holidays() {
+        let holidays = get_hk_holidays(2035);
+        // Lunar New Year 2035 falls on Thursday, February 8th
+        assert!(holidays.contains(&date(2035, 2, 8)));
+        assert!(holidays.contains(&date(2035, 2, 9)));
+        // The day after the Mid-Autumn Festival (September 16th)
+        assert!(holidays.contains(&date(2035, 9, 17)));
+    }
+
+    #[test]
+    fn test_severe_weather_closures() {
+        let holidays = get_hk_holidays(2023);
+        assert!(holidays.contains(&date(2023, 9, 1)));
+        assert!(holidays.contains(&date(2023, 9, 8)));
+    }
+}
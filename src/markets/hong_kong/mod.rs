@@ -0,0 +1,80 @@
+//! Hong Kong market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{special_days_in, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, SpecialDay, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Hong Kong market implementation (HKEX)
+pub struct HKEXMarket {
+    cache: HolidayCache,
+}
+
+impl HKEXMarket {
+    /// Create a new HKEX market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for HKEXMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for HKEXMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_hk_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_hk_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_hk_early_closes(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::HK_SPECIAL_DAYS, year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(HK_REGULAR_OPEN, HK_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        // Lunch break between the morning and afternoon sessions
+        hours
+            .breaks
+            .push(Session::new_unchecked(HK_LUNCH_START, HK_LUNCH_END));
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Hong_Kong
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::HKEX_FIRST_YEAR..=MAX_YEAR
+    }
+}
@@ -0,0 +1,130 @@
+//! Indian (NSE) holiday rules and calculations
+//!
+//! Most NSE holidays follow the Hindu, Islamic, Jain and Sikh calendars and
+//! are fixed by the exchange in an annual circular, so they are listed
+//! per year rather than computed.
+
+use crate::constants::date;
+use crate::markets::{is_weekend, special_days_in};
+use crate::{Holiday, SpecialDay};
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const NSE_FIRST_YEAR: i32 = 2024;
+
+/// Last year the holiday rules are defined for
+pub const NSE_LAST_YEAR: i32 = 2026;
+
+/// Trading holidays from the NSE annual holiday circulars
+const NSE_HOLIDAYS: &[(NaiveDate, &str)] = &[
+    (date(2024, 1, 26), "Republic Day"),
+    (date(2024, 3, 8), "Mahashivratri"),
+    (date(2024, 3, 25), "Holi"),
+    (date(2024, 3, 29), "Good Friday"),
+    (date(2024, 4, 11), "Id-Ul-Fitr (Ramadan Eid)"),
+    (date(2024, 4, 17), "Shri Ram Navami"),
+    (date(2024, 5, 1), "Maharashtra Day"),
+    (date(2024, 6, 17), "Bakri Id"),
+    (date(2024, 7, 17), "Moharram"),
+    (date(2024, 8, 15), "Independence Day"),
+    (date(2024, 10, 2), "Mahatma Gandhi Jayanti"),
+    (date(2024, 11, 1), "Diwali Laxmi Pujan"),
+    (date(2024, 11, 15), "Gurunanak Jayanti"),
+    (date(2024, 12, 25), "Christmas"),
+    (date(2025, 2, 26), "Mahashivratri"),
+    (date(2025, 3, 14), "Holi"),
+    (date(2025, 3, 31), "Id-Ul-Fitr (Ramadan Eid)"),
+    (date(2025, 4, 10), "Shri Mahavir Jayanti"),
+    (date(2025, 4, 14), "Dr. Baba Saheb Ambedkar Jayanti"),
+    (date(2025, 4, 18), "Good Friday"),
+    (date(2025, 5, 1), "Maharashtra Day"),
+    (date(2025, 8, 15), "Independence Day"),
+    (date(2025, 8, 27), "Ganesh Chaturthi"),
+    (date(2025, 10, 2), "Mahatma Gandhi Jayanti/Dussehra"),
+    (date(2025, 10, 21), "Diwali Laxmi Pujan"),
+    (date(2025, 10, 22), "Diwali Balipratipada"),
+    (date(2025, 11, 5), "Prakash Gurpurb Sri Guru Nanak Dev"),
+    (date(2025, 12, 25), "Christmas"),
+    (date(2026, 1, 26), "Republic Day"),
+    (date(2026, 3, 3), "Holi"),
+    (date(2026, 3, 26), "Shri Ram Navami"),
+    (date(2026, 3, 31), "Shri Mahavir Jayanti"),
+    (date(2026, 4, 3), "Good Friday"),
+    (date(2026, 4, 14), "Dr. Baba Saheb Ambedkar Jayanti"),
+    (date(2026, 5, 1), "Maharashtra Day"),
+    (date(2026, 5, 28), "Bakri Id"),
+    (date(2026, 6, 26), "Muharram"),
+    (date(2026, 9, 14), "Ganesh Chaturthi"),
+    (date(2026, 10, 2), "Mahatma Gandhi Jayanti"),
+    (date(2026, 10, 20), "Dussehra"),
+    (date(2026, 11, 10), "Diwali Balipratipada"),
+    (date(2026, 11, 24), "Prakash Gurpurb Sri Guru Nanak Dev"),
+    (date(2026, 12, 25), "Christmas"),
+];
+
+/// Closures declared after the annual circular
+pub const NSE_SPECIAL_DAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(2024, 1, 22),
+        "Pran Pratishtha of Shri Ram Lalla",
+        "NSE circular; Government of Maharashtra",
+    ),
+    SpecialDay::closed(
+        date(2024, 5, 20),
+        "General Parliamentary Elections",
+        "NSE circular",
+    ),
+    SpecialDay::closed(
+        date(2024, 11, 20),
+        "Maharashtra Legislative Assembly Elections",
+        "NSE circular",
+    ),
+];
+
+/// Get all NSE holidays for a given year
+pub fn get_nse_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_nse_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all NSE holidays for a given year with their names, sorted by date
+pub fn get_nse_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> = NSE_HOLIDAYS
+        .iter()
+        .filter(|(date, _)| date.year() == year && !is_weekend(*date))
+        .map(|(date, name)| Holiday::new(*date, name, true))
+        .collect();
+
+    holidays.extend(
+        special_days_in(NSE_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed())
+            .map(SpecialDay::to_holiday),
+    );
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nse_holidays() {
+        assert_eq!(get_nse_holidays(2025).len(), 14);
+        assert!(get_nse_holidays(2025).contains(&date(2025, 10, 21)));
+
+        // Election closures are merged into the annual list
+        let holidays = get_nse_holiday_list(2024);
+        let election = holidays
+            .iter()
+            .find(|h| h.date == date(2024, 5, 20))
+            .unwrap();
+        assert_eq!(election.name, "General Parliamentary Elections");
+        assert_eq!(holidays.len(), 17);
+    }
+}
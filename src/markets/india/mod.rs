@@ -0,0 +1,71 @@
+//! Indian market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{special_days_in, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, SpecialDay, TradingHours};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Indian market implementation (National Stock Exchange of India)
+pub struct NSEMarket {
+    cache: HolidayCache,
+}
+
+impl NSEMarket {
+    /// Create a new NSE market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for NSEMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for NSEMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_nse_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_nse_holiday_list(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::NSE_SPECIAL_DAYS, year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        // The pre-open call auction is reported as pre-market
+        TradingHours::new(
+            date,
+            Session::new_unchecked(IN_REGULAR_OPEN, IN_REGULAR_CLOSE),
+            Some(Session::new_unchecked(IN_PREOPEN_START, IN_REGULAR_OPEN)),
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Kolkata
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::NSE_FIRST_YEAR..=holidays::NSE_LAST_YEAR
+    }
+}
@@ -0,0 +1,60 @@
+//! Italian holiday rules and calculations
+//!
+//! Borsa Italiana closes on Ferragosto (August 15th) on top of the TARGET2
+//! holidays, and on Christmas Eve and New Year's Eve.
+
+use crate::markets::weekday_holidays;
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::Holiday;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const BIT_FIRST_YEAR: i32 = 2015;
+
+/// Get all Borsa Italiana holidays for a given year
+pub fn get_bit_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_bit_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all Borsa Italiana holidays for a given year with their names,
+/// sorted by date
+pub fn get_bit_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Ferragosto", fixed(8, 15)),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("St. Stephen's Day", fixed(12, 26)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_bit_holidays_2025() {
+        let holidays = get_bit_holidays(2025);
+        assert_eq!(holidays.len(), 9);
+        assert!(holidays.contains(&date(2025, 8, 15)));
+
+        // Republic Day and Epiphany are trading days
+        assert!(!holidays.contains(&date(2025, 6, 2)));
+        assert!(!holidays.contains(&date(2025, 1, 6)));
+
+        // Ferragosto 2026 falls on a Saturday
+        assert!(!get_bit_holiday_list(2026)
+            .iter()
+            .any(|h| h.name == "Ferragosto"));
+    }
+}
@@ -0,0 +1,66 @@
+//! Italian market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Italian market implementation (Borsa Italiana)
+pub struct BITMarket {
+    cache: HolidayCache,
+}
+
+impl BITMarket {
+    /// Create a new Borsa Italiana market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for BITMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for BITMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_bit_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_bit_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(IT_REGULAR_OPEN, IT_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Europe::Rome
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::BIT_FIRST_YEAR..=MAX_YEAR
+    }
+}
@@ -1,192 +1,244 @@
 //! Japanese holiday rules and calculations
 
+use crate::constants::date;
+use crate::markets::special_days_in;
+use crate::utils::astronomy::solar_term_date;
 use crate::utils::nth_weekday_of_month;
+use crate::{Holiday, SpecialDay};
 use chrono::{Datelike, NaiveDate, Weekday};
 use std::collections::HashSet;
 
 /// Expected number of Japanese holidays per year
 const JAPAN_HOLIDAYS_PER_YEAR: usize = 20;
 
+/// Japan Standard Time offset from UTC in hours
+const JST_OFFSET_HOURS: i32 = 9;
+
+/// One-off national holidays enacted by special law
+///
+/// These take part in the citizens' holiday rule, which is how April 30th
+/// and May 2nd 2019 became holidays around the enthronement.
+pub const JAPAN_SPECIAL_HOLIDAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(1990, 11, 12),
+        "Enthronement Ceremony of Emperor Akihito",
+        "Special act of the Diet",
+    ),
+    SpecialDay::closed(
+        date(1993, 6, 9),
+        "Wedding of Crown Prince Naruhito",
+        "Special act of the Diet",
+    ),
+    SpecialDay::closed(
+        date(2019, 5, 1),
+        "Enthronement of Emperor Naruhito",
+        "Special act of the Diet",
+    ),
+    SpecialDay::closed(
+        date(2019, 10, 22),
+        "Enthronement Ceremony of Emperor Naruhito",
+        "Special act of the Diet",
+    ),
+];
+
+/// One-off TSE closures that are not national holidays
+pub const TSE_SPECIAL_DAYS: &[SpecialDay] = &[SpecialDay::closed(
+    date(2020, 10, 1),
+    "Trading system failure",
+    "Japan Exchange Group",
+)];
+
 /// Get all Japanese holidays for a given year
 pub fn get_japan_holidays(year: i32) -> HashSet<NaiveDate> {
-    let mut holidays = HashSet::with_capacity(JAPAN_HOLIDAYS_PER_YEAR);
+    get_japan_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
 
-    // New Year holidays (Jan 1-3)
-    for day in 1..=3 {
-        let date = NaiveDate::from_ymd_opt(year, 1, day).expect("Valid date");
-        add_with_substitute(&mut holidays, date);
+/// Get all TSE holidays for a given year with their names, sorted by date
+///
+/// These are the national holidays under the Act on National Holidays
+/// (including citizens' holidays and substitute holidays) plus the
+/// exchange's own year-end closures on January 2-3 and December 31.
+pub fn get_japan_holiday_list(year: i32) -> Vec<Holiday> {
+    let national = national_holidays(year);
+    let mut holidays = Vec::with_capacity(JAPAN_HOLIDAYS_PER_YEAR);
+    holidays.extend(national.iter().cloned());
+
+    // Citizens' holiday: a day sandwiched between two national holidays
+    for pair in national.windows(2) {
+        let between = pair[0].date + chrono::Duration::days(1);
+        if pair[1].date == between + chrono::Duration::days(1) && between.weekday() != Weekday::Sun
+        {
+            holidays.push(Holiday::new(between, "Citizens' Holiday", true));
+        }
     }
 
-    // Coming of Age Day (2nd Monday of January)
-    if let Some(date) = nth_weekday_of_month(year, 1, Weekday::Mon, 2) {
-        holidays.insert(date);
+    // Substitute holidays for national holidays falling on Sunday
+    for holiday in national.iter().filter(|h| h.date.weekday() == Weekday::Sun) {
+        let mut substitute = holiday.date + chrono::Duration::days(1);
+        if year >= 2007 {
+            // Since 2007: the next day that is not already a holiday
+            while contains_date(&holidays, substitute) {
+                substitute += chrono::Duration::days(1);
+            }
+        } else if contains_date(&holidays, substitute) {
+            // Before 2007: only the following Monday
+            continue;
+        }
+        holidays.push(Holiday::new(
+            substitute,
+            &format!("Substitute Holiday for {}", holiday.name),
+            true,
+        ));
     }
 
-    // National Foundation Day (Feb 11)
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 2, 11).expect("Valid date"),
-    );
-
-    // Emperor's Birthday (Feb 23)
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 2, 23).expect("Valid date"),
-    );
-
-    // Vernal Equinox (around March 20-21)
-    if let Some(date) = calculate_vernal_equinox(year) {
-        add_with_substitute(&mut holidays, date);
+    // Exchange year-end closures
+    for (month, day, name) in [
+        (1, 2, "New Year Holiday"),
+        (1, 3, "New Year Holiday"),
+        (12, 31, "New Year's Eve"),
+    ] {
+        let date = NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+        if !contains_date(&holidays, date) {
+            holidays.push(Holiday::new(date, name, true));
+        }
     }
 
-    // Showa Day (Apr 29)
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 4, 29).expect("Valid date"),
+    holidays.extend(
+        special_days_in(TSE_SPECIAL_DAYS, year)
+            .iter()
+            .map(SpecialDay::to_holiday),
     );
 
-    // Golden Week
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 5, 3).expect("Valid date"),
-    );
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 5, 5).expect("Valid date"),
-    );
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
 
-    // Apply Golden Week bridge rules (includes May 4)
-    apply_golden_week_rules(year, &mut holidays);
+/// National holidays for a year, before substitute and citizens' holidays
+fn national_holidays(year: i32) -> Vec<Holiday> {
+    let mut holidays = Vec::with_capacity(JAPAN_HOLIDAYS_PER_YEAR);
+    let mut add = |date: Option<NaiveDate>, name: &str| {
+        if let Some(date) = date {
+            holidays.push(Holiday::new(date, name, true));
+        }
+    };
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+
+    add(fixed(1, 1), "New Year's Day");
+
+    // Coming of Age Day: January 15th until 1999, then the 2nd Monday
+    if year < 2000 {
+        add(fixed(1, 15), "Coming of Age Day");
+    } else {
+        add(
+            nth_weekday_of_month(year, 1, Weekday::Mon, 2),
+            "Coming of Age Day",
+        );
+    }
 
-    // Marine Day (3rd Monday of July)
-    if let Some(date) = nth_weekday_of_month(year, 7, Weekday::Mon, 3) {
-        holidays.insert(date);
+    add(fixed(2, 11), "National Foundation Day");
+
+    // Emperor's Birthday moved to February 23rd with the 2019 succession
+    if year >= 2020 {
+        add(fixed(2, 23), "Emperor's Birthday");
     }
 
-    // Mountain Day (Aug 11)
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 8, 11).expect("Valid date"),
-    );
+    add(Some(calculate_vernal_equinox(year)), "Vernal Equinox Day");
 
-    // Respect for Aged Day (3rd Monday of September)
-    if let Some(date) = nth_weekday_of_month(year, 9, Weekday::Mon, 3) {
-        holidays.insert(date);
+    // April 29th was Greenery Day until 2006
+    if year >= 2007 {
+        add(fixed(4, 29), "Showa Day");
+    } else {
+        add(fixed(4, 29), "Greenery Day");
     }
 
-    // Autumnal Equinox (around Sept 22-24)
-    if let Some(date) = calculate_autumnal_equinox(year) {
-        add_with_substitute(&mut holidays, date);
+    add(fixed(5, 3), "Constitution Memorial Day");
+    if year >= 2007 {
+        add(fixed(5, 4), "Greenery Day");
+    }
+    add(fixed(5, 5), "Children's Day");
+
+    // Marine Day: July 20th from 1996, 3rd Monday from 2003,
+    // moved for the Tokyo Olympics in 2020 and 2021
+    match year {
+        ..=1995 => {}
+        1996..=2002 => add(fixed(7, 20), "Marine Day"),
+        2020 => add(fixed(7, 23), "Marine Day"),
+        2021 => add(fixed(7, 22), "Marine Day"),
+        _ => add(nth_weekday_of_month(year, 7, Weekday::Mon, 3), "Marine Day"),
     }
 
-    // Health and Sports Day (2nd Monday of October)
-    if let Some(date) = nth_weekday_of_month(year, 10, Weekday::Mon, 2) {
-        holidays.insert(date);
+    // Mountain Day: from 2016, moved for the Tokyo Olympics in 2020 and 2021
+    match year {
+        ..=2015 => {}
+        2020 => add(fixed(8, 10), "Mountain Day"),
+        2021 => add(fixed(8, 8), "Mountain Day"),
+        _ => add(fixed(8, 11), "Mountain Day"),
     }
 
-    // Culture Day (Nov 3)
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 11, 3).expect("Valid date"),
-    );
+    // Respect for the Aged Day: September 15th until 2002, then the 3rd Monday
+    if year < 2003 {
+        add(fixed(9, 15), "Respect for the Aged Day");
+    } else {
+        add(
+            nth_weekday_of_month(year, 9, Weekday::Mon, 3),
+            "Respect for the Aged Day",
+        );
+    }
 
-    // Labour Thanksgiving Day (Nov 23)
-    add_with_substitute(
-        &mut holidays,
-        NaiveDate::from_ymd_opt(year, 11, 23).expect("Valid date"),
+    add(
+        Some(calculate_autumnal_equinox(year)),
+        "Autumnal Equinox Day",
     );
 
-    // Market closes Dec 31
-    holidays.insert(NaiveDate::from_ymd_opt(year, 12, 31).expect("Valid date"));
-
-    holidays
-}
+    // Health and Sports Day: October 10th until 1999, then the 2nd Monday;
+    // renamed Sports Day in 2020 and moved for the Tokyo Olympics
+    match year {
+        ..=1999 => add(fixed(10, 10), "Health and Sports Day"),
+        2000..=2019 => add(
+            nth_weekday_of_month(year, 10, Weekday::Mon, 2),
+            "Health and Sports Day",
+        ),
+        2020 => add(fixed(7, 24), "Sports Day"),
+        2021 => add(fixed(7, 23), "Sports Day"),
+        _ => add(
+            nth_weekday_of_month(year, 10, Weekday::Mon, 2),
+            "Sports Day",
+        ),
+    }
 
-/// Add holiday with substitute if it falls on Sunday
-fn add_with_substitute(holidays: &mut HashSet<NaiveDate>, date: NaiveDate) {
-    holidays.insert(date);
+    add(fixed(11, 3), "Culture Day");
+    add(fixed(11, 23), "Labour Thanksgiving Day");
 
-    if date.weekday() == Weekday::Sun {
-        let mut substitute = date + chrono::Duration::days(1);
-        let mut attempts = 0;
-        while holidays.contains(&substitute) && attempts < 7 {
-            substitute += chrono::Duration::days(1);
-            attempts += 1;
-        }
-        if attempts < 7 {
-            holidays.insert(substitute);
-        }
+    // Emperor Akihito's birthday, observed 1989-2018
+    if (1989..=2018).contains(&year) {
+        add(fixed(12, 23), "Emperor's Birthday");
     }
-}
-
-/// Apply Golden Week bridge day rules
-fn apply_golden_week_rules(year: i32, holidays: &mut HashSet<NaiveDate>) {
-    let may_3 = NaiveDate::from_ymd_opt(year, 5, 3).expect("Valid date");
-    let may_4 = NaiveDate::from_ymd_opt(year, 5, 4).expect("Valid date");
-    let may_5 = NaiveDate::from_ymd_opt(year, 5, 5).expect("Valid date");
 
-    // May 4 is always a holiday (Greenery Day)
-    holidays.insert(may_4);
+    holidays.extend(
+        special_days_in(JAPAN_SPECIAL_HOLIDAYS, year)
+            .iter()
+            .map(SpecialDay::to_holiday),
+    );
 
-    // Bridge day rules
-    if may_3.weekday() == Weekday::Tue {
-        // May 3 is Tuesday, add May 2 as bridge
-        holidays.insert(NaiveDate::from_ymd_opt(year, 5, 2).expect("Valid date"));
-    }
-    if may_5.weekday() == Weekday::Thu {
-        // May 5 is Thursday, add May 6 as bridge
-        holidays.insert(NaiveDate::from_ymd_opt(year, 5, 6).expect("Valid date"));
-    }
-    if may_3.weekday() == Weekday::Fri && may_5.weekday() == Weekday::Sun {
-        // May 3 is Friday, May 5 is Sunday, add May 6 as bridge
-        holidays.insert(NaiveDate::from_ymd_opt(year, 5, 6).expect("Valid date"));
-    }
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
 
-    // Additional bridge day when May 4 falls on Sunday
-    if may_4.weekday() == Weekday::Sun {
-        // May 4 is Sunday, add May 6 as substitute
-        holidays.insert(NaiveDate::from_ymd_opt(year, 5, 6).expect("Valid date"));
-    }
+fn contains_date(holidays: &[Holiday], date: NaiveDate) -> bool {
+    holidays.iter().any(|holiday| holiday.date == date)
 }
 
-/// Calculate Vernal Equinox
-fn calculate_vernal_equinox(year: i32) -> Option<NaiveDate> {
-    // Official Japanese government equinox dates 2020-2030
-    let day = match year {
-        2020 => 20,
-        2021 => 20,
-        2022 => 21,
-        2023 => 21,
-        2024 => 20,
-        2025 => 20,
-        2026 => 20,
-        2027 => 21,
-        2028 => 20,
-        2029 => 20,
-        2030 => 20,
-        _ => return None,
-    };
-    NaiveDate::from_ymd_opt(year, 3, day)
+/// Calculate Vernal Equinox Day (the JST date of the March equinox)
+pub fn calculate_vernal_equinox(year: i32) -> NaiveDate {
+    solar_term_date(year, 0.0, JST_OFFSET_HOURS)
 }
 
-/// Calculate Autumnal Equinox
-fn calculate_autumnal_equinox(year: i32) -> Option<NaiveDate> {
-    // Official Japanese government equinox dates 2020-2030
-    let day = match year {
-        2020 => 22,
-        2021 => 23,
-        2022 => 23,
-        2023 => 23,
-        2024 => 22,
-        2025 => 23,
-        2026 => 23,
-        2027 => 23,
-        2028 => 22,
-        2029 => 23,
-        2030 => 23,
-        _ => return None,
-    };
-    NaiveDate::from_ymd_opt(year, 9, day)
+/// Calculate Autumnal Equinox Day (the JST date of the September equinox)
+pub fn calculate_autumnal_equinox(year: i32) -> NaiveDate {
+    solar_term_date(year, 180.0, JST_OFFSET_HOURS)
 }
 
 #[cfg(test)]
@@ -264,5 +316,112 @@ mod tests {
         // May 3, 2024 was Friday, May 5 was Sunday
         // Should have bridge day on May 6 (Monday)
         assert!(holidays.contains(&NaiveDate::from_ymd_opt(2024, 5, 6).unwrap()));
+
+        // May 3, 2020 was Sunday: substitute holiday after May 4-5
+        let holidays = get_japan_holidays(2020);
+        assert!(holidays.contains(&NaiveDate::from_ymd_opt(2020, 5, 6).unwrap()));
+
+        // May 3, 2022 was Tuesday: May 2 was a trading day
+        let holidays = get_japan_holidays(2022);
+        assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2022, 5, 2).unwrap()));
+    }
+
+    #[test]
+    fn test_equinoxes_match_official_dates() {
+        // Official dates published by the National Astronomical Observatory of Japan
+        let vernal = [20, 20, 21, 21, 20, 20, 20, 21, 20, 20, 20];
+        let autumnal = [22, 23, 23, 23, 22, 23, 23, 23, 22, 23, 23];
+        for (i, year) in (2020..=2030).enumerate() {
+            assert_eq!(
+                calculate_vernal_equinox(year),
+                NaiveDate::from_ymd_opt(year, 3, vernal[i]).unwrap()
+            );
+            assert_eq!(
+                calculate_autumnal_equinox(year),
+                NaiveDate::from_ymd_opt(year, 9, autumnal[i]).unwrap()
+            );
+        }
+        assert_eq!(
+            calculate_autumnal_equinox(2012),
+            NaiveDate::from_ymd_opt(2012, 9, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_historical_rule_eras() {
+        // Emperor Akihito's birthday on December 23rd until 2018
+        let holidays = get_japan_holiday_list(2018);
+        let dec_23 = NaiveDate::from_ymd_opt(2018, 12, 23).unwrap();
+        assert!(holidays.iter().any(|h| h.date == dec_23));
+        // ... which fell on a Sunday, so Monday was a substitute holiday
+        let dec_24 = NaiveDate::from_ymd_opt(2018, 12, 24).unwrap();
+        assert!(holidays.iter().any(|h| h.date == dec_24));
+        assert!(!get_japan_holidays(2019).contains(&NaiveDate::from_ymd_opt(2019, 12, 23).unwrap()));
+        assert!(!get_japan_holidays(2019).contains(&NaiveDate::from_ymd_opt(2019, 2, 23).unwrap()));
+
+        // Tokyo Olympics moved Marine Day and Sports Day next to each other
+        let holidays = get_japan_holiday_list(2020);
+        let sports = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2020, 7, 24).unwrap())
+            .unwrap();
+        assert_eq!(sports.name, "Sports Day");
+        assert!(!get_japan_holidays(2020).contains(&NaiveDate::from_ymd_opt(2020, 10, 12).unwrap()));
+
+        // Fixed-date holidays before the Happy Monday reform
+        let holidays = get_japan_holidays(1995);
+        assert!(holidays.contains(&NaiveDate::from_ymd_opt(1995, 1, 16).unwrap())); // Jan 15 was Sunday
+        assert!(holidays.contains(&NaiveDate::from_ymd_opt(1995, 10, 10).unwrap()));
+        assert!(!holidays.contains(&NaiveDate::from_ymd_opt(1995, 7, 20).unwrap()));
+
+        // Citizens' holiday between Respect for the Aged Day and the equinox
+        let holidays = get_japan_holiday_list(2026);
+        let citizens = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2026, 9, 22).unwrap())
+            .unwrap();
+        assert_eq!(citizens.name, "Citizens' Holiday");
+    }
+
+    #[test]
+    fn test_holiday_names() {
+        let holidays = get_japan_holiday_list(2024);
+        assert!(holidays.windows(2).all(|pair| pair[0].date < pair[1].date));
+
+        // Culture Day 2024 was Sunday Nov 3
+        let substitute = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2024, 11, 4).unwrap())
+            .unwrap();
+        assert_eq!(substitute.name, "Substitute Holiday for Culture Day");
+
+        let marine = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())
+            .unwrap();
+        assert_eq!(marine.name, "Marine Day");
+    }
+
+    #[test]
+    fn test_enthronement_holidays_2019() {
+        let holidays = get_japan_holiday_list(2019);
+        let name = |m, d| {
+            holidays
+                .iter()
+                .find(|h| h.date == NaiveDate::from_ymd_opt(2019, m, d).unwrap())
+                .map(|h| h.name.as_str())
+        };
+
+        // Ten consecutive days off from April 27th to May 6th
+        assert_eq!(name(4, 30), Some("Citizens' Holiday"));
+        assert_eq!(name(5, 1), Some("Enthronement of Emperor Naruhito"));
+        assert_eq!(name(5, 2), Some("Citizens' Holiday"));
+        assert_eq!(name(5, 6), Some("Substitute Holiday for Children's Day"));
+        assert_eq!(
+            name(10, 22),
+            Some("Enthronement Ceremony of Emperor Naruhito")
+        );
+
+        assert!(get_japan_holidays(2020).contains(&NaiveDate::from_ymd_opt(2020, 10, 1).unwrap()));
     }
 }
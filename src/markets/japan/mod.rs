@@ -3,10 +3,10 @@
 pub mod holidays;
 
 use crate::constants::*;
-use crate::markets::MarketImpl;
+use crate::markets::{special_days_in, MarketImpl};
 use crate::utils::HolidayCache;
-use crate::{Session, TradingHours};
-use chrono::{Datelike, NaiveDate};
+use crate::{Holiday, Session, SpecialDay, TradingHours};
+use chrono::{Datelike, NaiveDate, NaiveTime};
 use chrono_tz::Tz;
 
 /// Japanese market implementation (TSE)
@@ -23,6 +23,24 @@ impl TSEMarket {
     }
 }
 
+/// TSE closing time, which moved from 15:00 to 15:30 on 2024-11-05
+fn regular_close(date: NaiveDate) -> NaiveTime {
+    if date >= NaiveDate::from_ymd_opt(2024, 11, 5).expect("Valid date") {
+        JP_EXTENDED_CLOSE
+    } else {
+        JP_REGULAR_CLOSE
+    }
+}
+
+/// TSE lunch break start, which moved from 11:00 to 11:30 on 2011-11-21
+fn lunch_start(date: NaiveDate) -> NaiveTime {
+    if date >= NaiveDate::from_ymd_opt(2011, 11, 21).expect("Valid date") {
+        JP_LUNCH_START
+    } else {
+        JP_FORMER_LUNCH_START
+    }
+}
+
 impl Default for TSEMarket {
     fn default() -> Self {
         Self::new()
@@ -38,16 +56,36 @@ impl MarketImpl for TSEMarket {
         holidays.contains(&date)
     }
 
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_japan_holiday_list(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        let mut days = special_days_in(holidays::JAPAN_SPECIAL_HOLIDAYS, year);
+        days.extend(special_days_in(holidays::TSE_SPECIAL_DAYS, year));
+        days.sort_by_key(|day| day.date);
+        days
+    }
+
     fn trading_hours(&self, date: NaiveDate) -> TradingHours {
-        TradingHours::new(
+        let mut hours = TradingHours::new(
             date,
-            Session::new_unchecked(JP_REGULAR_OPEN, JP_REGULAR_CLOSE),
+            Session::new_unchecked(JP_REGULAR_OPEN, regular_close(date)),
             None, // No pre-market
             None, // No after-hours
-        )
+        );
+        // Lunch break between the morning and afternoon sessions
+        hours
+            .breaks
+            .push(Session::new_unchecked(lunch_start(date), JP_LUNCH_END));
+        hours
     }
 
     fn timezone(&self) -> Tz {
         chrono_tz::Asia::Tokyo
     }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
 }
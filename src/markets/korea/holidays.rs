@@ -0,0 +1,264 @@
+//! Korean holiday rules and calculations
+//!
+//! KRX closes on the public holidays, on election days and on the last
+//! business day of the year. Substitute holidays follow the rules in force
+//! each year: Seollal and Chuseok gain a day when they overlap a Sunday or
+//! another holiday, Children's Day when it falls on a weekend or another
+//! holiday, the national days from 2021 and Buddha's Birthday and
+//! Christmas from 2023 when they fall on a weekend.
+
+use crate::constants::date;
+use crate::markets::{is_weekend, special_days_in};
+use crate::utils::lunar::{LunarDate, Lunisolar};
+use crate::{Holiday, SpecialDay};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const KRX_FIRST_YEAR: i32 = 2020;
+
+/// Election days and temporary public holidays designated by the government
+pub const KR_SPECIAL_DAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(2020, 4, 15),
+        "National Assembly Election Day",
+        "Public Official Election Act",
+    ),
+    SpecialDay::closed(
+        date(2020, 8, 17),
+        "Temporary Public Holiday",
+        "Presidential decree",
+    ),
+    SpecialDay::closed(
+        date(2022, 3, 9),
+        "Presidential Election Day",
+        "Public Official Election Act",
+    ),
+    SpecialDay::closed(
+        date(2022, 6, 1),
+        "Local Election Day",
+        "Public Official Election Act",
+    ),
+    SpecialDay::closed(
+        date(2023, 10, 2),
+        "Temporary Public Holiday",
+        "Presidential decree",
+    ),
+    SpecialDay::closed(
+        date(2024, 4, 10),
+        "National Assembly Election Day",
+        "Public Official Election Act",
+    ),
+    SpecialDay::closed(date(2024, 10, 1), "Armed Forces Day", "Presidential decree"),
+    SpecialDay::closed(
+        date(2025, 1, 27),
+        "Temporary Public Holiday",
+        "Presidential decree",
+    ),
+    SpecialDay::closed(
+        date(2025, 6, 3),
+        "Presidential Election Day",
+        "Public Official Election Act",
+    ),
+    SpecialDay::closed(
+        date(2026, 6, 3),
+        "Local Election Day",
+        "Public Official Election Act",
+    ),
+];
+
+/// When a holiday is replaced by a substitute holiday
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Substitute {
+    Never,
+    /// On a Sunday or another holiday (Seollal, Chuseok)
+    SundayOrOverlap,
+    /// On a weekend or another holiday (Children's Day)
+    WeekendOrOverlap,
+    /// On a weekend
+    Weekend,
+}
+
+/// Get all KRX holidays for a given year
+pub fn get_krx_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_krx_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all KRX holidays for a given year with their names, sorted by date
+pub fn get_krx_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let national = if year >= 2021 {
+        Substitute::Weekend
+    } else {
+        Substitute::Never
+    };
+    let religious = if year >= 2023 {
+        Substitute::Weekend
+    } else {
+        Substitute::Never
+    };
+
+    let mut rules = vec![
+        ("New Year's Day", fixed(1, 1), Substitute::Never),
+        ("Independence Movement Day", fixed(3, 1), national),
+        ("Labour Day", fixed(5, 1), Substitute::Never),
+        ("Children's Day", fixed(5, 5), Substitute::WeekendOrOverlap),
+        ("Memorial Day", fixed(6, 6), Substitute::Never),
+        ("Liberation Day", fixed(8, 15), national),
+        ("National Foundation Day", fixed(10, 3), national),
+        ("Hangul Day", fixed(10, 9), national),
+        ("Christmas Day", fixed(12, 25), religious),
+    ];
+    let lunar = |month, day| {
+        Lunisolar::Korean
+            .to_gregorian(LunarDate::new(year, month, day))
+            .expect("Holiday falls in a regular month")
+    };
+    let (seollal, buddha, chuseok) = (lunar(1, 1), lunar(4, 8), lunar(8, 15));
+    for (offset, name) in [
+        (-1, "Seollal Holiday"),
+        (0, "Seollal"),
+        (1, "Seollal Holiday"),
+    ] {
+        rules.push((
+            name,
+            seollal + Duration::days(offset),
+            Substitute::SundayOrOverlap,
+        ));
+    }
+    rules.push(("Buddha's Birthday", buddha, religious));
+    for (offset, name) in [
+        (-1, "Chuseok Holiday"),
+        (0, "Chuseok"),
+        (1, "Chuseok Holiday"),
+    ] {
+        rules.push((
+            name,
+            chuseok + Duration::days(offset),
+            Substitute::SundayOrOverlap,
+        ));
+    }
+
+    let mut taken: HashSet<NaiveDate> = rules.iter().map(|(_, date, _)| *date).collect();
+    let overlaps = |date: NaiveDate| rules.iter().filter(|(_, d, _)| *d == date).count() > 1;
+
+    let mut substitutes = Vec::new();
+    for (name, date, rule) in &rules {
+        let replaced = match rule {
+            Substitute::Never => false,
+            Substitute::SundayOrOverlap => date.weekday() == Weekday::Sun || overlaps(*date),
+            Substitute::WeekendOrOverlap => is_weekend(*date) || overlaps(*date),
+            Substitute::Weekend => is_weekend(*date),
+        };
+        if replaced {
+            substitutes.push((*name, *date));
+        }
+    }
+    // Seollal and Chuseok are replaced once however many days they overlap
+    substitutes.dedup_by_key(|(name, _)| name.trim_end_matches(" Holiday"));
+
+    let mut holidays: Vec<Holiday> = Vec::with_capacity(rules.len() + substitutes.len() + 1);
+    holidays.extend(
+        rules
+            .iter()
+            .filter(|(_, date, _)| date.year() == year)
+            .map(|(name, date, _)| Holiday::new(*date, name, true)),
+    );
+    for (name, date) in substitutes {
+        let mut substitute = date + Duration::days(1);
+        while is_weekend(substitute) || taken.contains(&substitute) {
+            substitute += Duration::days(1);
+        }
+        taken.insert(substitute);
+        let name = name.trim_end_matches(" Holiday");
+        holidays.push(Holiday::new(
+            substitute,
+            &format!("Substitute Holiday for {name}"),
+            true,
+        ));
+    }
+
+    holidays.extend(
+        special_days_in(KR_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed())
+            .map(SpecialDay::to_holiday),
+    );
+
+    // Year-end closing on the last business day of the year
+    let mut year_end = fixed(12, 31);
+    while is_weekend(year_end) {
+        year_end -= Duration::days(1);
+    }
+    holidays.push(Holiday::new(year_end, "Year-End Market Closing", true));
+
+    holidays.retain(|holiday| !is_weekend(holiday.date));
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays.dedup_by_key(|holiday| holiday.date);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_krx_holidays_2025() {
+        let holidays = get_krx_holidays(2025);
+        for (m, d) in [
+            (1, 1),
+            (1, 27), // Temporary holiday
+            (1, 28),
+            (1, 29),
+            (1, 30),
+            (3, 3), // Substitute for Independence Movement Day
+            (5, 1),
+            (5, 5),
+            (5, 6), // Substitute for Children's Day / Buddha's Birthday
+            (6, 3), // Presidential election
+            (6, 6),
+            (8, 15),
+            (10, 3),
+            (10, 6),
+            (10, 7),
+            (10, 8), // Substitute for Chuseok
+            (10, 9),
+            (12, 25),
+            (12, 31),
+        ] {
+            assert!(holidays.contains(&date(2025, m, d)), "2025-{m}-{d}");
+        }
+        assert_eq!(holidays.len(), 19);
+    }
+
+    #[test]
+    fn test_substitute_eras() {
+        // Buddha's Birthday on Saturday: substituted from 2023
+        assert!(get_krx_holidays(2023).contains(&date(2023, 5, 29)));
+        // Liberation Day on Sunday 2021: substituted from 2021
+        assert!(get_krx_holidays(2021).contains(&date(2021, 8, 16)));
+        // Christmas on Saturday 2021 was not substituted
+        assert!(!get_krx_holidays(2021).contains(&date(2021, 12, 27)));
+
+        // Seollal 2024 (Friday to Sunday) is replaced once
+        let holidays = get_krx_holiday_list(2024);
+        let substitutes: Vec<_> = holidays
+            .iter()
+            .filter(|h| h.name.starts_with("Substitute Holiday for Seollal"))
+            .collect();
+        assert_eq!(substitutes.len(), 1);
+        assert_eq!(substitutes[0].date, date(2024, 2, 12));
+    }
+
+    #[test]
+    fn test_year_end_closing() {
+        // December 31st 2022 was a Saturday
+        let holidays = get_krx_holiday_list(2022);
+        let year_end = holidays.last().unwrap();
+        assert_eq!(year_end.date, date(2022, 12, 30));
+        assert_eq!(year_end.name, "Year-End Market Closing");
+    }
+}
@@ -0,0 +1,70 @@
+//! Korean market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{special_days_in, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, SpecialDay, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Korean market implementation (Korea Exchange)
+pub struct KRXMarket {
+    cache: HolidayCache,
+}
+
+impl KRXMarket {
+    /// Create a new KRX market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for KRXMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for KRXMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_krx_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_krx_holiday_list(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::KR_SPECIAL_DAYS, year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(KR_REGULAR_OPEN, KR_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Seoul
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::KRX_FIRST_YEAR..=MAX_YEAR
+    }
+}
@@ -0,0 +1,90 @@
+//! Mexican holiday rules and calculations
+//!
+//! The Bolsa Mexicana de Valores closes on the statutory holidays, three of
+//! which move to a Monday, and on the bank holidays of Holy Week, All
+//! Souls' Day and the Day of the Virgin of Guadalupe. Inauguration Day is
+//! a holiday every six years.
+
+use crate::markets::weekday_holidays;
+use crate::utils::{calculate_good_friday, calculate_maundy_thursday, nth_weekday_of_month};
+use crate::Holiday;
+use chrono::{NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const BMV_FIRST_YEAR: i32 = 2010;
+
+/// First presidential inauguration held on October 1st
+const FIRST_OCTOBER_INAUGURATION: i32 = 2024;
+
+/// Get all BMV holidays for a given year
+pub fn get_bmv_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_bmv_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all BMV holidays for a given year with their names, sorted by date
+pub fn get_bmv_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    let inauguration = fixed(10, 1).filter(|_| {
+        year >= FIRST_OCTOBER_INAUGURATION && (year - FIRST_OCTOBER_INAUGURATION) % 6 == 0
+    });
+
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        (
+            "Constitution Day",
+            nth_weekday_of_month(year, 2, Weekday::Mon, 1),
+        ),
+        (
+            "Benito Juárez's Birthday",
+            nth_weekday_of_month(year, 3, Weekday::Mon, 3),
+        ),
+        ("Holy Thursday", calculate_maundy_thursday(year).ok()),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Independence Day", fixed(9, 16)),
+        ("Inauguration Day", inauguration),
+        ("All Souls' Day", fixed(11, 2)),
+        (
+            "Revolution Day",
+            nth_weekday_of_month(year, 11, Weekday::Mon, 3),
+        ),
+        ("Day of the Virgin of Guadalupe", fixed(12, 12)),
+        ("Christmas Day", fixed(12, 25)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_bmv_holidays_2025() {
+        let dates: Vec<_> = get_bmv_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 2, 3),
+                date(2025, 3, 17),
+                date(2025, 4, 17),
+                date(2025, 4, 18),
+                date(2025, 5, 1),
+                date(2025, 9, 16),
+                date(2025, 11, 17),
+                date(2025, 12, 12),
+                date(2025, 12, 25),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inauguration_day() {
+        assert!(get_bmv_holidays(2024).contains(&date(2024, 10, 1)));
+        assert!(!get_bmv_holidays(2025).contains(&date(2025, 10, 1)));
+    }
+}
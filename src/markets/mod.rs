@@ -9,6 +9,26 @@
 //! - **LSE**: London Stock Exchange with UK bank holidays  
 //! - **TSE**: Tokyo Stock Exchange with Japanese national holidays
 //! - **TSX**: Toronto Stock Exchange with Canadian holidays
+//! - **EURONEXT**: Euronext Paris, Amsterdam, Brussels and Lisbon
+//! - **XETRA**: Deutsche Börse Xetra
+//! - **SIX**: SIX Swiss Exchange
+//! - **BIT**: Borsa Italiana
+//! - **BME**: Bolsas y Mercados Españoles
+//! - **OMXS/OMXH/OMXC**: Nasdaq Stockholm, Helsinki and Copenhagen
+//! - **HKEX**: Hong Kong Exchanges with lunar holidays and a lunch break
+//! - **SSE/SZSE**: Shanghai and Shenzhen stock exchanges
+//! - **KRX**: Korea Exchange
+//! - **ASX**: Australian Securities Exchange
+//! - **NSE**: National Stock Exchange of India
+//! - **SGX**: Singapore Exchange
+//! - **TADAWUL**: Saudi Exchange, trading Sunday to Thursday
+//! - **DFM/ADX**: Dubai Financial Market and Abu Dhabi Securities Exchange
+//! - **JSE**: Johannesburg Stock Exchange
+//! - **EGX**: Egyptian Exchange, trading Sunday to Thursday
+//! - **B3**: Brasil, Bolsa, Balcão with Carnival closures
+//! - **BMV**: Bolsa Mexicana de Valores
+//! - **CME**: CME Globex, opening on the evening before each trading date
+//! - **CBOE/CBOEIndex**: Cboe equity and index options
 //!
 //! # Example
 //!
@@ -21,13 +41,33 @@
 //! # Ok::<(), trading_calendar::CalendarError>(())
 //! ```
 
-use crate::{Result, TradingHours};
-use chrono::{Datelike, NaiveDate, Weekday};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Result, SpecialDay, TradingHours, WorkingDay, MAX_YEAR, MIN_YEAR};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
 use chrono_tz::Tz;
 use std::fmt;
+use std::ops::RangeInclusive;
 
+pub mod australia;
+pub mod brazil;
 pub mod canada;
+pub mod china;
+pub mod egypt;
+pub mod euronext;
+pub mod germany;
+pub mod hong_kong;
+pub mod india;
+pub mod italy;
 pub mod japan;
+pub mod korea;
+pub mod mexico;
+pub mod nordic;
+pub mod saudi_arabia;
+pub mod singapore;
+pub mod south_africa;
+pub mod spain;
+pub mod switzerland;
+pub mod uae;
 pub mod uk;
 pub mod us;
 
@@ -48,6 +88,129 @@ pub enum Market {
     TSE,
     /// Toronto Stock Exchange
     TSX,
+    /// Euronext Paris, Amsterdam, Brussels and Lisbon
+    EURONEXT,
+    /// Deutsche Börse Xetra
+    XETRA,
+    /// SIX Swiss Exchange
+    SIX,
+    /// Borsa Italiana
+    BIT,
+    /// Bolsas y Mercados Españoles
+    BME,
+    /// Nasdaq Stockholm
+    OMXS,
+    /// Nasdaq Helsinki
+    OMXH,
+    /// Nasdaq Copenhagen
+    OMXC,
+    /// Hong Kong Exchanges and Clearing
+    HKEX,
+    /// Shanghai Stock Exchange
+    SSE,
+    /// Shenzhen Stock Exchange
+    SZSE,
+    /// Korea Exchange
+    KRX,
+    /// Australian Securities Exchange
+    ASX,
+    /// National Stock Exchange of India
+    NSE,
+    /// Singapore Exchange
+    SGX,
+    /// Saudi Exchange (Tadawul)
+    TADAWUL,
+    /// Dubai Financial Market
+    DFM,
+    /// Abu Dhabi Securities Exchange
+    ADX,
+    /// Johannesburg Stock Exchange
+    JSE,
+    /// Egyptian Exchange
+    EGX,
+    /// B3 - Brasil, Bolsa, Balcão
+    B3,
+    /// Bolsa Mexicana de Valores
+    BMV,
+    /// CME Globex
+    CME,
+    /// Cboe Options Exchange, equity and ETF options
+    CBOE,
+    /// Cboe Options Exchange, index options
+    CBOEIndex,
+    /// A market loaded from a calendar definition
+    ///
+    /// Custom calendars are built with `TradingCalendar::from_definition`;
+    /// their name, code and timezone are available from
+    /// `TradingCalendar::name`, `TradingCalendar::code` and
+    /// `TradingCalendar::timezone`.
+    Custom,
+}
+
+/// The days of the week on which a market is closed
+///
+/// Most markets close on Saturday and Sunday; exchanges in the Middle East
+/// have used Friday-Saturday and Thursday-Friday weekends.
+///
+/// # Examples
+///
+/// ```
+/// use trading_calendar::WeekendMask;
+/// use chrono::Weekday;
+///
+/// let weekend = WeekendMask::FRIDAY_SATURDAY;
+/// assert!(weekend.contains(Weekday::Fri));
+/// assert!(!weekend.contains(Weekday::Sun));
+/// assert_eq!(weekend.days(), [Weekday::Fri, Weekday::Sat]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekendMask(u8);
+
+impl WeekendMask {
+    /// Saturday and Sunday
+    pub const SATURDAY_SUNDAY: Self = Self::from_days(&[Weekday::Sat, Weekday::Sun]);
+    /// Friday and Saturday
+    pub const FRIDAY_SATURDAY: Self = Self::from_days(&[Weekday::Fri, Weekday::Sat]);
+    /// Thursday and Friday
+    pub const THURSDAY_FRIDAY: Self = Self::from_days(&[Weekday::Thu, Weekday::Fri]);
+
+    /// Create a mask from a list of weekdays
+    pub const fn from_days(days: &[Weekday]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < days.len() {
+            bits |= 1 << days[i].num_days_from_monday();
+            i += 1;
+        }
+        WeekendMask(bits)
+    }
+
+    /// Check if a weekday is part of the weekend
+    pub fn contains(self, weekday: Weekday) -> bool {
+        self.0 & (1 << weekday.num_days_from_monday()) != 0
+    }
+
+    /// Get the weekend days, starting from Monday
+    pub fn days(self) -> Vec<Weekday> {
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]
+        .into_iter()
+        .filter(|day| self.contains(*day))
+        .collect()
+    }
+}
+
+impl Default for WeekendMask {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
 }
 
 /// Internal trait for market implementations
@@ -55,15 +218,97 @@ pub trait MarketImpl: Send + Sync {
     /// Check if a date is a holiday
     fn is_holiday(&self, date: NaiveDate) -> bool;
 
+    /// Get all holidays in a year with their names, sorted by date
+    fn holidays(&self, year: i32) -> Vec<Holiday>;
+
+    /// Get all early close days in a year, sorted by date
+    fn early_closes(&self, _year: i32) -> Vec<Holiday> {
+        Vec::new()
+    }
+
+    /// Get the one-off closures and early closes in a year, sorted by date
+    ///
+    /// These are already included in [`holidays`](Self::holidays) and
+    /// [`early_closes`](Self::early_closes); this listing adds their source.
+    fn special_days(&self, _year: i32) -> Vec<SpecialDay> {
+        Vec::new()
+    }
+
+    /// Get the holiday cache backing [`early_close`](Self::early_close)
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        None
+    }
+
+    /// Get the early close time for a date, if the market closes early
+    ///
+    /// Called for every [`trading_hours`](Self::trading_hours) lookup. The
+    /// year's early closes are cached when the market provides a
+    /// [`holiday_cache`](Self::holiday_cache); implementors without one
+    /// should override this method rather than rebuild
+    /// [`early_closes`](Self::early_closes) on every call.
+    fn early_close(&self, date: NaiveDate) -> Option<NaiveTime> {
+        let year = date.year();
+        match self.holiday_cache() {
+            Some(cache) => cache.early_close_or_compute(date, || self.early_closes(year)),
+            None => self
+                .early_closes(year)
+                .into_iter()
+                .find(|day| day.date == date)
+                .and_then(|day| day.early_close),
+        }
+    }
+
     /// Get trading hours for a date
     fn trading_hours(&self, date: NaiveDate) -> TradingHours;
 
     /// Get the timezone
     fn timezone(&self) -> Tz;
 
+    /// Get the market's display name, for markets without their own
+    /// [`Market`] variant
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the market's short code, for markets without their own
+    /// [`Market`] variant
+    fn code(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the range of years the holiday rules are defined for
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        MIN_YEAR..=MAX_YEAR
+    }
+
+    /// Get the days of the week the market is closed, as in force on a date
+    fn weekend(&self, _date: NaiveDate) -> WeekendMask {
+        WeekendMask::SATURDAY_SUNDAY
+    }
+
+    /// Get the weekend days declared working days in a year, sorted by date
+    fn special_working_days(&self, _year: i32) -> Vec<WorkingDay> {
+        Vec::new()
+    }
+
+    /// Check if a date falls on the market's weekend
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend(date).contains(date.weekday())
+    }
+
     /// Check if a date is a trading day
+    ///
+    /// Weekend days are trading days only when declared a working day on
+    /// which the exchange trades.
     fn is_trading_day(&self, date: NaiveDate) -> bool {
-        !self.is_holiday(date) && !is_weekend(date)
+        if self.is_holiday(date) {
+            return false;
+        }
+        !self.is_weekend(date)
+            || self
+                .special_working_days(date.year())
+                .iter()
+                .any(|day| day.date == date && day.trading)
     }
 
     /// Get the next trading day
@@ -87,12 +332,37 @@ pub trait MarketImpl: Send + Sync {
 
 impl Market {
     /// Get the timezone for this market
+    ///
+    /// Returns UTC for [`Market::Custom`].
     pub fn timezone(&self) -> Tz {
         match self {
             Market::NYSE | Market::NASDAQ => chrono_tz::America::New_York,
             Market::LSE => chrono_tz::Europe::London,
             Market::TSE => chrono_tz::Asia::Tokyo,
             Market::TSX => chrono_tz::America::Toronto,
+            Market::EURONEXT => chrono_tz::Europe::Paris,
+            Market::XETRA => chrono_tz::Europe::Berlin,
+            Market::SIX => chrono_tz::Europe::Zurich,
+            Market::BIT => chrono_tz::Europe::Rome,
+            Market::BME => chrono_tz::Europe::Madrid,
+            Market::OMXS => chrono_tz::Europe::Stockholm,
+            Market::OMXH => chrono_tz::Europe::Helsinki,
+            Market::OMXC => chrono_tz::Europe::Copenhagen,
+            Market::HKEX => chrono_tz::Asia::Hong_Kong,
+            Market::SSE | Market::SZSE => chrono_tz::Asia::Shanghai,
+            Market::KRX => chrono_tz::Asia::Seoul,
+            Market::ASX => chrono_tz::Australia::Sydney,
+            Market::NSE => chrono_tz::Asia::Kolkata,
+            Market::SGX => chrono_tz::Asia::Singapore,
+            Market::TADAWUL => chrono_tz::Asia::Riyadh,
+            Market::DFM | Market::ADX => chrono_tz::Asia::Dubai,
+            Market::JSE => chrono_tz::Africa::Johannesburg,
+            Market::EGX => chrono_tz::Africa::Cairo,
+            Market::B3 => chrono_tz::America::Sao_Paulo,
+            Market::BMV => chrono_tz::America::Mexico_City,
+            Market::CME => chrono_tz::America::Chicago,
+            Market::CBOE | Market::CBOEIndex => chrono_tz::America::New_York,
+            Market::Custom => chrono_tz::UTC,
         }
     }
 
@@ -104,6 +374,32 @@ impl Market {
             Market::LSE => "London Stock Exchange",
             Market::TSE => "Tokyo Stock Exchange",
             Market::TSX => "Toronto Stock Exchange",
+            Market::EURONEXT => "Euronext",
+            Market::XETRA => "Deutsche Börse Xetra",
+            Market::SIX => "SIX Swiss Exchange",
+            Market::BIT => "Borsa Italiana",
+            Market::BME => "Bolsas y Mercados Españoles",
+            Market::OMXS => "Nasdaq Stockholm",
+            Market::OMXH => "Nasdaq Helsinki",
+            Market::OMXC => "Nasdaq Copenhagen",
+            Market::HKEX => "Hong Kong Exchanges and Clearing",
+            Market::SSE => "Shanghai Stock Exchange",
+            Market::SZSE => "Shenzhen Stock Exchange",
+            Market::KRX => "Korea Exchange",
+            Market::ASX => "Australian Securities Exchange",
+            Market::NSE => "National Stock Exchange of India",
+            Market::SGX => "Singapore Exchange",
+            Market::TADAWUL => "Saudi Exchange",
+            Market::DFM => "Dubai Financial Market",
+            Market::ADX => "Abu Dhabi Securities Exchange",
+            Market::JSE => "Johannesburg Stock Exchange",
+            Market::EGX => "Egyptian Exchange",
+            Market::B3 => "B3 - Brasil, Bolsa, Balcão",
+            Market::BMV => "Bolsa Mexicana de Valores",
+            Market::CME => "CME Globex",
+            Market::CBOE => "Cboe Options Exchange",
+            Market::CBOEIndex => "Cboe Options Exchange (Index Options)",
+            Market::Custom => "Custom Market",
         }
     }
 
@@ -115,6 +411,32 @@ impl Market {
             Market::LSE => "LSE",
             Market::TSE => "TSE",
             Market::TSX => "TSX",
+            Market::EURONEXT => "EURONEXT",
+            Market::XETRA => "XETRA",
+            Market::SIX => "SIX",
+            Market::BIT => "BIT",
+            Market::BME => "BME",
+            Market::OMXS => "OMXS",
+            Market::OMXH => "OMXH",
+            Market::OMXC => "OMXC",
+            Market::HKEX => "HKEX",
+            Market::SSE => "SSE",
+            Market::SZSE => "SZSE",
+            Market::KRX => "KRX",
+            Market::ASX => "ASX",
+            Market::NSE => "NSE",
+            Market::SGX => "SGX",
+            Market::TADAWUL => "TADAWUL",
+            Market::DFM => "DFM",
+            Market::ADX => "ADX",
+            Market::JSE => "JSE",
+            Market::EGX => "EGX",
+            Market::B3 => "B3",
+            Market::BMV => "BMV",
+            Market::CME => "CME",
+            Market::CBOE => "CBOE",
+            Market::CBOEIndex => "CBOE_INDEX",
+            Market::Custom => "CUSTOM",
         }
     }
 
@@ -125,6 +447,34 @@ impl Market {
             Market::LSE => Box::new(uk::LSEMarket::new()),
             Market::TSE => Box::new(japan::TSEMarket::new()),
             Market::TSX => Box::new(canada::TSXMarket::new()),
+            Market::EURONEXT => Box::new(euronext::EuronextMarket::new()),
+            Market::XETRA => Box::new(germany::XetraMarket::new()),
+            Market::SIX => Box::new(switzerland::SIXMarket::new()),
+            Market::BIT => Box::new(italy::BITMarket::new()),
+            Market::BME => Box::new(spain::BMEMarket::new()),
+            Market::OMXS => Box::new(nordic::StockholmMarket::new()),
+            Market::OMXH => Box::new(nordic::HelsinkiMarket::new()),
+            Market::OMXC => Box::new(nordic::CopenhagenMarket::new()),
+            Market::HKEX => Box::new(hong_kong::HKEXMarket::new()),
+            Market::SSE | Market::SZSE => Box::new(china::ChinaMarket::new()),
+            Market::KRX => Box::new(korea::KRXMarket::new()),
+            Market::ASX => Box::new(australia::ASXMarket::new()),
+            Market::NSE => Box::new(india::NSEMarket::new()),
+            Market::SGX => Box::new(singapore::SGXMarket::new()),
+            Market::TADAWUL => Box::new(saudi_arabia::TadawulMarket::new()),
+            Market::DFM | Market::ADX => Box::new(uae::UAEMarket::new()),
+            Market::JSE => Box::new(south_africa::JSEMarket::new()),
+            Market::EGX => Box::new(egypt::EGXMarket::new()),
+            Market::B3 => Box::new(brazil::B3Market::new()),
+            Market::BMV => Box::new(mexico::BMVMarket::new()),
+            Market::CME => Box::new(us::cme::CMEMarket::new()),
+            Market::CBOE => Box::new(us::cboe::CboeMarket::new()),
+            Market::CBOEIndex => Box::new(us::cboe::CboeMarket::index_options()),
+            Market::Custom => {
+                return Err(crate::CalendarError::InvalidConfiguration(
+                    "Custom markets are built from a calendar definition".to_string(),
+                ))
+            }
         })
     }
 }
@@ -145,6 +495,31 @@ impl std::str::FromStr for Market {
             "LSE" => Ok(Market::LSE),
             "TSE" => Ok(Market::TSE),
             "TSX" => Ok(Market::TSX),
+            "EURONEXT" => Ok(Market::EURONEXT),
+            "XETRA" => Ok(Market::XETRA),
+            "SIX" => Ok(Market::SIX),
+            "BIT" => Ok(Market::BIT),
+            "BME" => Ok(Market::BME),
+            "OMXS" => Ok(Market::OMXS),
+            "OMXH" => Ok(Market::OMXH),
+            "OMXC" => Ok(Market::OMXC),
+            "HKEX" => Ok(Market::HKEX),
+            "SSE" => Ok(Market::SSE),
+            "SZSE" => Ok(Market::SZSE),
+            "KRX" => Ok(Market::KRX),
+            "ASX" => Ok(Market::ASX),
+            "NSE" => Ok(Market::NSE),
+            "SGX" => Ok(Market::SGX),
+            "TADAWUL" => Ok(Market::TADAWUL),
+            "DFM" => Ok(Market::DFM),
+            "ADX" => Ok(Market::ADX),
+            "JSE" => Ok(Market::JSE),
+            "EGX" => Ok(Market::EGX),
+            "B3" => Ok(Market::B3),
+            "BMV" => Ok(Market::BMV),
+            "CME" => Ok(Market::CME),
+            "CBOE" => Ok(Market::CBOE),
+            "CBOE_INDEX" => Ok(Market::CBOEIndex),
             _ => Err(crate::CalendarError::InvalidConfiguration(format!(
                 "Unknown market: {s}"
             ))),
@@ -152,7 +527,78 @@ impl std::str::FromStr for Market {
     }
 }
 
-/// Check if a date is a weekend
+/// Get the entries of a special day table that fall in a year
+pub(crate) fn special_days_in(table: &[SpecialDay], year: i32) -> Vec<SpecialDay> {
+    let mut days: Vec<SpecialDay> = table
+        .iter()
+        .filter(|day| day.date.year() == year)
+        .copied()
+        .collect();
+    days.sort_by_key(|day| day.date);
+    days
+}
+
+/// Build a year's holidays from named rules, dropping dates that fall on a
+/// weekend
+///
+/// For markets that do not move holidays off weekends. When two rules fall
+/// on the same date, the first is kept.
+pub(crate) fn weekday_holidays<const N: usize>(
+    rules: [(&str, Option<NaiveDate>); N],
+) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> = rules
+        .into_iter()
+        .filter_map(|(name, date)| date.map(|date| Holiday::new(date, name, true)))
+        .filter(|holiday| !is_weekend(holiday.date))
+        .collect();
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays.dedup_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Build a year's early closes from named rules, dropping dates that fall
+/// on a weekend or a holiday
+pub(crate) fn weekday_early_closes<const N: usize>(
+    rules: [(&str, Option<NaiveDate>); N],
+    close: NaiveTime,
+    holidays: &[Holiday],
+) -> Vec<Holiday> {
+    let mut early_closes: Vec<Holiday> = rules
+        .into_iter()
+        .filter_map(|(name, date)| date.map(|date| Holiday::with_early_close(date, name, close)))
+        .filter(|day| !is_weekend(day.date) && !holidays.iter().any(|h| h.date == day.date))
+        .collect();
+    early_closes.sort_by_key(|day| day.date);
+    early_closes.dedup_by_key(|day| day.date);
+    early_closes
+}
+
+/// Replace computed holiday dates with periods announced by the exchange
+///
+/// Each announced `(name, first, last)` period replaces the computed dates
+/// of the holiday with the same name in the year the period starts.
+pub(crate) fn apply_announced_periods(
+    rules: &mut Vec<(&'static str, NaiveDate)>,
+    announced: &[(&'static str, NaiveDate, NaiveDate)],
+    year: i32,
+) {
+    for &(name, first, last) in announced
+        .iter()
+        .filter(|(_, first, _)| first.year() == year)
+    {
+        rules.retain(|(rule, date)| *rule != name || date.year() != year);
+        let mut day = first;
+        while day <= last {
+            rules.push((name, day));
+            day += chrono::Duration::days(1);
+        }
+    }
+}
+
+/// Check if a date falls on a Saturday or Sunday
+///
+/// For the holiday rules of markets with a Saturday-Sunday weekend; markets
+/// with other weeks answer through [`MarketImpl::is_weekend`].
 pub(crate) fn is_weekend(date: NaiveDate) -> bool {
     matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
 }
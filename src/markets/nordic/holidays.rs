@@ -0,0 +1,209 @@
+//! Nasdaq Nordic holiday rules and calculations
+//!
+//! Stockholm, Helsinki and Copenhagen each follow their own national
+//! holidays, and all three close on Christmas Eve and New Year's Eve.
+//! Holidays on a weekend are not moved.
+
+use crate::constants::SE_EARLY_CLOSE;
+use crate::markets::{weekday_early_closes, weekday_holidays};
+use crate::utils::easter::calculate_easter;
+use crate::utils::{
+    calculate_ascension_day, calculate_easter_monday, calculate_good_friday,
+    calculate_maundy_thursday, calculate_whit_monday,
+};
+use crate::Holiday;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const NORDIC_FIRST_YEAR: i32 = 2010;
+
+/// Last year Denmark observed General Prayer Day, abolished from 2024
+const DK_LAST_PRAYER_DAY_YEAR: i32 = 2023;
+
+/// Get all Nasdaq Stockholm holidays for a given year
+pub fn get_stockholm_holidays(year: i32) -> HashSet<NaiveDate> {
+    dates(get_stockholm_holiday_list(year))
+}
+
+/// Get all Nasdaq Stockholm holidays for a given year with their names,
+/// sorted by date
+pub fn get_stockholm_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Epiphany", fixed(1, 6)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Ascension Day", calculate_ascension_day(year).ok()),
+        ("National Day of Sweden", fixed(6, 6)),
+        ("Midsummer Eve", midsummer_eve(year)),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Boxing Day", fixed(12, 26)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+/// Get all Nasdaq Stockholm half days (13:00 close) for a given year,
+/// sorted by date
+pub fn get_stockholm_early_closes(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    let day_before = |date: Option<NaiveDate>| date.map(|date| date - Duration::days(1));
+    weekday_early_closes(
+        [
+            ("Twelfth Night", fixed(1, 5)),
+            ("Maundy Thursday", calculate_maundy_thursday(year).ok()),
+            ("Walpurgis Night", fixed(4, 30)),
+            (
+                "Day before Ascension Day",
+                day_before(calculate_ascension_day(year).ok()),
+            ),
+            ("All Saints' Eve", day_before(all_saints_day(year))),
+        ],
+        SE_EARLY_CLOSE,
+        &get_stockholm_holiday_list(year),
+    )
+}
+
+/// Get all Nasdaq Helsinki holidays for a given year
+pub fn get_helsinki_holidays(year: i32) -> HashSet<NaiveDate> {
+    dates(get_helsinki_holiday_list(year))
+}
+
+/// Get all Nasdaq Helsinki holidays for a given year with their names,
+/// sorted by date
+pub fn get_helsinki_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Epiphany", fixed(1, 6)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("May Day", fixed(5, 1)),
+        ("Ascension Day", calculate_ascension_day(year).ok()),
+        ("Midsummer Eve", midsummer_eve(year)),
+        ("Independence Day", fixed(12, 6)),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Boxing Day", fixed(12, 26)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+/// Get all Nasdaq Copenhagen holidays for a given year
+pub fn get_copenhagen_holidays(year: i32) -> HashSet<NaiveDate> {
+    dates(get_copenhagen_holiday_list(year))
+}
+
+/// Get all Nasdaq Copenhagen holidays for a given year with their names,
+/// sorted by date
+pub fn get_copenhagen_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    let easter = calculate_easter(year).ok();
+    let after_easter = |days| easter.map(|easter| easter + Duration::days(days));
+    let prayer_day = if year <= DK_LAST_PRAYER_DAY_YEAR {
+        after_easter(26)
+    } else {
+        None
+    };
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Maundy Thursday", calculate_maundy_thursday(year).ok()),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("General Prayer Day", prayer_day),
+        ("Ascension Day", calculate_ascension_day(year).ok()),
+        ("Day after Ascension Day", after_easter(40)),
+        ("Whit Monday", calculate_whit_monday(year).ok()),
+        ("Constitution Day", fixed(6, 5)),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Boxing Day", fixed(12, 26)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+fn dates(holidays: Vec<Holiday>) -> HashSet<NaiveDate> {
+    holidays.into_iter().map(|holiday| holiday.date).collect()
+}
+
+/// Midsummer Eve, the Friday between June 19th and 25th
+fn midsummer_eve(year: i32) -> Option<NaiveDate> {
+    first_weekday_from(NaiveDate::from_ymd_opt(year, 6, 19)?, Weekday::Fri)
+}
+
+/// All Saints' Day in Sweden, the Saturday between October 31st and
+/// November 6th
+fn all_saints_day(year: i32) -> Option<NaiveDate> {
+    first_weekday_from(NaiveDate::from_ymd_opt(year, 10, 31)?, Weekday::Sat)
+}
+
+/// Get the first `weekday` on or after `date`
+fn first_weekday_from(date: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let offset = (7 + weekday.num_days_from_monday() - date.weekday().num_days_from_monday()) % 7;
+    date.checked_add_signed(Duration::days(offset.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_stockholm_holidays_2025() {
+        let holidays = get_stockholm_holidays(2025);
+        assert!(holidays.contains(&date(2025, 1, 6))); // Epiphany
+        assert!(holidays.contains(&date(2025, 5, 29))); // Ascension Day
+        assert!(holidays.contains(&date(2025, 6, 6))); // National Day
+        assert!(holidays.contains(&date(2025, 6, 20))); // Midsummer Eve
+        assert!(holidays.contains(&date(2025, 12, 31)));
+        assert!(!holidays.contains(&date(2025, 6, 9))); // Whit Monday
+        assert_eq!(holidays.len(), 12);
+    }
+
+    #[test]
+    fn test_stockholm_early_closes() {
+        let early_closes = get_stockholm_early_closes(2025);
+        let dates: Vec<_> = early_closes.iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 4, 17), // Maundy Thursday
+                date(2025, 4, 30),
+                date(2025, 5, 28),
+                date(2025, 10, 31),
+            ]
+        );
+        assert_eq!(early_closes[0].early_close, Some(SE_EARLY_CLOSE));
+
+        // Twelfth Night 2026 falls on a Monday
+        assert_eq!(get_stockholm_early_closes(2026)[0].date, date(2026, 1, 5));
+    }
+
+    #[test]
+    fn test_helsinki_holidays_2025() {
+        let holidays = get_helsinki_holidays(2025);
+        assert!(holidays.contains(&date(2025, 6, 20))); // Midsummer Eve
+        assert!(!holidays.contains(&date(2025, 12, 6))); // Saturday
+        assert!(get_helsinki_holidays(2024).contains(&date(2024, 12, 6))); // Independence Day
+
+        // Swedish National Day is a trading day in Helsinki
+        assert!(!holidays.contains(&date(2025, 6, 6)));
+    }
+
+    #[test]
+    fn test_copenhagen_holidays() {
+        let holidays = get_copenhagen_holidays(2023);
+        assert!(holidays.contains(&date(2023, 4, 6))); // Maundy Thursday
+        assert!(holidays.contains(&date(2023, 5, 5))); // General Prayer Day
+        assert!(holidays.contains(&date(2023, 5, 19))); // Day after Ascension
+        assert!(holidays.contains(&date(2023, 6, 5))); // Constitution Day
+
+        // General Prayer Day was abolished from 2024
+        let holidays = get_copenhagen_holiday_list(2024);
+        assert!(!holidays.iter().any(|h| h.name == "General Prayer Day"));
+        assert!(!get_copenhagen_holidays(2024).contains(&date(2024, 4, 26)));
+    }
+}
@@ -0,0 +1,182 @@
+//! Nasdaq Nordic market implementations
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Swedish market implementation (Nasdaq Stockholm)
+pub struct StockholmMarket {
+    cache: HolidayCache,
+}
+
+impl StockholmMarket {
+    /// Create a new Nasdaq Stockholm market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for StockholmMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for StockholmMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_stockholm_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_stockholm_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_stockholm_early_closes(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(SE_REGULAR_OPEN, SE_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Europe::Stockholm
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::NORDIC_FIRST_YEAR..=MAX_YEAR
+    }
+}
+
+/// Finnish market implementation (Nasdaq Helsinki)
+pub struct HelsinkiMarket {
+    cache: HolidayCache,
+}
+
+impl HelsinkiMarket {
+    /// Create a new Nasdaq Helsinki market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for HelsinkiMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for HelsinkiMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_helsinki_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_helsinki_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(FI_REGULAR_OPEN, FI_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Europe::Helsinki
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::NORDIC_FIRST_YEAR..=MAX_YEAR
+    }
+}
+
+/// Danish market implementation (Nasdaq Copenhagen)
+pub struct CopenhagenMarket {
+    cache: HolidayCache,
+}
+
+impl CopenhagenMarket {
+    /// Create a new Nasdaq Copenhagen market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for CopenhagenMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for CopenhagenMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_copenhagen_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_copenhagen_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(DK_REGULAR_OPEN, DK_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Europe::Copenhagen
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::NORDIC_FIRST_YEAR..=MAX_YEAR
+    }
+}
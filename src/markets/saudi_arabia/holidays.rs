@@ -0,0 +1,127 @@
+//! Saudi holiday rules and calculations
+//!
+//! The Saudi Exchange trades Sunday to Thursday. It closes for the two
+//! Eids, whose dates follow the Umm al-Qura calendar, and for Founding Day
+//! and National Day. Since 2022 a national day falling on a Friday is
+//! observed on the Thursday before and one falling on a Saturday on the
+//! Sunday after.
+//!
+//! The Eid breaks are announced by the exchange each year. Without an
+//! announcement the break is computed: from the eve of Eid al-Fitr to the
+//! third of Shawwal, and from the Day of Arafah to the twelfth of Dhu
+//! al-Hijjah.
+
+use crate::constants::date;
+use crate::markets::{apply_announced_periods, WeekendMask};
+use crate::utils::hijri::{hijri_dates_in, hijri_days_in, DHU_AL_HIJJAH, SHAWWAL};
+use crate::Holiday;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const TADAWUL_FIRST_YEAR: i32 = 2020;
+
+/// First year of Founding Day and of moving national days off the weekend
+const FOUNDING_DAY_FIRST_YEAR: i32 = 2022;
+
+/// Eid breaks announced by the Saudi Exchange that differ from the
+/// computed period
+const SA_ANNOUNCED_CLOSURES: &[(&str, NaiveDate, NaiveDate)] =
+    &[("Eid al-Fitr", date(2023, 4, 20), date(2023, 4, 25))];
+
+/// Get the Saudi Exchange weekend
+pub fn tadawul_weekend(_date: NaiveDate) -> WeekendMask {
+    WeekendMask::FRIDAY_SATURDAY
+}
+
+/// Get all Saudi Exchange holidays for a given year
+pub fn get_tadawul_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_tadawul_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all Saudi Exchange holidays for a given year with their names,
+/// sorted by date
+pub fn get_tadawul_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut rules = Vec::new();
+
+    for eid in hijri_dates_in(year, SHAWWAL, 1) {
+        for offset in -1..=2 {
+            rules.push(("Eid al-Fitr", eid + Duration::days(offset)));
+        }
+    }
+    for day in hijri_days_in(year, DHU_AL_HIJJAH, 9, 4) {
+        rules.push(("Eid al-Adha", day));
+    }
+    apply_announced_periods(&mut rules, SA_ANNOUNCED_CLOSURES, year);
+
+    if year >= FOUNDING_DAY_FIRST_YEAR {
+        rules.push(("Founding Day", observed(fixed(2, 22))));
+        rules.push(("National Day", observed(fixed(9, 23))));
+    } else {
+        rules.push(("National Day", fixed(9, 23)));
+    }
+
+    let mut holidays: Vec<Holiday> = rules
+        .into_iter()
+        .filter(|(_, date)| date.year() == year && !tadawul_weekend(*date).contains(date.weekday()))
+        .map(|(name, date)| Holiday::new(date, name, true))
+        .collect();
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays.dedup_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Move a national day off the weekend
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Fri => date - Duration::days(1),
+        Weekday::Sat => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tadawul_holidays_2025() {
+        let dates: Vec<_> = get_tadawul_holiday_list(2025)
+            .iter()
+            .map(|h| h.date)
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 2, 23), // Founding Day on Saturday
+                date(2025, 3, 30),
+                date(2025, 3, 31),
+                date(2025, 4, 1),
+                date(2025, 6, 5), // Day of Arafah
+                date(2025, 6, 8),
+                date(2025, 9, 23),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_announced_eid_break() {
+        // The 2023 Eid al-Fitr break ran from Thursday to Tuesday
+        let holidays = get_tadawul_holidays(2023);
+        assert!(holidays.contains(&date(2023, 4, 20)));
+        assert!(holidays.contains(&date(2023, 4, 25)));
+        assert!(!holidays.contains(&date(2023, 4, 26)));
+    }
+
+    #[test]
+    fn test_national_day_on_friday() {
+        // September 23rd, 2022 was a Friday
+        assert!(get_tadawul_holidays(2022).contains(&date(2022, 9, 22)));
+        // Before 2022 national days were not moved
+        assert!(!get_tadawul_holidays(2021).contains(&date(2021, 2, 22)));
+    }
+}
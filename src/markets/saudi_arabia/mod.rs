@@ -0,0 +1,71 @@
+//! Saudi market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{MarketImpl, WeekendMask};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Saudi market implementation (Saudi Exchange, Tadawul)
+pub struct TadawulMarket {
+    cache: HolidayCache,
+}
+
+impl TadawulMarket {
+    /// Create a new Saudi Exchange market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for TadawulMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for TadawulMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_tadawul_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_tadawul_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(SA_REGULAR_OPEN, SA_REGULAR_CLOSE),
+            // Pre-open order entry
+            Some(Session::new_unchecked(SA_PREOPEN_START, SA_REGULAR_OPEN)),
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Riyadh
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::TADAWUL_FIRST_YEAR..=MAX_YEAR
+    }
+
+    fn weekend(&self, date: NaiveDate) -> WeekendMask {
+        holidays::tadawul_weekend(date)
+    }
+}
@@ -0,0 +1,204 @@
+//! Singapore holiday rules and calculations
+//!
+//! SGX closes on the gazetted public holidays. A holiday on a Sunday moves
+//! to the next day that is not itself a holiday; one on a Saturday is not
+//! replaced. The festival dates are gazetted each year by the Ministry of
+//! Manpower.
+
+use crate::constants::{date, SG_EARLY_CLOSE};
+use crate::markets::{is_weekend, special_days_in};
+use crate::utils::calculate_good_friday;
+use crate::{Holiday, SpecialDay};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const SGX_FIRST_YEAR: i32 = 2023;
+
+/// Last year the holiday rules are defined for
+pub const SGX_LAST_YEAR: i32 = 2026;
+
+/// Gazetted dates of Chinese New Year, Hari Raya Puasa, Vesak Day, Hari
+/// Raya Haji and Deepavali
+const SG_FESTIVAL_DATES: &[(i32, [NaiveDate; 5])] = &[
+    (
+        2023,
+        [
+            date(2023, 1, 22),
+            date(2023, 4, 22),
+            date(2023, 6, 2),
+            date(2023, 6, 29),
+            date(2023, 11, 12),
+        ],
+    ),
+    (
+        2024,
+        [
+            date(2024, 2, 10),
+            date(2024, 4, 10),
+            date(2024, 5, 22),
+            date(2024, 6, 17),
+            date(2024, 10, 31),
+        ],
+    ),
+    (
+        2025,
+        [
+            date(2025, 1, 29),
+            date(2025, 3, 31),
+            date(2025, 5, 12),
+            date(2025, 6, 7),
+            date(2025, 10, 20),
+        ],
+    ),
+    (
+        2026,
+        [
+            date(2026, 2, 17),
+            date(2026, 3, 21),
+            date(2026, 5, 31),
+            date(2026, 5, 27),
+            date(2026, 11, 8),
+        ],
+    ),
+];
+
+/// Polling days, which are public holidays
+pub const SG_SPECIAL_DAYS: &[SpecialDay] = &[SpecialDay::closed(
+    date(2023, 9, 1),
+    "Polling Day (Presidential Election)",
+    "Parliamentary Elections Act; Ministry of Manpower",
+)];
+
+/// Get all SGX holidays for a given year
+pub fn get_sgx_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_sgx_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all SGX holidays for a given year with their names, sorted by date
+pub fn get_sgx_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut rules = vec![("New Year's Day", fixed(1, 1))];
+    if let Some((_, [new_year, puasa, vesak, haji, deepavali])) = festival_dates(year) {
+        rules.push(("Chinese New Year", *new_year));
+        rules.push(("Chinese New Year", *new_year + Duration::days(1)));
+        rules.push(("Hari Raya Puasa", *puasa));
+        rules.push(("Vesak Day", *vesak));
+        rules.push(("Hari Raya Haji", *haji));
+        rules.push(("Deepavali", *deepavali));
+    }
+    if let Ok(date) = calculate_good_friday(year) {
+        rules.push(("Good Friday", date));
+    }
+    rules.push(("Labour Day", fixed(5, 1)));
+    rules.push(("National Day", fixed(8, 9)));
+    rules.push(("Christmas Day", fixed(12, 25)));
+    rules.sort_by_key(|(_, date)| *date);
+
+    let mut taken: HashSet<NaiveDate> = rules.iter().map(|(_, date)| *date).collect();
+    let mut holidays = Vec::with_capacity(rules.len() + 2);
+    for (name, date) in rules {
+        if date.weekday() == Weekday::Sun {
+            let mut substitute = date + Duration::days(1);
+            while taken.contains(&substitute) {
+                substitute += Duration::days(1);
+            }
+            taken.insert(substitute);
+            holidays.push(Holiday::new(
+                substitute,
+                &format!("{name} (substitute day)"),
+                true,
+            ));
+        } else if date.weekday() != Weekday::Sat {
+            holidays.push(Holiday::new(date, name, true));
+        }
+    }
+
+    holidays.extend(
+        special_days_in(SG_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed() && !is_weekend(day.date))
+            .map(SpecialDay::to_holiday),
+    );
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Get all SGX half days (morning session only) for a given year, sorted
+/// by date
+pub fn get_sgx_early_closes(year: i32) -> Vec<Holiday> {
+    let holidays = get_sgx_holidays(year);
+    let mut early_closes = Vec::new();
+
+    if let Some((_, [new_year, ..])) = festival_dates(year) {
+        early_closes.push(Holiday::with_early_close(
+            *new_year - Duration::days(1),
+            "Eve of Chinese New Year",
+            SG_EARLY_CLOSE,
+        ));
+    }
+    for (day, name) in [(24, "Christmas Eve"), (31, "New Year's Eve")] {
+        early_closes.push(Holiday::with_early_close(
+            NaiveDate::from_ymd_opt(year, 12, day).expect("Valid date"),
+            name,
+            SG_EARLY_CLOSE,
+        ));
+    }
+
+    early_closes.retain(|day| !is_weekend(day.date) && !holidays.contains(&day.date));
+    early_closes.sort_by_key(|day| day.date);
+    early_closes
+}
+
+fn festival_dates(year: i32) -> Option<&'static (i32, [NaiveDate; 5])> {
+    SG_FESTIVAL_DATES.iter().find(|(y, _)| *y == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sgx_holidays_2026() {
+        let dates: Vec<_> = get_sgx_holiday_list(2026).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2026, 1, 1),
+                date(2026, 2, 17),
+                date(2026, 2, 18),
+                date(2026, 4, 3),
+                date(2026, 5, 1),
+                date(2026, 5, 27),
+                date(2026, 6, 1),  // Vesak Day on Sunday
+                date(2026, 8, 10), // National Day on Sunday
+                date(2026, 11, 9), // Deepavali on Sunday
+                date(2026, 12, 25),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chinese_new_year_on_sunday() {
+        // 2023: first day on Sunday, second day on Monday
+        let holidays = get_sgx_holidays(2023);
+        assert!(holidays.contains(&date(2023, 1, 23)));
+        assert!(holidays.contains(&date(2023, 1, 24)));
+        assert!(holidays.contains(&date(2023, 9, 1))); // Polling Day
+    }
+
+    #[test]
+    fn test_sgx_early_closes() {
+        let early_closes = get_sgx_early_closes(2025);
+        let dates: Vec<_> = early_closes.iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [date(2025, 1, 28), date(2025, 12, 24), date(2025, 12, 31)]
+        );
+        assert_eq!(early_closes[0].early_close, Some(SG_EARLY_CLOSE));
+    }
+}
@@ -0,0 +1,80 @@
+//! Singapore market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{special_days_in, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, SpecialDay, TradingHours};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// Singapore market implementation (SGX)
+pub struct SGXMarket {
+    cache: HolidayCache,
+}
+
+impl SGXMarket {
+    /// Create a new SGX market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for SGXMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for SGXMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_sgx_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_sgx_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_sgx_early_closes(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::SG_SPECIAL_DAYS, year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(SG_REGULAR_OPEN, SG_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        // Lunch break between the morning and afternoon sessions
+        hours
+            .breaks
+            .push(Session::new_unchecked(SG_LUNCH_START, SG_LUNCH_END));
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Singapore
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::SGX_FIRST_YEAR..=holidays::SGX_LAST_YEAR
+    }
+}
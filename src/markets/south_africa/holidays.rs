@@ -0,0 +1,221 @@
+//! South African holiday rules and calculations
+//!
+//! The JSE closes on the public holidays of the Public Holidays Act 36 of
+//! 1994. A holiday on a Sunday is observed on the Monday; when that Monday
+//! is itself a holiday (Christmas Day on a Sunday), the next day is
+//! declared instead. Election days and one-off holidays are proclaimed by
+//! the President.
+
+use crate::constants::{date, ZA_EARLY_CLOSE};
+use crate::markets::{is_weekend, special_days_in};
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::{Holiday, SpecialDay};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const JSE_FIRST_YEAR: i32 = 1995;
+
+/// Election days and holidays proclaimed by the President
+pub const ZA_SPECIAL_DAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(1999, 6, 2),
+        "National and Provincial Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(1999, 12, 31),
+        "Millennium Holiday",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2000, 1, 3),
+        "Millennium Holiday",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2000, 12, 5),
+        "Local Government Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2004, 4, 14),
+        "National and Provincial Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2006, 3, 1),
+        "Local Government Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2008, 5, 2),
+        "Public Holiday",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2009, 4, 22),
+        "National and Provincial Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2011, 5, 18),
+        "Local Government Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2014, 5, 7),
+        "National and Provincial Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2016, 8, 3),
+        "Local Government Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2019, 5, 8),
+        "National and Provincial Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2021, 11, 1),
+        "Local Government Elections",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2023, 12, 15),
+        "Rugby World Cup Victory Holiday",
+        "Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2024, 5, 29),
+        "National and Provincial Elections",
+        "Presidential proclamation",
+    ),
+];
+
+/// Get all JSE holidays for a given year
+pub fn get_jse_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_jse_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all JSE holidays for a given year with their names, sorted by date
+pub fn get_jse_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut rules = vec![
+        ("New Year's Day", fixed(1, 1)),
+        ("Human Rights Day", fixed(3, 21)),
+        ("Freedom Day", fixed(4, 27)),
+        ("Workers' Day", fixed(5, 1)),
+        ("Youth Day", fixed(6, 16)),
+        ("National Women's Day", fixed(8, 9)),
+        ("Heritage Day", fixed(9, 24)),
+        ("Day of Reconciliation", fixed(12, 16)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Day of Goodwill", fixed(12, 26)),
+    ];
+    if let Ok(date) = calculate_good_friday(year) {
+        rules.push(("Good Friday", date));
+    }
+    if let Ok(date) = calculate_easter_monday(year) {
+        rules.push(("Family Day", date));
+    }
+    rules.sort_by_key(|(_, date)| *date);
+
+    let mut taken: HashSet<NaiveDate> = rules.iter().map(|(_, date)| *date).collect();
+    let mut holidays = Vec::with_capacity(rules.len() + 2);
+    for (name, date) in rules {
+        if date.weekday() == Weekday::Sun {
+            let mut substitute = date + Duration::days(1);
+            while taken.contains(&substitute) {
+                substitute += Duration::days(1);
+            }
+            taken.insert(substitute);
+            holidays.push(Holiday::new(
+                substitute,
+                &format!("{name} (observed)"),
+                true,
+            ));
+        } else if date.weekday() != Weekday::Sat {
+            holidays.push(Holiday::new(date, name, true));
+        }
+    }
+
+    holidays.extend(
+        special_days_in(ZA_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed() && !is_weekend(day.date))
+            .map(SpecialDay::to_holiday),
+    );
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Get all JSE early closes for a given year, sorted by date
+pub fn get_jse_early_closes(year: i32) -> Vec<Holiday> {
+    let holidays = get_jse_holidays(year);
+    let mut early_closes = Vec::new();
+    for (day, name) in [(24, "Christmas Eve"), (31, "New Year's Eve")] {
+        early_closes.push(Holiday::with_early_close(
+            NaiveDate::from_ymd_opt(year, 12, day).expect("Valid date"),
+            name,
+            ZA_EARLY_CLOSE,
+        ));
+    }
+    early_closes.retain(|day| !is_weekend(day.date) && !holidays.contains(&day.date));
+    early_closes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jse_holidays_2025() {
+        let dates: Vec<_> = get_jse_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 3, 21),
+                date(2025, 4, 18),
+                date(2025, 4, 21),
+                date(2025, 4, 28), // Freedom Day on Sunday
+                date(2025, 5, 1),
+                date(2025, 6, 16),
+                date(2025, 9, 24),
+                date(2025, 12, 16),
+                date(2025, 12, 25),
+                date(2025, 12, 26),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_christmas_on_sunday() {
+        // 2022: Christmas Day on Sunday, Day of Goodwill on Monday
+        let holidays = get_jse_holidays(2022);
+        assert!(holidays.contains(&date(2022, 12, 26)));
+        assert!(holidays.contains(&date(2022, 12, 27)));
+        // National Women's Day 2025 falls on a Saturday and is not replaced
+        assert!(!get_jse_holidays(2025).contains(&date(2025, 8, 11)));
+    }
+
+    #[test]
+    fn test_proclaimed_holidays() {
+        assert!(get_jse_holidays(2024).contains(&date(2024, 5, 29)));
+        assert!(get_jse_holidays(2023).contains(&date(2023, 12, 15)));
+        assert_eq!(
+            get_jse_early_closes(2025)
+                .iter()
+                .map(|h| h.date)
+                .collect::<Vec<_>>(),
+            [date(2025, 12, 24), date(2025, 12, 31)]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! South African market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{special_days_in, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, SpecialDay, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// South African market implementation (Johannesburg Stock Exchange)
+pub struct JSEMarket {
+    cache: HolidayCache,
+}
+
+impl JSEMarket {
+    /// Create a new JSE market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for JSEMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for JSEMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_jse_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_jse_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_jse_early_closes(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::ZA_SPECIAL_DAYS, year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(ZA_REGULAR_OPEN, ZA_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Africa::Johannesburg
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::JSE_FIRST_YEAR..=MAX_YEAR
+    }
+}
@@ -0,0 +1,71 @@
+//! Spanish holiday rules and calculations
+//!
+//! BME closes on the TARGET2 holidays and trades half days on Christmas
+//! Eve and New Year's Eve.
+
+use crate::constants::ES_EARLY_CLOSE;
+use crate::markets::{weekday_early_closes, weekday_holidays};
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::Holiday;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const BME_FIRST_YEAR: i32 = 2010;
+
+/// Get all BME holidays for a given year
+pub fn get_bme_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_bme_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all BME holidays for a given year with their names, sorted by date
+pub fn get_bme_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Christmas Day", fixed(12, 25)),
+        ("St. Stephen's Day", fixed(12, 26)),
+    ])
+}
+
+/// Get all BME half days (14:00 close) for a given year, sorted by date
+pub fn get_bme_early_closes(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_early_closes(
+        [
+            ("Christmas Eve", fixed(12, 24)),
+            ("New Year's Eve", fixed(12, 31)),
+        ],
+        ES_EARLY_CLOSE,
+        &get_bme_holiday_list(year),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_bme_holidays_2025() {
+        let holidays = get_bme_holidays(2025);
+        assert_eq!(holidays.len(), 6);
+        // Epiphany and National Day are trading days
+        assert!(!holidays.contains(&date(2025, 1, 6)));
+        assert!(!get_bme_holidays(2026).contains(&date(2026, 10, 12)));
+    }
+
+    #[test]
+    fn test_bme_early_closes() {
+        let early_closes = get_bme_early_closes(2024);
+        assert_eq!(early_closes.len(), 2);
+        assert_eq!(early_closes[1].date, date(2024, 12, 31));
+        assert_eq!(early_closes[1].early_close, Some(ES_EARLY_CLOSE));
+    }
+}
@@ -0,0 +1,59 @@
+//! Swiss holiday rules and calculations
+//!
+//! SIX closes on Berchtoldstag (January 2nd), Ascension Day, Whit Monday
+//! and Swiss National Day on top of the TARGET2 holidays, and on Christmas
+//! Eve and New Year's Eve.
+
+use crate::markets::weekday_holidays;
+use crate::utils::{
+    calculate_ascension_day, calculate_easter_monday, calculate_good_friday, calculate_whit_monday,
+};
+use crate::Holiday;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const SIX_FIRST_YEAR: i32 = 2010;
+
+/// Get all SIX holidays for a given year
+pub fn get_six_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_six_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all SIX holidays for a given year with their names, sorted by date
+pub fn get_six_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    weekday_holidays([
+        ("New Year's Day", fixed(1, 1)),
+        ("Berchtoldstag", fixed(1, 2)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Ascension Day", calculate_ascension_day(year).ok()),
+        ("Whit Monday", calculate_whit_monday(year).ok()),
+        ("Swiss National Day", fixed(8, 1)),
+        ("Christmas Eve", fixed(12, 24)),
+        ("Christmas Day", fixed(12, 25)),
+        ("St. Stephen's Day", fixed(12, 26)),
+        ("New Year's Eve", fixed(12, 31)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_six_holidays_2025() {
+        let holidays = get_six_holidays(2025);
+        assert!(holidays.contains(&date(2025, 1, 2))); // Berchtoldstag
+        assert!(holidays.contains(&date(2025, 5, 29))); // Ascension Day
+        assert!(holidays.contains(&date(2025, 6, 9))); // Whit Monday
+        assert!(holidays.contains(&date(2025, 8, 1))); // Swiss National Day
+        assert_eq!(holidays.len(), 12);
+    }
+}
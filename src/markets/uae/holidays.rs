@@ -0,0 +1,130 @@
+//! UAE holiday rules and calculations
+//!
+//! The Dubai Financial Market and the Abu Dhabi Securities Exchange close
+//! on the federal public holidays. The UAE moved its weekend from
+//! Friday-Saturday to Saturday-Sunday on January 1st, 2022.
+//!
+//! The Islamic holidays follow the Umm al-Qura calendar; the Cabinet may
+//! move a holiday, which is recorded as an announced date.
+
+use crate::constants::date;
+use crate::markets::{apply_announced_periods, WeekendMask};
+use crate::utils::hijri::{hijri_dates_in, hijri_days_in, DHU_AL_HIJJAH, RAMADAN, SHAWWAL};
+use crate::Holiday;
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
+
+/// First year the holiday rules are defined for
+pub const UAE_FIRST_YEAR: i32 = 2020;
+
+/// First day of the Saturday-Sunday weekend
+const SATURDAY_SUNDAY_FROM: NaiveDate = date(2022, 1, 1);
+
+/// Holidays the Cabinet moved away from their Hijri date
+const AE_ANNOUNCED_CLOSURES: &[(&str, NaiveDate, NaiveDate)] = &[
+    ("Islamic New Year", date(2023, 7, 21), date(2023, 7, 21)),
+    ("Prophet's Birthday", date(2023, 9, 29), date(2023, 9, 29)),
+];
+
+/// Get the UAE weekend in force on a date
+pub fn uae_weekend(date: NaiveDate) -> WeekendMask {
+    if date < SATURDAY_SUNDAY_FROM {
+        WeekendMask::FRIDAY_SATURDAY
+    } else {
+        WeekendMask::SATURDAY_SUNDAY
+    }
+}
+
+/// Get all DFM/ADX holidays for a given year
+pub fn get_uae_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_uae_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all DFM/ADX holidays for a given year with their names, sorted by
+/// date
+pub fn get_uae_holiday_list(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).expect("Valid date");
+    let mut rules = vec![("New Year's Day", fixed(1, 1))];
+
+    // From the 29th of Ramadan to the 3rd of Shawwal; a 29-day Ramadan
+    // makes the 30th the first of Shawwal
+    for day in hijri_days_in(year, RAMADAN, 29, 2)
+        .into_iter()
+        .chain(hijri_days_in(year, SHAWWAL, 1, 3))
+    {
+        rules.push(("Eid al-Fitr", day));
+    }
+    for day in hijri_dates_in(year, DHU_AL_HIJJAH, 9) {
+        rules.push(("Arafat Day", day));
+    }
+    for day in hijri_days_in(year, DHU_AL_HIJJAH, 10, 3) {
+        rules.push(("Eid al-Adha", day));
+    }
+    for day in hijri_dates_in(year, 1, 1) {
+        rules.push(("Islamic New Year", day));
+    }
+    for day in hijri_dates_in(year, 3, 12) {
+        rules.push(("Prophet's Birthday", day));
+    }
+    apply_announced_periods(&mut rules, AE_ANNOUNCED_CLOSURES, year);
+
+    rules.push(("Commemoration Day", fixed(12, 1)));
+    rules.push(("National Day", fixed(12, 2)));
+    rules.push(("National Day", fixed(12, 3)));
+
+    let mut holidays: Vec<Holiday> = rules
+        .into_iter()
+        .filter(|(_, date)| date.year() == year && !uae_weekend(*date).contains(date.weekday()))
+        .map(|(name, date)| Holiday::new(date, name, true))
+        .collect();
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays.dedup_by_key(|holiday| holiday.date);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uae_holidays_2025() {
+        let dates: Vec<_> = get_uae_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 3, 31),
+                date(2025, 4, 1),
+                date(2025, 6, 5), // Arafat Day
+                date(2025, 6, 6),
+                date(2025, 6, 27), // Islamic New Year on a Friday
+                date(2025, 9, 5),  // Prophet's Birthday on a Friday
+                date(2025, 12, 1),
+                date(2025, 12, 2),
+                date(2025, 12, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekend_switch() {
+        assert!(uae_weekend(date(2021, 12, 31)).contains(chrono::Weekday::Fri));
+        assert!(uae_weekend(date(2022, 1, 2)).contains(chrono::Weekday::Sun));
+
+        // December 2nd, 2021 (Thursday) and 3rd (Friday, weekend)
+        let holidays = get_uae_holidays(2021);
+        assert!(holidays.contains(&date(2021, 12, 2)));
+        assert!(!holidays.contains(&date(2021, 12, 3)));
+    }
+
+    #[test]
+    fn test_announced_moves() {
+        let holidays = get_uae_holidays(2023);
+        assert!(holidays.contains(&date(2023, 7, 21)));
+        assert!(holidays.contains(&date(2023, 9, 29)));
+        assert!(!holidays.contains(&date(2023, 9, 27)));
+    }
+}
@@ -0,0 +1,71 @@
+//! UAE market implementation
+
+pub mod holidays;
+
+use crate::constants::*;
+use crate::markets::{MarketImpl, WeekendMask};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, TradingHours, MAX_YEAR};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use std::ops::RangeInclusive;
+
+/// UAE market implementation (Dubai Financial Market and Abu Dhabi
+/// Securities Exchange)
+pub struct UAEMarket {
+    cache: HolidayCache,
+}
+
+impl UAEMarket {
+    /// Create a new DFM/ADX market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for UAEMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for UAEMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_uae_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_uae_holiday_list(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        TradingHours::new(
+            date,
+            Session::new_unchecked(AE_REGULAR_OPEN, AE_REGULAR_CLOSE),
+            None, // No pre-market
+            None, // No after-hours
+        )
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::Asia::Dubai
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        holidays::UAE_FIRST_YEAR..=MAX_YEAR
+    }
+
+    fn weekend(&self, date: NaiveDate) -> WeekendMask {
+        holidays::uae_weekend(date)
+    }
+}
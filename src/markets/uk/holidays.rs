@@ -1,58 +1,147 @@
 //! UK holiday rules and calculations
 
+use crate::constants::{date, UK_EARLY_CLOSE};
+use crate::markets::{is_weekend, special_days_in};
 use crate::utils::{
     calculate_easter_monday, calculate_good_friday, last_weekday_of_month, nth_weekday_of_month,
 };
+use crate::{Holiday, SpecialDay};
 use chrono::{Datelike, NaiveDate, Weekday};
 use std::collections::HashSet;
 
 /// Expected number of UK holidays per year
 const UK_HOLIDAYS_PER_YEAR: usize = 10;
 
+/// One-off bank holidays on which the LSE closed
+pub const UK_SPECIAL_DAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(1999, 12, 31),
+        "Millennium Celebrations",
+        "Bank holiday by royal proclamation",
+    ),
+    SpecialDay::closed(
+        date(2002, 6, 4),
+        "Golden Jubilee of Queen Elizabeth II",
+        "Bank holiday by royal proclamation",
+    ),
+    SpecialDay::closed(
+        date(2011, 4, 29),
+        "Wedding of Prince William and Catherine Middleton",
+        "Bank holiday by royal proclamation",
+    ),
+    SpecialDay::closed(
+        date(2012, 6, 5),
+        "Diamond Jubilee of Queen Elizabeth II",
+        "Bank holiday by royal proclamation",
+    ),
+    SpecialDay::closed(
+        date(2022, 6, 3),
+        "Platinum Jubilee of Queen Elizabeth II",
+        "Bank holiday by royal proclamation",
+    ),
+    SpecialDay::closed(
+        date(2022, 9, 19),
+        "State Funeral of Queen Elizabeth II",
+        "Bank holiday by royal proclamation",
+    ),
+    SpecialDay::closed(
+        date(2023, 5, 8),
+        "Coronation of King Charles III",
+        "Bank holiday by royal proclamation",
+    ),
+];
+
 /// Get all UK holidays for a given year
 pub fn get_uk_holidays(year: i32) -> HashSet<NaiveDate> {
-    let mut holidays = HashSet::with_capacity(UK_HOLIDAYS_PER_YEAR);
+    get_uk_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all UK holidays for a given year with their names, sorted by date
+pub fn get_uk_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays = Vec::with_capacity(UK_HOLIDAYS_PER_YEAR);
 
     // New Year's Day
-    holidays.insert(adjust_for_weekend_uk(
-        NaiveDate::from_ymd_opt(year, 1, 1).expect("Valid date"),
+    let new_year = NaiveDate::from_ymd_opt(year, 1, 1).expect("Valid date");
+    holidays.push(substitute(
+        new_year,
+        adjust_for_weekend_uk(new_year),
+        "New Year's Day",
     ));
 
     // Good Friday
     if let Ok(date) = calculate_good_friday(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Good Friday", true));
     }
 
     // Easter Monday
     if let Ok(date) = calculate_easter_monday(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Easter Monday", true));
     }
 
-    // Early May Bank Holiday (1st Monday of May)
-    if let Some(date) = nth_weekday_of_month(year, 5, Weekday::Mon, 1) {
-        holidays.insert(date);
+    // Early May Bank Holiday (1st Monday of May), moved to May 8th for the
+    // 50th and 75th anniversaries of VE Day
+    let early_may = match year {
+        1995 | 2020 => NaiveDate::from_ymd_opt(year, 5, 8),
+        _ => nth_weekday_of_month(year, 5, Weekday::Mon, 1),
+    };
+    if let Some(date) = early_may {
+        holidays.push(Holiday::new(date, "Early May Bank Holiday", true));
     }
 
-    // Spring Bank Holiday (last Monday of May)
-    if let Some(date) = last_weekday_of_month(year, 5, Weekday::Mon) {
-        holidays.insert(date);
+    // Spring Bank Holiday (last Monday of May), moved to June for the
+    // Golden, Diamond and Platinum Jubilees
+    let spring = match year {
+        2002 => NaiveDate::from_ymd_opt(2002, 6, 3),
+        2012 => NaiveDate::from_ymd_opt(2012, 6, 4),
+        2022 => NaiveDate::from_ymd_opt(2022, 6, 2),
+        _ => last_weekday_of_month(year, 5, Weekday::Mon),
+    };
+    if let Some(date) = spring {
+        holidays.push(Holiday::new(date, "Spring Bank Holiday", true));
     }
 
     // Summer Bank Holiday (last Monday of August)
     if let Some(date) = last_weekday_of_month(year, 8, Weekday::Mon) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Summer Bank Holiday", true));
     }
 
-    // Christmas Day
-    let christmas = NaiveDate::from_ymd_opt(year, 12, 25).expect("Valid date");
-    holidays.insert(adjust_for_weekend_uk(christmas));
+    // Christmas Day and Boxing Day (special rules)
+    add_christmas_and_boxing_day(&mut holidays, year);
 
-    // Boxing Day (special rules)
-    add_boxing_day(&mut holidays, year);
+    holidays.extend(
+        special_days_in(UK_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed())
+            .map(SpecialDay::to_holiday),
+    );
 
+    holidays.sort_by_key(|holiday| holiday.date);
     holidays
 }
 
+/// Get all LSE half days (12:30 close) for a given year, sorted by date
+pub fn get_uk_early_closes(year: i32) -> Vec<Holiday> {
+    let holidays = get_uk_holidays(year);
+    let mut early_closes = vec![
+        Holiday::with_early_close(
+            NaiveDate::from_ymd_opt(year, 12, 24).expect("Valid date"),
+            "Christmas Eve",
+            UK_EARLY_CLOSE,
+        ),
+        Holiday::with_early_close(
+            NaiveDate::from_ymd_opt(year, 12, 31).expect("Valid date"),
+            "New Year's Eve",
+            UK_EARLY_CLOSE,
+        ),
+    ];
+
+    early_closes.retain(|day| !is_weekend(day.date) && !holidays.contains(&day.date));
+    early_closes
+}
+
 fn adjust_for_weekend_uk(date: NaiveDate) -> NaiveDate {
     match date.weekday() {
         Weekday::Sat => date + chrono::Duration::days(2),
@@ -61,33 +150,48 @@ fn adjust_for_weekend_uk(date: NaiveDate) -> NaiveDate {
     }
 }
 
-fn add_boxing_day(holidays: &mut HashSet<NaiveDate>, year: i32) {
+/// Name a holiday, marking it as a substitute day when it was moved
+fn substitute(actual: NaiveDate, observed: NaiveDate, name: &str) -> Holiday {
+    if actual == observed {
+        Holiday::new(observed, name, true)
+    } else {
+        Holiday::new(observed, &format!("{name} (substitute day)"), true)
+    }
+}
+
+fn add_christmas_and_boxing_day(holidays: &mut Vec<Holiday>, year: i32) {
     let christmas = NaiveDate::from_ymd_opt(year, 12, 25).expect("Valid date");
     let boxing = NaiveDate::from_ymd_opt(year, 12, 26).expect("Valid date");
+    let dec_27 = NaiveDate::from_ymd_opt(year, 12, 27).expect("Valid date");
+    let dec_28 = NaiveDate::from_ymd_opt(year, 12, 28).expect("Valid date");
 
     match christmas.weekday() {
         Weekday::Fri => {
             // Christmas on Friday = observed Friday
             // Boxing Day on Saturday = observed Monday
-            holidays.insert(christmas);
-            holidays.insert(boxing + chrono::Duration::days(2));
+            holidays.push(Holiday::new(christmas, "Christmas Day", true));
+            holidays.push(substitute(boxing, dec_28, "Boxing Day"));
         }
         Weekday::Sat => {
             // Christmas on Saturday = observed Monday (27th)
             // Boxing Day on Sunday = observed Tuesday (28th)
-            holidays.insert(NaiveDate::from_ymd_opt(year, 12, 27).unwrap());
-            holidays.insert(NaiveDate::from_ymd_opt(year, 12, 28).unwrap());
+            holidays.push(substitute(christmas, dec_27, "Christmas Day"));
+            holidays.push(substitute(boxing, dec_28, "Boxing Day"));
         }
         Weekday::Sun => {
-            // Christmas on Sunday = observed Monday (26th)
-            // Boxing Day on Monday = observed Tuesday (27th)
-            holidays.insert(boxing);
-            holidays.insert(NaiveDate::from_ymd_opt(year, 12, 27).unwrap());
+            // Christmas on Sunday = substitute day Tuesday (27th)
+            // Boxing Day on Monday = observed Monday (26th)
+            holidays.push(Holiday::new(boxing, "Boxing Day", true));
+            holidays.push(substitute(christmas, dec_27, "Christmas Day"));
         }
         _ => {
             // Christmas on weekday
-            holidays.insert(christmas);
-            holidays.insert(adjust_for_weekend_uk(boxing));
+            holidays.push(Holiday::new(christmas, "Christmas Day", true));
+            holidays.push(substitute(
+                boxing,
+                adjust_for_weekend_uk(boxing),
+                "Boxing Day",
+            ));
         }
     }
 }
@@ -156,4 +260,75 @@ mod tests {
         assert!(holidays_2025.contains(&NaiveDate::from_ymd_opt(2025, 12, 26).unwrap()));
         // Boxing Day
     }
+
+    #[test]
+    fn test_uk_holiday_names() {
+        let holidays = get_uk_holiday_list(2025);
+        assert_eq!(holidays.len(), 8);
+
+        let spring = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2025, 5, 26).unwrap())
+            .unwrap();
+        assert_eq!(spring.name, "Spring Bank Holiday");
+
+        // 2022: Christmas on Sunday, substitute day on Tuesday 27th
+        let holidays = get_uk_holiday_list(2022);
+        let names: Vec<_> = holidays
+            .iter()
+            .filter(|h| h.date.month() == 12)
+            .map(|h| (h.date.day(), h.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![(26, "Boxing Day"), (27, "Christmas Day (substitute day)")]
+        );
+    }
+
+    #[test]
+    fn test_uk_early_closes() {
+        // 2025: Christmas Eve Wednesday, New Year's Eve Wednesday
+        let early_closes = get_uk_early_closes(2025);
+        assert_eq!(early_closes.len(), 2);
+        assert_eq!(
+            early_closes[0].date,
+            NaiveDate::from_ymd_opt(2025, 12, 24).unwrap()
+        );
+        assert_eq!(early_closes[1].name, "New Year's Eve");
+        assert_eq!(early_closes[1].early_close, Some(UK_EARLY_CLOSE));
+
+        // 2022: both fell on Saturdays
+        assert!(get_uk_early_closes(2022).is_empty());
+    }
+
+    #[test]
+    fn test_moved_bank_holidays() {
+        // VE Day anniversaries
+        let holidays = get_uk_holidays(2020);
+        assert!(holidays.contains(&NaiveDate::from_ymd_opt(2020, 5, 8).unwrap()));
+        assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2020, 5, 4).unwrap()));
+        assert!(get_uk_holidays(1995).contains(&NaiveDate::from_ymd_opt(1995, 5, 8).unwrap()));
+
+        // Platinum Jubilee: Spring Bank Holiday on Thursday June 2nd
+        let holidays = get_uk_holidays(2022);
+        assert!(holidays.contains(&NaiveDate::from_ymd_opt(2022, 6, 2).unwrap()));
+        assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2022, 5, 30).unwrap()));
+    }
+
+    #[test]
+    fn test_special_bank_holidays() {
+        let closed =
+            |y, m, d| get_uk_holidays(y).contains(&NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert!(closed(1999, 12, 31));
+        assert!(closed(2011, 4, 29));
+        assert!(closed(2022, 6, 3));
+        assert!(closed(2022, 9, 19));
+        assert!(closed(2023, 5, 8));
+
+        // The Millennium bank holiday replaces the New Year's Eve half day
+        let millennium = NaiveDate::from_ymd_opt(1999, 12, 31).unwrap();
+        assert!(!get_uk_early_closes(1999)
+            .iter()
+            .any(|h| h.date == millennium));
+    }
 }
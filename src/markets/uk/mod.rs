@@ -3,9 +3,9 @@
 pub mod holidays;
 
 use crate::constants::*;
-use crate::markets::MarketImpl;
+use crate::markets::{special_days_in, MarketImpl};
 use crate::utils::HolidayCache;
-use crate::{Session, TradingHours};
+use crate::{Holiday, Session, SpecialDay, TradingHours};
 use chrono::{Datelike, NaiveDate};
 use chrono_tz::Tz;
 
@@ -38,16 +38,34 @@ impl MarketImpl for LSEMarket {
         holidays.contains(&date)
     }
 
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_uk_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_uk_early_closes(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::UK_SPECIAL_DAYS, year)
+    }
+
     fn trading_hours(&self, date: NaiveDate) -> TradingHours {
-        TradingHours::new(
+        let mut hours = TradingHours::new(
             date,
             Session::new_unchecked(UK_REGULAR_OPEN, UK_REGULAR_CLOSE),
             None, // No pre-market
             None, // No after-hours
-        )
+        );
+        hours.early_close = self.early_close(date);
+        hours
     }
 
     fn timezone(&self) -> Tz {
         chrono_tz::Europe::London
     }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
 }
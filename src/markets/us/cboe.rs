@@ -0,0 +1,134 @@
+//! Cboe options market implementation
+//!
+//! Cboe Options follows the NYSE holiday calendar. Equity and ETF options
+//! trade the NYSE regular session; cash-settled index options such as SPX
+//! trade until 16:15 ET, and until 13:15 ET on half days.
+
+use super::holidays;
+use crate::constants::*;
+use crate::markets::{special_days_in, MarketImpl};
+use crate::utils::HolidayCache;
+use crate::{Holiday, Session, SpecialDay, TradingHours};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+
+/// Cboe options market implementation
+pub struct CboeMarket {
+    cache: HolidayCache,
+    index_options: bool,
+}
+
+impl CboeMarket {
+    /// Create a new Cboe market instance for equity and ETF options
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+            index_options: false,
+        }
+    }
+
+    /// Create a new Cboe market instance for index options
+    pub fn index_options() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+            index_options: true,
+        }
+    }
+}
+
+impl Default for CboeMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for CboeMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self
+            .cache
+            .get_or_compute(year, || holidays::get_us_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_us_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        let early_closes = holidays::get_us_early_closes(year);
+        if !self.index_options {
+            return early_closes;
+        }
+        early_closes
+            .into_iter()
+            .map(|day| match day.early_close {
+                Some(US_EARLY_CLOSE) => {
+                    Holiday::with_early_close(day.date, &day.name, CBOE_INDEX_EARLY_CLOSE)
+                }
+                _ => day,
+            })
+            .collect()
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::US_SPECIAL_DAYS, year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let close = if self.index_options {
+            CBOE_INDEX_CLOSE
+        } else {
+            US_REGULAR_CLOSE
+        };
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(US_REGULAR_OPEN, close),
+            None, // No pre-market
+            None, // No after-hours
+        );
+        hours.early_close = self.early_close(date);
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::America::New_York
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_options_close() {
+        let day = date(2025, 3, 10);
+        assert_eq!(
+            CboeMarket::new().trading_hours(day).market_close(),
+            US_REGULAR_CLOSE
+        );
+        assert_eq!(
+            CboeMarket::index_options()
+                .trading_hours(day)
+                .market_close(),
+            CBOE_INDEX_CLOSE
+        );
+    }
+
+    #[test]
+    fn test_index_options_half_day() {
+        let black_friday = date(2025, 11, 28);
+        assert_eq!(
+            CboeMarket::new().early_close(black_friday),
+            Some(US_EARLY_CLOSE)
+        );
+        assert_eq!(
+            CboeMarket::index_options().early_close(black_friday),
+            Some(CBOE_INDEX_EARLY_CLOSE)
+        );
+    }
+}
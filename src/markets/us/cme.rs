@@ -0,0 +1,187 @@
+//! CME Globex market implementation
+//!
+//! Globex trades nearly around the clock: each trading date's session opens
+//! at 17:00 CT on the previous calendar day, so Monday's session opens on
+//! Sunday evening, and closes at 16:00 CT for the daily maintenance break.
+//! On most US holidays Globex does not close but halts at noon; it closes
+//! fully only on New Year's Day, Good Friday and Christmas Day.
+
+use super::holidays::{
+    christmas_day, get_us_early_closes, independence_day, juneteenth, labor_day, memorial_day,
+    mlk_day, new_years_day, presidents_day, thanksgiving_day, US_SPECIAL_DAYS,
+};
+use crate::constants::*;
+use crate::markets::MarketImpl;
+use crate::utils::{calculate_good_friday, HolidayCache};
+use crate::{Holiday, Session, TradingHours};
+use chrono::{Datelike, NaiveDate, Weekday};
+use chrono_tz::Tz;
+use std::collections::HashSet;
+
+/// CME Globex market implementation (equity index futures)
+pub struct CMEMarket {
+    cache: HolidayCache,
+}
+
+impl CMEMarket {
+    /// Create a new CME market instance
+    pub fn new() -> Self {
+        Self {
+            cache: HolidayCache::default(),
+        }
+    }
+}
+
+impl Default for CMEMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketImpl for CMEMarket {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = self.cache.get_or_compute(year, || get_cme_holidays(year));
+        holidays.contains(&date)
+    }
+
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        get_cme_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        get_cme_early_closes(year)
+    }
+
+    fn trading_hours(&self, date: NaiveDate) -> TradingHours {
+        let mut hours = TradingHours::new(
+            date,
+            Session::new_unchecked(CME_REGULAR_OPEN, CME_REGULAR_CLOSE),
+            // The overnight session opens on the previous calendar day
+            Some(Session::new_unchecked(CME_GLOBEX_OPEN, CME_REGULAR_OPEN)),
+            Some(Session::new_unchecked(CME_REGULAR_CLOSE, CME_GLOBEX_CLOSE)),
+        );
+
+        if let Some(early_close) = self.early_close(date) {
+            hours.early_close = Some(early_close);
+            // Globex halts with the regular session
+            hours.after_hours = None;
+        }
+
+        hours
+    }
+
+    fn timezone(&self) -> Tz {
+        chrono_tz::America::Chicago
+    }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
+}
+
+/// Get all CME Globex full closures for a given year
+pub fn get_cme_holidays(year: i32) -> HashSet<NaiveDate> {
+    get_cme_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all CME Globex full closures for a given year with their names,
+/// sorted by date
+pub fn get_cme_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays = Vec::with_capacity(3);
+
+    // Like NYSE, Globex trades through a Saturday New Year's Day
+    let new_year = new_years_day(year);
+    if new_year.weekday() != Weekday::Sat {
+        holidays.push(Holiday::new(new_year, "New Year's Day", true));
+    }
+    if let Ok(date) = calculate_good_friday(year) {
+        holidays.push(Holiday::new(date, "Good Friday", true));
+    }
+    holidays.push(Holiday::new(christmas_day(year), "Christmas Day", true));
+
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Get all CME Globex holiday halts and early closes for a given year,
+/// sorted by date
+///
+/// US holidays the exchange does not close for halt trading at 12:00 CT;
+/// the NYSE half days close at 12:15 CT.
+pub fn get_cme_early_closes(year: i32) -> Vec<Holiday> {
+    let mut halts = vec![
+        ("Martin Luther King Jr. Day", mlk_day(year)),
+        ("Presidents' Day", presidents_day(year)),
+        ("Memorial Day", memorial_day(year)),
+        ("Independence Day", Some(independence_day(year))),
+        ("Labor Day", labor_day(year)),
+        ("Thanksgiving Day", thanksgiving_day(year)),
+    ];
+    if year >= 2022 {
+        halts.push((
+            "Juneteenth National Independence Day",
+            Some(juneteenth(year)),
+        ));
+    }
+
+    let mut early_closes: Vec<Holiday> = halts
+        .into_iter()
+        .filter_map(|(name, date)| {
+            date.map(|date| Holiday::with_early_close(date, name, CME_HOLIDAY_HALT))
+        })
+        .collect();
+
+    // One-off NYSE early closes do not apply to Globex
+    early_closes.extend(
+        get_us_early_closes(year)
+            .into_iter()
+            .filter(|day| {
+                !US_SPECIAL_DAYS
+                    .iter()
+                    .any(|special| special.date == day.date)
+            })
+            .map(|day| Holiday::with_early_close(day.date, &day.name, CME_EARLY_CLOSE)),
+    );
+    early_closes.sort_by_key(|day| day.date);
+    early_closes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cme_closures_2025() {
+        let dates: Vec<_> = get_cme_holiday_list(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [date(2025, 1, 1), date(2025, 4, 18), date(2025, 12, 25)]
+        );
+    }
+
+    #[test]
+    fn test_holiday_halts_are_trading_days() {
+        let market = CMEMarket::new();
+        let mlk = date(2025, 1, 20);
+        assert!(market.is_trading_day(mlk));
+        assert_eq!(market.early_close(mlk), Some(CME_HOLIDAY_HALT));
+        assert_eq!(
+            market.early_close(date(2025, 11, 28)),
+            Some(CME_EARLY_CLOSE)
+        );
+        assert_eq!(market.early_closes(2025).len(), 10);
+    }
+
+    #[test]
+    fn test_overnight_session() {
+        let hours = CMEMarket::new().trading_hours(date(2025, 3, 10));
+        let overnight = hours.pre_market.unwrap();
+        assert_eq!(overnight.start, CME_GLOBEX_OPEN);
+        assert_eq!(overnight.end, CME_REGULAR_OPEN);
+        assert_eq!(hours.after_hours.unwrap().end, CME_GLOBEX_CLOSE);
+    }
+}
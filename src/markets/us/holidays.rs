@@ -1,54 +1,190 @@
 //! US holiday rules and calculations
 
+use crate::constants::{date, time, US_EARLY_CLOSE};
+use crate::markets::{is_weekend, special_days_in};
 use crate::utils::{calculate_good_friday, last_weekday_of_month, nth_weekday_of_month};
+use crate::{Holiday, SpecialDay};
 use chrono::{Datelike, NaiveDate, Weekday};
 use std::collections::HashSet;
 
 /// Expected number of US holidays per year
 const US_HOLIDAYS_PER_YEAR: usize = 11;
 
+/// One-off NYSE closures and early closes
+pub const US_SPECIAL_DAYS: &[SpecialDay] = &[
+    SpecialDay::closed(
+        date(1994, 4, 27),
+        "National Day of Mourning for President Richard Nixon",
+        "NYSE; Presidential proclamation",
+    ),
+    SpecialDay::early_close(
+        date(1997, 10, 27),
+        time(15, 30),
+        "Circuit breaker halt",
+        "NYSE Rule 80B",
+    ),
+    SpecialDay::closed(date(2001, 9, 11), "September 11 attacks", "NYSE"),
+    SpecialDay::closed(date(2001, 9, 12), "September 11 attacks", "NYSE"),
+    SpecialDay::closed(date(2001, 9, 13), "September 11 attacks", "NYSE"),
+    SpecialDay::closed(date(2001, 9, 14), "September 11 attacks", "NYSE"),
+    SpecialDay::closed(
+        date(2004, 6, 11),
+        "National Day of Mourning for President Ronald Reagan",
+        "NYSE; Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2007, 1, 2),
+        "National Day of Mourning for President Gerald Ford",
+        "NYSE; Presidential proclamation",
+    ),
+    SpecialDay::closed(date(2012, 10, 29), "Hurricane Sandy", "NYSE"),
+    SpecialDay::closed(date(2012, 10, 30), "Hurricane Sandy", "NYSE"),
+    SpecialDay::closed(
+        date(2018, 12, 5),
+        "National Day of Mourning for President George H. W. Bush",
+        "NYSE; Presidential proclamation",
+    ),
+    SpecialDay::closed(
+        date(2025, 1, 9),
+        "National Day of Mourning for President Jimmy Carter",
+        "NYSE; Presidential proclamation",
+    ),
+];
+
 /// Get all US holidays for a given year
 pub fn get_us_holidays(year: i32) -> HashSet<NaiveDate> {
-    let mut holidays = HashSet::with_capacity(US_HOLIDAYS_PER_YEAR);
+    get_us_holiday_list(year)
+        .into_iter()
+        .map(|holiday| holiday.date)
+        .collect()
+}
+
+/// Get all US holidays for a given year with their names, sorted by date
+pub fn get_us_holiday_list(year: i32) -> Vec<Holiday> {
+    let mut holidays = Vec::with_capacity(US_HOLIDAYS_PER_YEAR);
 
     // Fixed holidays with weekend adjustments
-    holidays.insert(new_years_day(year));
-    holidays.insert(independence_day(year));
-    holidays.insert(christmas_day(year));
+    // A Saturday New Year's Day is not observed: December 31st is the last
+    // trading day of the accounting year
+    let new_year = new_years_day(year);
+    if new_year.weekday() != Weekday::Sat {
+        holidays.push(observed(year, 1, 1, new_year, "New Year's Day"));
+    }
+    holidays.push(observed(
+        year,
+        7,
+        4,
+        independence_day(year),
+        "Independence Day",
+    ));
+    holidays.push(observed(year, 12, 25, christmas_day(year), "Christmas Day"));
 
-    // Juneteenth only became a federal holiday in 2021
-    if year >= 2021 {
-        holidays.insert(juneteenth(year));
+    // Juneteenth became a federal holiday in 2021; NYSE first closed in 2022
+    if year >= 2022 {
+        holidays.push(observed(
+            year,
+            6,
+            19,
+            juneteenth(year),
+            "Juneteenth National Independence Day",
+        ));
     }
 
-    // Variable holidays
-    if let Some(date) = mlk_day(year) {
-        holidays.insert(date);
+    // Variable holidays (NYSE has closed for Martin Luther King Jr. Day since 1998)
+    if year >= 1998 {
+        if let Some(date) = mlk_day(year) {
+            holidays.push(Holiday::new(date, "Martin Luther King Jr. Day", true));
+        }
     }
     if let Some(date) = presidents_day(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Presidents' Day", true));
     }
     if let Some(date) = memorial_day(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Memorial Day", true));
     }
     if let Some(date) = labor_day(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Labor Day", true));
     }
     if let Some(date) = thanksgiving_day(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Thanksgiving Day", true));
     }
     if let Ok(date) = calculate_good_friday(year) {
-        holidays.insert(date);
+        holidays.push(Holiday::new(date, "Good Friday", true));
     }
 
+    holidays.extend(
+        special_days_in(US_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| day.is_closed())
+            .map(SpecialDay::to_holiday),
+    );
+
+    holidays.sort_by_key(|holiday| holiday.date);
     holidays
 }
 
-/// New Year's Day (January 1st, observed on Monday if weekend)
+/// Get all US early close days for a given year, sorted by date
+///
+/// Regular half days close at 13:00 ET; special early closes carry their own
+/// close time.
+pub fn get_us_early_closes(year: i32) -> Vec<Holiday> {
+    let holidays = get_us_holidays(year);
+    let mut early_closes = Vec::with_capacity(3);
+
+    // July 3rd if July 4th is a weekday
+    let july_3 = NaiveDate::from_ymd_opt(year, 7, 3).expect("Valid date");
+    if !is_weekend(july_3 + chrono::Duration::days(1)) {
+        early_closes.push(Holiday::with_early_close(
+            july_3,
+            "Day Before Independence Day",
+            US_EARLY_CLOSE,
+        ));
+    }
+
+    // Day after Thanksgiving (Black Friday)
+    if let Some(thanksgiving) = thanksgiving_day(year) {
+        early_closes.push(Holiday::with_early_close(
+            thanksgiving + chrono::Duration::days(1),
+            "Day After Thanksgiving",
+            US_EARLY_CLOSE,
+        ));
+    }
+
+    // Christmas Eve
+    let christmas_eve = NaiveDate::from_ymd_opt(year, 12, 24).expect("Valid date");
+    early_closes.push(Holiday::with_early_close(
+        christmas_eve,
+        "Christmas Eve",
+        US_EARLY_CLOSE,
+    ));
+
+    early_closes.retain(|day| !is_weekend(day.date) && !holidays.contains(&day.date));
+    early_closes.extend(
+        special_days_in(US_SPECIAL_DAYS, year)
+            .iter()
+            .filter(|day| !day.is_closed())
+            .map(SpecialDay::to_holiday),
+    );
+    early_closes.sort_by_key(|day| day.date);
+    early_closes
+}
+
+/// Name a fixed-date holiday, marking it as observed when it was moved
+fn observed(year: i32, month: u32, day: u32, date: NaiveDate, name: &str) -> Holiday {
+    if NaiveDate::from_ymd_opt(year, month, day) == Some(date) {
+        Holiday::new(date, name, true)
+    } else {
+        Holiday::new(date, &format!("{name} (observed)"), true)
+    }
+}
+
+/// New Year's Day (January 1st, observed on Monday if Sunday)
+///
+/// A Saturday New Year's Day is returned unchanged: NYSE does not close on
+/// the preceding Friday because it ends the accounting year.
 pub fn new_years_day(year: i32) -> NaiveDate {
     let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).expect("Valid date");
     match jan1.weekday() {
-        Weekday::Sat => jan1 + chrono::Duration::days(2),
         Weekday::Sun => jan1 + chrono::Duration::days(1),
         _ => jan1,
     }
@@ -89,23 +225,23 @@ pub fn thanksgiving_day(year: i32) -> Option<NaiveDate> {
     nth_weekday_of_month(year, 11, Weekday::Thu, 4)
 }
 
-/// Juneteenth (June 19th, observed on Monday if weekend)
+/// Juneteenth (June 19th, observed on Friday if Saturday, Monday if Sunday)
 pub fn juneteenth(year: i32) -> NaiveDate {
-    let jun19 = NaiveDate::from_ymd_opt(year, 6, 19).expect("Valid date");
-    match jun19.weekday() {
-        Weekday::Sat => jun19 + chrono::Duration::days(2),
-        Weekday::Sun => jun19 + chrono::Duration::days(1),
-        _ => jun19,
-    }
+    adjust_for_weekend(NaiveDate::from_ymd_opt(year, 6, 19).expect("Valid date"))
 }
 
-/// Christmas Day (December 25th, observed on Monday if weekend)
+/// Christmas Day (December 25th, observed on Friday if Saturday, Monday if Sunday)
 pub fn christmas_day(year: i32) -> NaiveDate {
-    let dec25 = NaiveDate::from_ymd_opt(year, 12, 25).expect("Valid date");
-    match dec25.weekday() {
-        Weekday::Sat => dec25 + chrono::Duration::days(2),
-        Weekday::Sun => dec25 + chrono::Duration::days(1),
-        _ => dec25,
+    adjust_for_weekend(NaiveDate::from_ymd_opt(year, 12, 25).expect("Valid date"))
+}
+
+/// NYSE Rule 7.2: Saturday holidays close the preceding Friday,
+/// Sunday holidays the following Monday
+fn adjust_for_weekend(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - chrono::Duration::days(1),
+        Weekday::Sun => date + chrono::Duration::days(1),
+        _ => date,
     }
 }
 
@@ -171,20 +307,120 @@ mod tests {
             christmas_day(2025),
             NaiveDate::from_ymd_opt(2025, 12, 25).unwrap()
         );
+
+        // 2021: December 25th is Saturday, observed on Friday the 24th
+        assert_eq!(
+            christmas_day(2021),
+            NaiveDate::from_ymd_opt(2021, 12, 24).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_saturday_new_years_day_not_observed() {
+        // January 1st, 2022 was a Saturday: NYSE open on Dec 31st and Jan 3rd
+        let holidays = get_us_holidays(2022);
+        assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2022, 1, 3).unwrap()));
+        let holidays = get_us_holidays(2021);
+        assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2021, 12, 31).unwrap()));
+    }
+
+    #[test]
+    fn test_mlk_day_era() {
+        assert!(!get_us_holidays(1997).contains(&NaiveDate::from_ymd_opt(1997, 1, 20).unwrap()));
+        assert!(get_us_holidays(1998).contains(&NaiveDate::from_ymd_opt(1998, 1, 19).unwrap()));
     }
 
     #[test]
     fn test_juneteenth_year_condition() {
-        // Juneteenth should not be a holiday before 2021
+        // Juneteenth should not be a holiday before 2022
         let holidays_2020 = get_us_holidays(2020);
         assert!(!holidays_2020.contains(&NaiveDate::from_ymd_opt(2020, 6, 19).unwrap()));
 
-        // Juneteenth should be a holiday from 2021 onwards
+        // Federal holiday in 2021, but NYSE stayed open (June 19 was a Saturday)
         let holidays_2021 = get_us_holidays(2021);
-        // June 19, 2021 was Saturday, so observed on Monday June 21
-        assert!(holidays_2021.contains(&NaiveDate::from_ymd_opt(2021, 6, 21).unwrap()));
+        assert!(!holidays_2021.contains(&NaiveDate::from_ymd_opt(2021, 6, 18).unwrap()));
+
+        // Juneteenth should be a holiday from 2022 onwards
+        // June 19, 2022 was Sunday, so observed on Monday June 20
+        let holidays_2022 = get_us_holidays(2022);
+        assert!(holidays_2022.contains(&NaiveDate::from_ymd_opt(2022, 6, 20).unwrap()));
 
         let holidays_2025 = get_us_holidays(2025);
         assert!(holidays_2025.contains(&NaiveDate::from_ymd_opt(2025, 6, 19).unwrap()));
     }
+
+    #[test]
+    fn test_holiday_names() {
+        // Ten regular holidays plus the National Day of Mourning for President Carter
+        let holidays = get_us_holiday_list(2025);
+        assert_eq!(holidays.len(), 11);
+        assert!(holidays.windows(2).all(|pair| pair[0].date < pair[1].date));
+
+        let thanksgiving = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2025, 11, 27).unwrap())
+            .unwrap();
+        assert_eq!(thanksgiving.name, "Thanksgiving Day");
+        assert!(thanksgiving.market_closed);
+
+        // July 4th, 2026 is a Saturday, observed on Friday July 3rd
+        let holidays = get_us_holiday_list(2026);
+        let observed = holidays
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2026, 7, 3).unwrap())
+            .unwrap();
+        assert_eq!(observed.name, "Independence Day (observed)");
+    }
+
+    #[test]
+    fn test_early_closes() {
+        let early_closes = get_us_early_closes(2025);
+        let dates: Vec<_> = early_closes.iter().map(|day| day.date).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2025, 7, 3).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 11, 28).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 12, 24).unwrap(),
+            ]
+        );
+        assert!(early_closes
+            .iter()
+            .all(|day| !day.market_closed && day.early_close == Some(US_EARLY_CLOSE)));
+
+        // 2022: July 3rd and Christmas Eve both fell on weekends
+        let early_closes = get_us_early_closes(2022);
+        assert_eq!(early_closes.len(), 1);
+        assert_eq!(early_closes[0].name, "Day After Thanksgiving");
+    }
+
+    #[test]
+    fn test_special_closures() {
+        let closed =
+            |y, m, d| get_us_holidays(y).contains(&NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert!(closed(2001, 9, 11));
+        assert!(closed(2001, 9, 14));
+        assert!(closed(2012, 10, 29));
+        assert!(closed(2012, 10, 30));
+        assert!(closed(2018, 12, 5));
+        assert!(closed(2025, 1, 9));
+
+        let carter = get_us_holiday_list(2025)
+            .into_iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(2025, 1, 9).unwrap())
+            .unwrap();
+        assert_eq!(
+            carter.name,
+            "National Day of Mourning for President Jimmy Carter"
+        );
+
+        // Special early closes are listed alongside the regular half days
+        let early = get_us_early_closes(1997);
+        let halt = early
+            .iter()
+            .find(|h| h.date == NaiveDate::from_ymd_opt(1997, 10, 27).unwrap())
+            .unwrap();
+        assert_eq!(halt.early_close, chrono::NaiveTime::from_hms_opt(15, 30, 0));
+        assert!(early.windows(2).all(|pair| pair[0].date < pair[1].date));
+    }
 }
@@ -1,11 +1,13 @@
 //! US market implementation
 
+pub mod cboe;
+pub mod cme;
 pub mod holidays;
 
 use crate::constants::*;
-use crate::markets::MarketImpl;
+use crate::markets::{special_days_in, MarketImpl};
 use crate::utils::HolidayCache;
-use crate::{Session, TradingHours};
+use crate::{Holiday, Session, SpecialDay, TradingHours};
 use chrono::{Datelike, NaiveDate};
 use chrono_tz::Tz;
 
@@ -21,32 +23,6 @@ impl USMarket {
             cache: HolidayCache::default(),
         }
     }
-
-    /// Check if date is an early close day
-    fn is_early_close_day(&self, date: NaiveDate) -> bool {
-        // Day after Thanksgiving (Black Friday)
-        if let Some(thanksgiving) = holidays::thanksgiving_day(date.year()) {
-            let black_friday = thanksgiving + chrono::Duration::days(1);
-            if date == black_friday {
-                return true;
-            }
-        }
-
-        // July 3rd if July 4th is a weekday
-        if date.month() == 7 && date.day() == 3 {
-            let july_4th = date + chrono::Duration::days(1);
-            if !crate::markets::is_weekend(july_4th) {
-                return true;
-            }
-        }
-
-        // Christmas Eve if it's a weekday
-        if date.month() == 12 && date.day() == 24 && !crate::markets::is_weekend(date) {
-            return true;
-        }
-
-        false
-    }
 }
 
 impl Default for USMarket {
@@ -64,6 +40,18 @@ impl MarketImpl for USMarket {
         holidays.contains(&date)
     }
 
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_us_holiday_list(year)
+    }
+
+    fn early_closes(&self, year: i32) -> Vec<Holiday> {
+        holidays::get_us_early_closes(year)
+    }
+
+    fn special_days(&self, year: i32) -> Vec<SpecialDay> {
+        special_days_in(holidays::US_SPECIAL_DAYS, year)
+    }
+
     fn trading_hours(&self, date: NaiveDate) -> TradingHours {
         let mut hours = TradingHours::new(
             date,
@@ -75,10 +63,10 @@ impl MarketImpl for USMarket {
             )),
         );
 
-        if self.is_early_close_day(date) {
-            hours.early_close = Some(US_EARLY_CLOSE);
+        if let Some(early_close) = self.early_close(date) {
+            hours.early_close = Some(early_close);
             // Update after-hours to start at early close time
-            hours.after_hours = Some(Session::new_unchecked(US_EARLY_CLOSE, US_AFTERHOURS_CLOSE));
+            hours.after_hours = Some(Session::new_unchecked(early_close, US_AFTERHOURS_CLOSE));
         }
 
         hours
@@ -87,4 +75,8 @@ impl MarketImpl for USMarket {
     fn timezone(&self) -> Tz {
         chrono_tz::America::New_York
     }
+
+    fn holiday_cache(&self) -> Option<&HolidayCache> {
+        Some(&self.cache)
+    }
 }
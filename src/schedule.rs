@@ -1,7 +1,13 @@
 //! Trading hours and session definitions
 //!
 //! This module defines the structure for market trading sessions including
-//! regular hours, pre-market, after-hours, and early close times.
+//! regular hours, pre-market, after-hours, intraday breaks (such as the Tokyo
+//! lunch break) and early close times.
+//!
+//! A [`TradingHours`] belongs to a trading date, but its sessions need not
+//! fall on that calendar day: CME Globex opens on the evening before each
+//! trading date. [`TradingHours::windows`] places the sessions on the
+//! calendar.
 //!
 //! # Example
 //!
@@ -17,7 +23,7 @@
 //! ```
 
 use crate::{CalendarError, Result};
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use std::fmt;
 
 /// A trading session with start and end times
@@ -73,14 +79,141 @@ impl fmt::Display for Session {
     }
 }
 
+/// The kinds of session a trading day is made of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SessionKind {
+    /// Extended trading before the regular session
+    PreMarket,
+    /// The regular session, excluding intraday breaks
+    Regular,
+    /// Extended trading after the regular session
+    AfterHours,
+}
+
+impl SessionKind {
+    /// Every session kind, in the order they occur during a day
+    pub const ALL: [SessionKind; 3] = [
+        SessionKind::PreMarket,
+        SessionKind::Regular,
+        SessionKind::AfterHours,
+    ];
+}
+
+impl fmt::Display for SessionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SessionKind::PreMarket => "pre-market",
+            SessionKind::Regular => "regular",
+            SessionKind::AfterHours => "after-hours",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A session placed on the calendar
+///
+/// Unlike a [`Session`], whose times are times of day, a window carries the
+/// local dates it starts and ends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SessionWindow {
+    /// The kind of session
+    pub kind: SessionKind,
+    /// Local date and time the session starts
+    pub start: NaiveDateTime,
+    /// Local date and time the session ends
+    pub end: NaiveDateTime,
+}
+
+impl SessionWindow {
+    /// Check if a local date and time falls within this window
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        at >= self.start && at < self.end
+    }
+
+    /// Place a session at its first start at or after `from`
+    fn after(kind: SessionKind, session: &Session, from: NaiveDateTime) -> Self {
+        let mut start = from.date().and_time(session.start);
+        if start < from {
+            start += Duration::days(1);
+        }
+        let mut end = start.date().and_time(session.end);
+        if end <= start {
+            end += Duration::days(1);
+        }
+        SessionWindow { kind, start, end }
+    }
+
+    /// Place a session at its last end at or before `to`
+    fn before(kind: SessionKind, session: &Session, to: NaiveDateTime) -> Self {
+        let mut end = to.date().and_time(session.end);
+        if end > to {
+            end -= Duration::days(1);
+        }
+        let mut start = end.date().and_time(session.start);
+        if start >= end {
+            start -= Duration::days(1);
+        }
+        SessionWindow { kind, start, end }
+    }
+}
+
+impl fmt::Display for SessionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} - {}",
+            self.kind,
+            self.start.format("%Y-%m-%d %H:%M"),
+            self.end.format("%Y-%m-%d %H:%M")
+        )
+    }
+}
+
+/// Where an instant falls in a market's trading schedule
+///
+/// Returned by [`TradingCalendar::classify`](crate::TradingCalendar::classify).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SessionLocation {
+    /// The trading date the session belongs to
+    pub trading_date: NaiveDate,
+    /// The kind of session in progress
+    pub session_kind: SessionKind,
+    /// Time elapsed since that kind of session opened on the trading date
+    ///
+    /// For the regular session this is measured from the opening, not from
+    /// the end of an intraday break.
+    pub offset_from_open: Duration,
+}
+
 /// Trading hours for a specific date
+///
+/// The date is the trading date. Session times are times of day; a session
+/// may open on the calendar day before the trading date, as laid out by
+/// [`windows`](Self::windows).
+///
+/// The struct is non-exhaustive: outside this crate, build it with
+/// [`new`](Self::new) and the `with_*` methods.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serialization",
     derive(serde::Serialize, serde::Deserialize)
 )]
+#[non_exhaustive]
 pub struct TradingHours {
-    /// The date these hours apply to
+    /// The trading date these hours apply to
     pub date: NaiveDate,
     /// Pre-market session (if available)
     pub pre_market: Option<Session>,
@@ -90,6 +223,9 @@ pub struct TradingHours {
     pub after_hours: Option<Session>,
     /// Early close time (overrides regular session end)
     pub early_close: Option<NaiveTime>,
+    /// Intraday breaks within the regular session, ordered by start time
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub breaks: Vec<Session>,
 }
 
 impl TradingHours {
@@ -106,7 +242,32 @@ impl TradingHours {
             regular,
             after_hours,
             early_close: None,
+            breaks: Vec::new(),
+        }
+    }
+
+    /// Add an intraday break (e.g. a lunch break) with validation
+    ///
+    /// The break must lie strictly inside the regular session and after any
+    /// break added before it.
+    pub fn with_break(mut self, pause: Session) -> Result<Self> {
+        if pause.start <= self.regular.start
+            || pause.end >= self.regular.end
+            || pause.start >= pause.end
+        {
+            return Err(CalendarError::InvalidTime(
+                "Break must lie within the regular session".to_string(),
+            ));
         }
+        if let Some(last) = self.breaks.last() {
+            if pause.start < last.end {
+                return Err(CalendarError::InvalidTime(
+                    "Breaks must be ordered and must not overlap".to_string(),
+                ));
+            }
+        }
+        self.breaks.push(pause);
+        Ok(self)
     }
 
     /// Set early close time with validation
@@ -130,38 +291,206 @@ impl TradingHours {
         self.early_close.unwrap_or(self.regular.end)
     }
 
+    /// Check if the regular session has intraday breaks
+    pub fn has_breaks(&self) -> bool {
+        !self.breaks.is_empty()
+    }
+
+    /// Get the regular session split into its trading segments
+    ///
+    /// Segments are ordered and exclude intraday breaks; the last segment
+    /// ends at the market close (considering early close). A market without
+    /// breaks has a single segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{Session, TradingHours};
+    /// use chrono::{NaiveDate, NaiveTime};
+    ///
+    /// let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// let hours = TradingHours::new(
+    ///     NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+    ///     Session::new(time(9, 0), time(15, 30))?,
+    ///     None,
+    ///     None,
+    /// )
+    /// .with_break(Session::new(time(11, 30), time(12, 30))?)?;
+    ///
+    /// let segments = hours.segments();
+    /// assert_eq!(segments.len(), 2);
+    /// assert_eq!(segments[0].end, time(11, 30));
+    /// assert_eq!(segments[1].start, time(12, 30));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn segments(&self) -> Vec<Session> {
+        let close = self.market_close();
+        if self.regular.start > self.regular.end {
+            // Overnight regular sessions have no breaks
+            return vec![Session::new_unchecked(self.regular.start, close)];
+        }
+        let mut segments = Vec::with_capacity(self.breaks.len() + 1);
+        let mut start = self.regular.start;
+
+        for pause in &self.breaks {
+            if pause.start >= close {
+                break;
+            }
+            segments.push(Session::new_unchecked(start, pause.start));
+            start = pause.end;
+        }
+        if start < close {
+            segments.push(Session::new_unchecked(start, close));
+        }
+
+        segments
+    }
+
+    /// Check if the regular session is in progress at a specific time
+    ///
+    /// Unlike [`is_open_at`](Self::is_open_at), pre-market and after-hours
+    /// sessions are not considered.
+    pub fn is_regular_session_at(&self, time: NaiveTime) -> bool {
+        self.segments().iter().any(|segment| segment.contains(time))
+    }
+
     /// Check if the market is open at a specific time
+    ///
+    /// Pre-market and after-hours sessions count as open; use
+    /// [`session_at`](Self::session_at) to tell them apart.
     pub fn is_open_at(&self, time: NaiveTime) -> bool {
-        // Check pre-market session
+        self.session_at(time).is_some()
+    }
+
+    /// Get the session in progress at a specific time
+    ///
+    /// On early close days, after-hours trading is only available from the
+    /// early close. Times are compared as times of day; use
+    /// [`TradingCalendar::session_at`](crate::TradingCalendar::session_at)
+    /// to look up an instant, which accounts for sessions that open on the
+    /// previous calendar day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{Session, SessionKind, TradingHours};
+    /// use chrono::{NaiveDate, NaiveTime};
+    ///
+    /// let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// let hours = TradingHours::new(
+    ///     NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+    ///     Session::new(time(9, 30), time(16, 0))?,
+    ///     Some(Session::new(time(4, 0), time(9, 30))?),
+    ///     Some(Session::new(time(16, 0), time(20, 0))?),
+    /// );
+    ///
+    /// assert_eq!(hours.session_at(time(5, 0)), Some(SessionKind::PreMarket));
+    /// assert_eq!(hours.session_at(time(10, 0)), Some(SessionKind::Regular));
+    /// assert_eq!(hours.session_at(time(21, 0)), None);
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn session_at(&self, time: NaiveTime) -> Option<SessionKind> {
+        // Regular session (with early close and break consideration)
+        if self.is_regular_session_at(time) {
+            return Some(SessionKind::Regular);
+        }
+
         if let Some(ref pre) = self.pre_market {
             if pre.contains(time) {
-                return true;
+                return Some(SessionKind::PreMarket);
             }
         }
 
-        // Check regular session (with early close consideration)
-        let regular_end = self.early_close.unwrap_or(self.regular.end);
-        if time >= self.regular.start && time < regular_end {
-            return true;
-        }
-
-        // Check after-hours session
         // After-hours is available on early close days, but only after the early close time
         if let Some(ref after) = self.after_hours {
-            if after.contains(time) {
-                // If it's an early close day, only allow after-hours after early close
-                if let Some(early_close) = self.early_close {
-                    if time >= early_close {
-                        return true;
-                    }
-                } else {
-                    // No early close, after-hours is available
-                    return true;
-                }
+            if after.contains(time) && self.early_close.map_or(true, |close| time >= close) {
+                return Some(SessionKind::AfterHours);
             }
         }
 
-        false
+        None
+    }
+
+    /// Get the periods of a day in which a kind of session is in progress
+    ///
+    /// The regular session is split into its [`segments`](Self::segments);
+    /// after-hours trading starts no earlier than an early close.
+    pub fn sessions(&self, kind: SessionKind) -> Vec<Session> {
+        match kind {
+            SessionKind::PreMarket => self.pre_market.iter().cloned().collect(),
+            SessionKind::Regular => self.segments(),
+            SessionKind::AfterHours => self
+                .after_hours
+                .iter()
+                .filter_map(|after| match self.early_close {
+                    Some(close) if after.start < after.end && after.start < close => {
+                        (close < after.end).then(|| Session::new_unchecked(close, after.end))
+                    }
+                    _ => Some(after.clone()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Get the sessions of the trading date placed on the calendar, ordered
+    /// by start
+    ///
+    /// The regular session opens on the trading date, or on the previous
+    /// calendar day if it ends before it starts. Pre-market trading ends at
+    /// or before the open and after-hours trading starts at or after the
+    /// close, so a pre-market session from 17:00 to 08:30 opens on the
+    /// evening before the trading date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::{Session, SessionKind, TradingHours};
+    /// use chrono::{NaiveDate, NaiveTime};
+    ///
+    /// let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    /// let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    /// let hours = TradingHours::new(
+    ///     monday,
+    ///     Session::new(time(8, 30), time(15, 15))?,
+    ///     Some(Session::new(time(17, 0), time(8, 30))?),
+    ///     None,
+    /// );
+    ///
+    /// let windows = hours.windows();
+    /// assert_eq!(windows[0].kind, SessionKind::PreMarket);
+    /// assert_eq!(windows[0].start, monday.pred_opt().unwrap().and_time(time(17, 0)));
+    /// assert_eq!(windows[1].start, monday.and_time(time(8, 30)));
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn windows(&self) -> Vec<SessionWindow> {
+        let open_date = if self.regular.start > self.regular.end {
+            self.date.pred_opt().unwrap_or(self.date)
+        } else {
+            self.date
+        };
+        let open = open_date.and_time(self.regular.start);
+
+        let mut regular = Vec::with_capacity(self.breaks.len() + 1);
+        let mut from = open;
+        for segment in self.segments() {
+            let window = SessionWindow::after(SessionKind::Regular, &segment, from);
+            from = window.end;
+            regular.push(window);
+        }
+        let close = regular.last().map_or(open, |window| window.end);
+
+        let mut windows: Vec<SessionWindow> = self
+            .sessions(SessionKind::PreMarket)
+            .iter()
+            .map(|session| SessionWindow::before(SessionKind::PreMarket, session, open))
+            .collect();
+        windows.extend(regular);
+        windows.extend(
+            self.sessions(SessionKind::AfterHours)
+                .iter()
+                .map(|session| SessionWindow::after(SessionKind::AfterHours, session, close)),
+        );
+        windows
     }
 }
 
@@ -169,6 +498,10 @@ impl fmt::Display for TradingHours {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}: Regular {}", self.date, self.regular)?;
 
+        for pause in &self.breaks {
+            write!(f, ", Break {pause}")?;
+        }
+
         if let Some(ref pre) = self.pre_market {
             write!(f, ", Pre-Market {pre}")?;
         }
@@ -216,6 +549,7 @@ mod tests {
                 NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
             )),
             early_close: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+            breaks: Vec::new(),
         };
 
         assert!(hours.is_early_close());
@@ -275,4 +609,126 @@ mod tests {
         );
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_lunch_break() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let hours = TradingHours::new(
+            NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+            Session::new_unchecked(time(9, 0), time(15, 30)),
+            None,
+            None,
+        )
+        .with_break(Session::new_unchecked(time(11, 30), time(12, 30)))
+        .unwrap();
+
+        assert!(hours.has_breaks());
+        assert!(hours.is_open_at(time(11, 29)));
+        assert!(!hours.is_open_at(time(11, 30)));
+        assert!(!hours.is_open_at(time(12, 0)));
+        assert!(hours.is_open_at(time(12, 30)));
+        assert_eq!(hours.market_close(), time(15, 30));
+        assert_eq!(
+            hours.segments(),
+            vec![
+                Session::new_unchecked(time(9, 0), time(11, 30)),
+                Session::new_unchecked(time(12, 30), time(15, 30)),
+            ]
+        );
+
+        // An early close during the break drops the afternoon segment
+        let mut half_day = hours.clone();
+        half_day.early_close = Some(time(11, 30));
+        assert_eq!(
+            half_day.segments(),
+            vec![Session::new_unchecked(time(9, 0), time(11, 30))]
+        );
+    }
+
+    #[test]
+    fn test_break_validation() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let hours = TradingHours::new(
+            NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+            Session::new_unchecked(time(9, 0), time(15, 0)),
+            None,
+            None,
+        );
+
+        // Outside the regular session
+        assert!(hours
+            .clone()
+            .with_break(Session::new_unchecked(time(8, 0), time(9, 30)))
+            .is_err());
+
+        // Overlapping breaks
+        let with_lunch = hours
+            .with_break(Session::new_unchecked(time(11, 30), time(12, 30)))
+            .unwrap();
+        assert!(with_lunch
+            .with_break(Session::new_unchecked(time(12, 0), time(13, 0)))
+            .is_err());
+    }
+
+    #[test]
+    fn test_windows_across_midnight() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let previous = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let next = NaiveDate::from_ymd_opt(2025, 3, 11).unwrap();
+
+        // After-hours trading running to midnight ends on the next day
+        let hours = TradingHours::new(
+            date,
+            Session::new_unchecked(time(9, 30), time(16, 0)),
+            Some(Session::new_unchecked(time(4, 0), time(9, 30))),
+            Some(Session::new_unchecked(time(16, 0), time(0, 0))),
+        );
+        let windows = hours.windows();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].start, date.and_time(time(4, 0)));
+        assert_eq!(windows[2].end, next.and_time(time(0, 0)));
+
+        // An overnight regular session opens on the previous day
+        let overnight = TradingHours::new(
+            date,
+            Session::new_unchecked(time(20, 0), time(4, 0)),
+            None,
+            None,
+        );
+        assert!(overnight.is_regular_session_at(time(23, 0)));
+        let windows = overnight.windows();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].start, previous.and_time(time(20, 0)));
+        assert_eq!(windows[0].end, date.and_time(time(4, 0)));
+        assert!(windows[0].contains(date.and_time(time(3, 59))));
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_trading_hours_without_breaks_deserialize() {
+        // Written before intraday breaks were added
+        let json = r#"{
+            "date": "2025-06-02",
+            "pre_market": null,
+            "regular": {"start": "09:30:00", "end": "16:00:00"},
+            "after_hours": null,
+            "early_close": null
+        }"#;
+        let hours: TradingHours = serde_json::from_str(json).unwrap();
+        assert!(!hours.has_breaks());
+        assert_eq!(
+            hours.regular.end,
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap()
+        );
+
+        let lunch = Session::new_unchecked(
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+        );
+        let hours = hours.with_break(lunch).unwrap();
+        let round_trip: TradingHours =
+            serde_json::from_str(&serde_json::to_string(&hours).unwrap()).unwrap();
+        assert_eq!(round_trip, hours);
+    }
 }
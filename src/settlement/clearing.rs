@@ -0,0 +1,151 @@
+//! Clearing and currency holiday calendars
+
+use crate::markets::is_weekend;
+use crate::utils::{calculate_easter_monday, calculate_good_friday};
+use crate::utils::{last_weekday_of_month, nth_weekday_of_month};
+use crate::Holiday;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Holiday calendars of payment and clearing systems
+///
+/// Settlement needs the cash leg to move as well as the securities, so it
+/// skips days these systems are closed even when the exchange trades.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum ClearingCalendar {
+    /// US Federal Reserve (Fedwire) holidays, which DTC follows for USD
+    /// settlement. Adds Columbus Day and Veterans Day to the NYSE calendar
+    /// and drops Good Friday.
+    Fedwire,
+    /// TARGET2 closing days for EUR settlement, as defined since 2002
+    Target2,
+}
+
+impl ClearingCalendar {
+    /// Get the name of the clearing calendar
+    pub fn name(&self) -> &'static str {
+        match self {
+            ClearingCalendar::Fedwire => "Federal Reserve (Fedwire)",
+            ClearingCalendar::Target2 => "TARGET2",
+        }
+    }
+
+    /// Check if the system is closed on a date (weekends included)
+    pub fn is_closed(&self, date: NaiveDate) -> bool {
+        is_weekend(date) || self.holidays(date.year()).iter().any(|h| h.date == date)
+    }
+
+    /// Get the weekday holidays of a year
+    pub fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays = match self {
+            ClearingCalendar::Fedwire => fedwire_holidays(year),
+            ClearingCalendar::Target2 => target2_holidays(year),
+        };
+        holidays.retain(|holiday| !is_weekend(holiday.date));
+        holidays.sort_by_key(|holiday| holiday.date);
+        holidays
+    }
+}
+
+/// Federal Reserve holidays
+///
+/// A holiday on a Sunday is observed on the Monday; one on a Saturday is
+/// not observed, and Fedwire is open the Friday before.
+fn fedwire_holidays(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| {
+        NaiveDate::from_ymd_opt(year, month, day).map(|date| match date.weekday() {
+            Weekday::Sun => date + Duration::days(1),
+            _ => date,
+        })
+    };
+
+    let mut rules = vec![
+        ("New Year's Day", fixed(1, 1)),
+        (
+            "Birthday of Martin Luther King, Jr.",
+            nth_weekday_of_month(year, 1, Weekday::Mon, 3),
+        ),
+        (
+            "Washington's Birthday",
+            nth_weekday_of_month(year, 2, Weekday::Mon, 3),
+        ),
+        ("Memorial Day", last_weekday_of_month(year, 5, Weekday::Mon)),
+        ("Independence Day", fixed(7, 4)),
+        ("Labor Day", nth_weekday_of_month(year, 9, Weekday::Mon, 1)),
+        (
+            "Columbus Day",
+            nth_weekday_of_month(year, 10, Weekday::Mon, 2),
+        ),
+        ("Veterans Day", fixed(11, 11)),
+        (
+            "Thanksgiving Day",
+            nth_weekday_of_month(year, 11, Weekday::Thu, 4),
+        ),
+        ("Christmas Day", fixed(12, 25)),
+    ];
+    // First observed by the Federal Reserve Banks in 2022
+    if year >= 2022 {
+        rules.push(("Juneteenth National Independence Day", fixed(6, 19)));
+    }
+
+    rules
+        .into_iter()
+        .filter_map(|(name, date)| date.map(|date| Holiday::new(date, name, true)))
+        .collect()
+}
+
+/// TARGET2 closing days
+fn target2_holidays(year: i32) -> Vec<Holiday> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    [
+        ("New Year's Day", fixed(1, 1)),
+        ("Good Friday", calculate_good_friday(year).ok()),
+        ("Easter Monday", calculate_easter_monday(year).ok()),
+        ("Labour Day", fixed(5, 1)),
+        ("Christmas Day", fixed(12, 25)),
+        ("Boxing Day", fixed(12, 26)),
+    ]
+    .into_iter()
+    .filter_map(|(name, date)| date.map(|date| Holiday::new(date, name, true)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    #[test]
+    fn test_fedwire_holidays() {
+        let fed = ClearingCalendar::Fedwire;
+        assert_eq!(fed.holidays(2025).len(), 11);
+        assert!(fed.is_closed(date(2025, 10, 13))); // Columbus Day
+        assert!(fed.is_closed(date(2025, 11, 11))); // Veterans Day
+        assert!(!fed.is_closed(date(2025, 4, 18))); // Good Friday
+
+        // Sunday holidays move to Monday; Saturday holidays are not observed
+        assert!(fed.is_closed(date(2023, 1, 2)));
+        assert!(!fed.is_closed(date(2021, 12, 24)));
+        assert_eq!(fed.holidays(2021).len(), 9);
+    }
+
+    #[test]
+    fn test_target2_holidays() {
+        let target = ClearingCalendar::Target2;
+        let dates: Vec<_> = target.holidays(2025).iter().map(|h| h.date).collect();
+        assert_eq!(
+            dates,
+            [
+                date(2025, 1, 1),
+                date(2025, 4, 18),
+                date(2025, 4, 21),
+                date(2025, 5, 1),
+                date(2025, 12, 25),
+                date(2025, 12, 26),
+            ]
+        );
+    }
+}
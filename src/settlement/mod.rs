@@ -0,0 +1,374 @@
+//! Settlement date calculation
+//!
+//! Trades settle a number of settlement days after the trade date. A
+//! settlement day must be a trading day on the exchange *and* a business
+//! day for the clearing systems that move the cash, so US equities traded
+//! the Friday before Columbus Day settle on the Tuesday even though NYSE is
+//! open on the Monday.
+//!
+//! Each market has a default [`SettlementCycle`] that changes on the dates
+//! regulators shortened it, and default [`ClearingCalendar`]s.
+//!
+//! # Example
+//!
+//! ```
+//! use trading_calendar::settlement::SettlementCycle;
+//! use trading_calendar::{Market, TradingCalendar};
+//! use chrono::NaiveDate;
+//!
+//! let nyse = TradingCalendar::new(Market::NYSE)?;
+//! let friday = NaiveDate::from_ymd_opt(2025, 10, 10).unwrap();
+//!
+//! // US equities settle T+1 since May 28th, 2024, skipping Columbus Day
+//! assert_eq!(Market::NYSE.settlement_cycle(friday), SettlementCycle::T1);
+//! let settles = nyse.settlement_date(friday, SettlementCycle::T1)?;
+//! assert_eq!(settles, NaiveDate::from_ymd_opt(2025, 10, 14).unwrap());
+//! # Ok::<(), trading_calendar::CalendarError>(())
+//! ```
+
+mod clearing;
+
+pub use clearing::ClearingCalendar;
+
+use crate::constants::date;
+use crate::{CalendarError, Market, Result, TradingCalendar};
+use chrono::NaiveDate;
+use std::fmt;
+
+/// Number of settlement days between trade date and settlement date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SettlementCycle {
+    days: u32,
+}
+
+impl SettlementCycle {
+    /// Same-day settlement
+    pub const T0: SettlementCycle = SettlementCycle::new(0);
+    /// Next-day settlement
+    pub const T1: SettlementCycle = SettlementCycle::new(1);
+    /// Settlement two days after the trade
+    pub const T2: SettlementCycle = SettlementCycle::new(2);
+    /// Settlement three days after the trade
+    pub const T3: SettlementCycle = SettlementCycle::new(3);
+
+    /// Create a cycle of `days` settlement days
+    pub const fn new(days: u32) -> Self {
+        SettlementCycle { days }
+    }
+
+    /// Get the number of settlement days
+    pub const fn days(&self) -> u32 {
+        self.days
+    }
+}
+
+impl fmt::Display for SettlementCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "T+{}", self.days)
+    }
+}
+
+/// US equity settlement (SEC Rule 15c6-1)
+const US_CYCLES: &[(NaiveDate, SettlementCycle)] = &[
+    (date(1995, 6, 7), SettlementCycle::T3),
+    (date(2017, 9, 5), SettlementCycle::T2),
+    (date(2024, 5, 28), SettlementCycle::T1),
+];
+
+/// Canadian equity settlement (CDS)
+const CA_CYCLES: &[(NaiveDate, SettlementCycle)] = &[
+    (date(1995, 6, 7), SettlementCycle::T3),
+    (date(2017, 9, 5), SettlementCycle::T2),
+    (date(2024, 5, 27), SettlementCycle::T1),
+];
+
+/// UK equity settlement (CREST); T+1 is scheduled by the UK Accelerated
+/// Settlement Taskforce
+const UK_CYCLES: &[(NaiveDate, SettlementCycle)] = &[
+    (date(2001, 2, 5), SettlementCycle::T3),
+    (date(2014, 10, 6), SettlementCycle::T2),
+    (date(2027, 10, 11), SettlementCycle::T1),
+];
+
+/// European equity settlement, harmonised on T+2 by the EU Central
+/// Securities Depositories Regulation; SIX moved on the same date
+const EU_CYCLES: &[(NaiveDate, SettlementCycle)] = &[(date(2014, 10, 6), SettlementCycle::T2)];
+
+/// Japanese equity settlement (JASDEC)
+const JP_CYCLES: &[(NaiveDate, SettlementCycle)] = &[(date(2019, 7, 16), SettlementCycle::T2)];
+
+/// Australian equity settlement (CHESS)
+const AU_CYCLES: &[(NaiveDate, SettlementCycle)] = &[(date(2016, 3, 7), SettlementCycle::T2)];
+
+/// Indian equity settlement, phased in by market capitalisation and
+/// complete for all stocks on January 27th, 2023
+const IN_CYCLES: &[(NaiveDate, SettlementCycle)] = &[
+    (date(2003, 4, 1), SettlementCycle::T2),
+    (date(2023, 1, 27), SettlementCycle::T1),
+];
+
+/// Singapore equity settlement (CDP)
+const SG_CYCLES: &[(NaiveDate, SettlementCycle)] = &[(date(2018, 12, 10), SettlementCycle::T2)];
+
+/// South African equity settlement (Strate)
+const ZA_CYCLES: &[(NaiveDate, SettlementCycle)] = &[(date(2016, 7, 11), SettlementCycle::T3)];
+
+/// Brazilian equity settlement (B3 clearinghouse)
+const BR_CYCLES: &[(NaiveDate, SettlementCycle)] = &[(date(2019, 5, 20), SettlementCycle::T2)];
+
+impl Market {
+    /// Get the default settlement cycle for equities traded on a date
+    ///
+    /// Cycles change on the trade date a shorter cycle took effect.
+    /// Before the first change tracked for a market, the previous cycle
+    /// (T+5 in North America before June 1995, T+5 in the UK before
+    /// February 2001, T+3 in Japan, continental Europe, Australia, India,
+    /// Singapore and Brazil) is returned. Hong Kong and Korea settle T+2 and
+    /// mainland China T+1 throughout.
+    pub fn settlement_cycle(&self, trade_date: NaiveDate) -> SettlementCycle {
+        let (table, before): (&[(NaiveDate, SettlementCycle)], _) = match self {
+            Market::NYSE | Market::NASDAQ | Market::CBOE | Market::CBOEIndex => {
+                (US_CYCLES, SettlementCycle::new(5))
+            }
+            Market::TSX => (CA_CYCLES, SettlementCycle::new(5)),
+            Market::LSE => (UK_CYCLES, SettlementCycle::new(5)),
+            Market::TSE => (JP_CYCLES, SettlementCycle::T3),
+            Market::EURONEXT
+            | Market::XETRA
+            | Market::SIX
+            | Market::BIT
+            | Market::BME
+            | Market::OMXS
+            | Market::OMXH
+            | Market::OMXC => (EU_CYCLES, SettlementCycle::T3),
+            Market::HKEX | Market::KRX => (&[], SettlementCycle::T2),
+            Market::SSE | Market::SZSE => (&[], SettlementCycle::T1),
+            Market::ASX => (AU_CYCLES, SettlementCycle::T3),
+            Market::NSE => (IN_CYCLES, SettlementCycle::T3),
+            Market::SGX => (SG_CYCLES, SettlementCycle::T3),
+            Market::TADAWUL | Market::DFM | Market::ADX | Market::EGX => (&[], SettlementCycle::T2),
+            Market::JSE => (ZA_CYCLES, SettlementCycle::new(5)),
+            Market::B3 => (BR_CYCLES, SettlementCycle::T3),
+            Market::BMV => (&[], SettlementCycle::T2),
+            // Futures variation margin settles on the next banking day
+            Market::CME => (&[], SettlementCycle::T1),
+            Market::Custom => (&[], SettlementCycle::T2),
+        };
+        table
+            .iter()
+            .rev()
+            .find(|(effective, _)| *effective <= trade_date)
+            .map_or(before, |(_, cycle)| *cycle)
+    }
+
+    /// Get the clearing calendars settlement on this market waits for
+    pub fn clearing_calendars(&self) -> &'static [ClearingCalendar] {
+        match self {
+            Market::NYSE | Market::NASDAQ | Market::CME | Market::CBOE | Market::CBOEIndex => {
+                &[ClearingCalendar::Fedwire]
+            }
+            Market::EURONEXT | Market::XETRA | Market::BIT | Market::BME | Market::OMXH => {
+                &[ClearingCalendar::Target2]
+            }
+            _ => &[],
+        }
+    }
+}
+
+impl TradingCalendar {
+    /// Check if a date is a settlement day against the market's default
+    /// clearing calendars
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    pub fn is_settlement_day(&self, date: NaiveDate) -> Result<bool> {
+        self.is_settlement_day_with(date, self.market().clearing_calendars())
+    }
+
+    /// Check if a date is a settlement day against explicit clearing calendars
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalendarError::DateOutOfRange` if the date is outside
+    /// [`supported_years`](Self::supported_years).
+    pub fn is_settlement_day_with(
+        &self,
+        date: NaiveDate,
+        clearing: &[ClearingCalendar],
+    ) -> Result<bool> {
+        Ok(self.is_trading_day(date)? && !clearing.iter().any(|c| c.is_closed(date)))
+    }
+
+    /// Get the settlement date of a trade against the market's default
+    /// clearing calendars
+    ///
+    /// Use [`Market::settlement_cycle`] for the market's regulatory cycle on
+    /// the trade date.
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::InvalidDateCalculation` if the trade date is not a
+    ///   trading day
+    /// - `CalendarError::DateOutOfRange` if the trade or settlement date is
+    ///   outside [`supported_years`](Self::supported_years)
+    pub fn settlement_date(
+        &self,
+        trade_date: NaiveDate,
+        cycle: SettlementCycle,
+    ) -> Result<NaiveDate> {
+        self.settlement_date_with(trade_date, cycle, self.market().clearing_calendars())
+    }
+
+    /// Get the settlement date of a trade against explicit clearing calendars
+    ///
+    /// The settlement date is the `cycle`th settlement day after the trade
+    /// date; for T+0 it is the trade date itself if that is a settlement
+    /// day, and the next settlement day otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - `CalendarError::InvalidDateCalculation` if the trade date is not a
+    ///   trading day
+    /// - `CalendarError::DateOutOfRange` if the trade or settlement date is
+    ///   outside [`supported_years`](Self::supported_years)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::settlement::{ClearingCalendar, SettlementCycle};
+    /// use trading_calendar::{Market, TradingCalendar};
+    /// use chrono::NaiveDate;
+    ///
+    /// let lse = TradingCalendar::new(Market::LSE)?;
+    /// // EUR-denominated trade on Wednesday, April 30th 2025: May 1st is
+    /// // a TARGET2 holiday but a London trading day, and May 5th is the
+    /// // Early May Bank Holiday
+    /// let trade = NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+    /// let settles = lse.settlement_date_with(trade, SettlementCycle::T2, &[ClearingCalendar::Target2])?;
+    /// assert_eq!(settles, NaiveDate::from_ymd_opt(2025, 5, 6).unwrap());
+    /// # Ok::<(), trading_calendar::CalendarError>(())
+    /// ```
+    pub fn settlement_date_with(
+        &self,
+        trade_date: NaiveDate,
+        cycle: SettlementCycle,
+        clearing: &[ClearingCalendar],
+    ) -> Result<NaiveDate> {
+        if !self.is_trading_day(trade_date)? {
+            return Err(CalendarError::InvalidDateCalculation(format!(
+                "Trade date {trade_date} is not a trading day"
+            )));
+        }
+
+        let mut date = trade_date;
+        let mut remaining = cycle.days();
+        if remaining == 0 && self.is_settlement_day_with(date, clearing)? {
+            return Ok(date);
+        }
+        remaining = remaining.max(1);
+        while remaining > 0 {
+            date = self.next_trading_day(date);
+            if self.is_settlement_day_with(date, clearing)? {
+                remaining -= 1;
+            }
+        }
+        Ok(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_cycles() {
+        assert_eq!(Market::NYSE.settlement_cycle(date(1994, 1, 3)).days(), 5);
+        assert_eq!(
+            Market::NYSE.settlement_cycle(date(2017, 9, 1)),
+            SettlementCycle::T3
+        );
+        assert_eq!(
+            Market::NYSE.settlement_cycle(date(2017, 9, 5)),
+            SettlementCycle::T2
+        );
+        assert_eq!(
+            Market::NASDAQ.settlement_cycle(date(2024, 5, 24)),
+            SettlementCycle::T2
+        );
+        assert_eq!(
+            Market::NASDAQ.settlement_cycle(date(2024, 5, 28)),
+            SettlementCycle::T1
+        );
+        assert_eq!(
+            Market::TSX.settlement_cycle(date(2024, 5, 27)),
+            SettlementCycle::T1
+        );
+        assert_eq!(
+            Market::LSE.settlement_cycle(date(2025, 6, 2)),
+            SettlementCycle::T2
+        );
+        assert_eq!(
+            Market::TSE.settlement_cycle(date(2019, 7, 12)),
+            SettlementCycle::T3
+        );
+        assert_eq!(
+            Market::XETRA.settlement_cycle(date(2014, 10, 6)),
+            SettlementCycle::T2
+        );
+        assert_eq!(
+            Market::NSE.settlement_cycle(date(2025, 3, 3)),
+            SettlementCycle::T1
+        );
+        assert_eq!(SettlementCycle::T2.to_string(), "T+2");
+    }
+
+    #[test]
+    fn test_us_settlement_skips_bank_holidays() {
+        let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+
+        // Veterans Day: NYSE open, Fedwire closed
+        assert!(nyse.is_trading_day(date(2025, 11, 11)).unwrap());
+        assert!(!nyse.is_settlement_day(date(2025, 11, 11)).unwrap());
+        assert_eq!(
+            nyse.settlement_date(date(2025, 11, 10), SettlementCycle::T1)
+                .unwrap(),
+            date(2025, 11, 12)
+        );
+        // Trades on the bank holiday settle the next day as usual
+        assert_eq!(
+            nyse.settlement_date(date(2025, 11, 11), SettlementCycle::T1)
+                .unwrap(),
+            date(2025, 11, 12)
+        );
+        assert_eq!(
+            nyse.settlement_date(date(2025, 11, 11), SettlementCycle::T0)
+                .unwrap(),
+            date(2025, 11, 12)
+        );
+        // Without clearing calendars only the exchange counts
+        assert_eq!(
+            nyse.settlement_date_with(date(2025, 11, 10), SettlementCycle::T1, &[])
+                .unwrap(),
+            date(2025, 11, 11)
+        );
+    }
+
+    #[test]
+    fn test_settlement_errors() {
+        let nyse = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+        assert!(matches!(
+            nyse.settlement_date(date(2025, 12, 25), SettlementCycle::T1),
+            Err(CalendarError::InvalidDateCalculation(_))
+        ));
+        assert!(matches!(
+            nyse.settlement_date(date(2030, 12, 31), SettlementCycle::T2),
+            Err(CalendarError::DateOutOfRange(_))
+        ));
+    }
+}
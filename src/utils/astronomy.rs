@@ -0,0 +1,275 @@
+//! Astronomical calculations for rule-based holidays
+//!
+//! Solar positions follow the low-accuracy algorithm from Jean Meeus,
+//! *Astronomical Algorithms* (2nd ed., ch. 25), which is accurate to about
+//! 0.01° of solar longitude. That places equinoxes and solstices within a
+//! quarter of an hour, ample for determining the civil date on which they
+//! fall. New moons follow ch. 49 and are accurate to well under a minute.
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+/// Julian Day of the J2000.0 epoch (2000-01-01 12:00 TT)
+const J2000: f64 = 2_451_545.0;
+
+/// Mean length of the tropical year in days
+const TROPICAL_YEAR: f64 = 365.242_19;
+
+/// Seconds in a day
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Mean length of the synodic month in days
+pub const SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// Julian Day (TT) of the mean new moon of 2000-01-06, lunation 0
+const NEW_MOON_EPOCH: f64 = 2_451_550.097_66;
+
+/// Convert a UTC instant into a Julian Day number
+pub fn julian_day(instant: DateTime<Utc>) -> f64 {
+    // 1970-01-01 00:00 UTC is JD 2440587.5
+    instant.timestamp() as f64 / SECONDS_PER_DAY + 2_440_587.5
+}
+
+/// Convert a Julian Day number into a UTC instant (rounded to the second)
+pub fn from_julian_day(jd: f64) -> DateTime<Utc> {
+    let seconds = ((jd - 2_440_587.5) * SECONDS_PER_DAY).round() as i64;
+    Utc.timestamp_opt(seconds, 0)
+        .single()
+        .expect("Julian day within chrono's range")
+}
+
+/// Approximate difference between Terrestrial Time and UT in seconds
+///
+/// Uses the polynomial fits by Espenak and Meeus, which are accurate to
+/// within a few seconds over 1900-2150.
+pub fn delta_t(year: i32) -> f64 {
+    let y = year as f64;
+    if year < 1941 {
+        let t = y - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t * t + 0.0020936 * t * t * t
+    } else if year < 1961 {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t * t / 233.0 + t * t * t / 2547.0
+    } else if year < 1986 {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t * t / 260.0 - t * t * t / 718.0
+    } else if year < 2005 {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t * t
+            + 0.0017275 * t * t * t
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if year < 2050 {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t * t
+    } else {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+    }
+}
+
+/// Apparent geocentric longitude of the Sun in degrees, for a Julian Day (TT)
+pub fn solar_longitude(jde: f64) -> f64 {
+    let t = (jde - J2000) / 36_525.0;
+    let l0 = 280.46646 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let m = (357.52911 + 35_999.050_29 * t - 0.000_153_7 * t * t).to_radians();
+    let c = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * m.sin()
+        + (0.019_993 - 0.000_101 * t) * (2.0 * m).sin()
+        + 0.000_289 * (3.0 * m).sin();
+    let omega = (125.04 - 1_934.136 * t).to_radians();
+    let apparent = l0 + c - 0.005_69 - 0.004_78 * omega.sin();
+    apparent.rem_euclid(360.0)
+}
+
+/// Find the instant when the Sun reaches a given apparent longitude
+///
+/// Longitudes are measured from the vernal equinox: 0° is the March
+/// equinox, 90° the June solstice, 180° the September equinox and 270° the
+/// December solstice. Other multiples of 15° are the traditional East Asian
+/// solar terms. The result is the first such instant on or after the March
+/// equinox of `year`, so longitudes past about 280° fall in January or
+/// February of the following year.
+pub fn solar_term(year: i32, longitude: f64) -> DateTime<Utc> {
+    // Start from the mean position, counted from the March equinox
+    let march_20 = NaiveDate::from_ymd_opt(year, 3, 20)
+        .expect("Valid date")
+        .and_hms_opt(12, 0, 0)
+        .expect("Valid time")
+        .and_utc();
+    let delta_t = delta_t(year) / SECONDS_PER_DAY;
+    let mut jde = julian_day(march_20) + delta_t + longitude / 360.0 * TROPICAL_YEAR;
+
+    for _ in 0..50 {
+        let error = (longitude - solar_longitude(jde) + 540.0).rem_euclid(360.0) - 180.0;
+        jde += error * TROPICAL_YEAR / 360.0;
+        if error.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    from_julian_day(jde - delta_t)
+}
+
+/// Instant of the March (vernal) equinox
+pub fn march_equinox(year: i32) -> DateTime<Utc> {
+    solar_term(year, 0.0)
+}
+
+/// Instant of the September (autumnal) equinox
+pub fn september_equinox(year: i32) -> DateTime<Utc> {
+    solar_term(year, 180.0)
+}
+
+/// Instant of the new moon of lunation `k`
+///
+/// Lunation 0 is the new moon of 2000-01-06; negative values count
+/// backwards.
+pub fn new_moon(k: i64) -> DateTime<Utc> {
+    let k = k as f64;
+    let t = k / 1_236.85;
+    let mut jde = NEW_MOON_EPOCH + SYNODIC_MONTH * k + 0.000_154_37 * t * t
+        - 0.000_000_150 * t * t * t
+        + 0.000_000_000_73 * t.powi(4);
+
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+    let m =
+        (2.5534 + 29.105_356_70 * k - 0.000_001_4 * t * t - 0.000_000_11 * t * t * t).to_radians();
+    let mp = (201.5643 + 385.816_935_28 * k + 0.010_758_2 * t * t + 0.000_012_38 * t * t * t
+        - 0.000_000_058 * t.powi(4))
+    .to_radians();
+    let f = (160.7108 + 390.670_502_84 * k - 0.001_611_8 * t * t - 0.000_002_27 * t * t * t
+        + 0.000_000_011 * t.powi(4))
+    .to_radians();
+    let omega =
+        (124.7746 - 1.563_755_88 * k + 0.002_067_2 * t * t + 0.000_002_15 * t * t * t).to_radians();
+
+    // Periodic terms for the new moon
+    jde += -0.407_20 * mp.sin()
+        + 0.172_41 * e * m.sin()
+        + 0.016_08 * (2.0 * mp).sin()
+        + 0.010_39 * (2.0 * f).sin()
+        + 0.007_39 * e * (mp - m).sin()
+        - 0.005_14 * e * (mp + m).sin()
+        + 0.002_08 * e * e * (2.0 * m).sin()
+        - 0.001_11 * (mp - 2.0 * f).sin()
+        - 0.000_57 * (mp + 2.0 * f).sin()
+        + 0.000_56 * e * (2.0 * mp + m).sin()
+        - 0.000_42 * (3.0 * mp).sin()
+        + 0.000_42 * e * (m + 2.0 * f).sin()
+        + 0.000_38 * e * (m - 2.0 * f).sin()
+        - 0.000_24 * e * (2.0 * mp - m).sin()
+        - 0.000_17 * omega.sin()
+        - 0.000_07 * (mp + 2.0 * m).sin()
+        + 0.000_04 * (2.0 * mp - 2.0 * f).sin()
+        + 0.000_04 * (3.0 * m).sin()
+        + 0.000_03 * (mp + m - 2.0 * f).sin()
+        + 0.000_03 * (2.0 * mp + 2.0 * f).sin()
+        - 0.000_03 * (mp + m + 2.0 * f).sin()
+        + 0.000_03 * (mp - m + 2.0 * f).sin()
+        - 0.000_02 * (mp - m - 2.0 * f).sin()
+        - 0.000_02 * (3.0 * mp + m).sin()
+        + 0.000_02 * (4.0 * mp).sin();
+
+    // Planetary arguments
+    let planetary: [(f64, f64); 14] = [
+        (325.0, 299.77 + 0.107_408 * k - 0.009_173 * t * t),
+        (165.0, 251.88 + 0.016_321 * k),
+        (164.0, 251.83 + 26.651_886 * k),
+        (126.0, 349.42 + 36.412_478 * k),
+        (110.0, 84.66 + 18.206_239 * k),
+        (62.0, 141.74 + 53.303_771 * k),
+        (60.0, 207.14 + 2.453_732 * k),
+        (56.0, 154.84 + 7.306_860 * k),
+        (47.0, 34.52 + 27.261_239 * k),
+        (42.0, 207.19 + 0.121_824 * k),
+        (40.0, 291.34 + 1.844_379 * k),
+        (37.0, 161.72 + 24.198_154 * k),
+        (35.0, 239.56 + 25.513_099 * k),
+        (23.0, 331.55 + 3.592_518 * k),
+    ];
+    jde += planetary
+        .iter()
+        .map(|(coefficient, angle)| coefficient * 1e-6 * angle.to_radians().sin())
+        .sum::<f64>();
+
+    let year = 2000 + (k / 12.3685) as i32;
+    from_julian_day(jde - delta_t(year) / SECONDS_PER_DAY)
+}
+
+/// Lunation number of the last new moon at or before an instant
+pub fn lunation_at(instant: DateTime<Utc>) -> i64 {
+    let mut k = ((julian_day(instant) - NEW_MOON_EPOCH) / SYNODIC_MONTH).floor() as i64;
+    while new_moon(k + 1) <= instant {
+        k += 1;
+    }
+    while new_moon(k) > instant {
+        k -= 1;
+    }
+    k
+}
+
+/// Civil date of an instant at a fixed UTC offset in hours
+pub fn local_date(instant: DateTime<Utc>, offset_hours: i32) -> NaiveDate {
+    (instant + chrono::Duration::hours(offset_hours.into())).date_naive()
+}
+
+/// Civil date of a solar term at a fixed UTC offset in hours
+pub fn solar_term_date(year: i32, longitude: f64, offset_hours: i32) -> NaiveDate {
+    local_date(solar_term(year, longitude), offset_hours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn test_julian_day_round_trip() {
+        let instant = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(julian_day(instant), J2000);
+        assert_eq!(from_julian_day(J2000), instant);
+    }
+
+    #[test]
+    fn test_equinox_instants() {
+        // Published instants: 2024-03-20 03:06 UTC and 2024-09-22 12:44 UTC
+        let march = march_equinox(2024);
+        assert_eq!((march.month(), march.day()), (3, 20));
+        let minutes = march.hour() * 60 + march.minute();
+        assert!((176..=196).contains(&minutes), "{march}");
+
+        let september = september_equinox(2024);
+        assert_eq!((september.month(), september.day()), (9, 22));
+        let minutes = september.hour() * 60 + september.minute();
+        assert!((754..=774).contains(&minutes), "{september}");
+    }
+
+    #[test]
+    fn test_new_moon_instants() {
+        // Published instants: 2024-02-09 22:59 UTC and 2025-01-29 12:36 UTC
+        let after = Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap();
+        let instant = new_moon(lunation_at(after));
+        assert_eq!((instant.month(), instant.day()), (2, 9));
+        let minutes = instant.hour() * 60 + instant.minute();
+        assert!((1374..=1384).contains(&minutes), "{instant}");
+
+        let after = Utc.with_ymd_and_hms(2025, 1, 30, 0, 0, 0).unwrap();
+        let instant = new_moon(lunation_at(after));
+        assert_eq!((instant.month(), instant.day()), (1, 29));
+        let minutes = instant.hour() * 60 + instant.minute();
+        assert!((751..=761).contains(&minutes), "{instant}");
+    }
+
+    #[test]
+    fn test_solstice_dates() {
+        // December solstice 2025 is on 2025-12-21 (15:03 UTC)
+        assert_eq!(
+            solar_term_date(2025, 270.0, 0),
+            NaiveDate::from_ymd_opt(2025, 12, 21).unwrap()
+        );
+        // June solstice 2026 is on 2026-06-21 (08:24 UTC)
+        assert_eq!(
+            solar_term_date(2026, 90.0, 0),
+            NaiveDate::from_ymd_opt(2026, 6, 21).unwrap()
+        );
+    }
+}
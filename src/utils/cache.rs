@@ -1,14 +1,19 @@
 //! Caching utilities for holiday calculations
 
-use chrono::NaiveDate;
+use crate::Holiday;
+use chrono::{Datelike, NaiveDate, NaiveTime};
 use lru::LruCache;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::num::NonZeroUsize;
 use std::sync::Mutex;
 
 /// Thread-safe cache for holiday data with LRU eviction
+///
+/// Holiday dates and early close times are cached per year, each holding
+/// up to the cache's capacity of years.
 pub struct HolidayCache {
     data: Mutex<LruCache<i32, HashSet<NaiveDate>>>,
+    early_closes: Mutex<LruCache<i32, HashMap<NaiveDate, NaiveTime>>>,
 }
 
 impl HolidayCache {
@@ -19,8 +24,10 @@ impl HolidayCache {
 
     /// Create a new cache with specified maximum entries
     pub fn with_capacity(max_entries: usize) -> Self {
+        let capacity = NonZeroUsize::new(max_entries).unwrap();
         Self {
-            data: Mutex::new(LruCache::new(NonZeroUsize::new(max_entries).unwrap())),
+            data: Mutex::new(LruCache::new(capacity)),
+            early_closes: Mutex::new(LruCache::new(capacity)),
         }
     }
 
@@ -40,6 +47,30 @@ impl HolidayCache {
         holidays
     }
 
+    /// Get the early close time of a date, computing and caching the
+    /// year's early closes on first use
+    ///
+    /// `compute` returns the early close days of the date's year.
+    pub fn early_close_or_compute<F>(&self, date: NaiveDate, compute: F) -> Option<NaiveTime>
+    where
+        F: FnOnce() -> Vec<Holiday>,
+    {
+        let year = date.year();
+        let mut cache = self.early_closes.lock().unwrap();
+
+        if let Some(closes) = cache.get(&year) {
+            return closes.get(&date).copied();
+        }
+
+        let closes: HashMap<NaiveDate, NaiveTime> = compute()
+            .into_iter()
+            .filter_map(|day| day.early_close.map(|close| (day.date, close)))
+            .collect();
+        let close = closes.get(&date).copied();
+        cache.put(year, closes);
+        close
+    }
+
     /// Get cached holidays for a year if available
     pub fn get(&self, year: i32) -> Option<HashSet<NaiveDate>> {
         let mut cache = self.data.lock().unwrap();
@@ -73,8 +104,8 @@ impl HolidayCache {
 
     /// Clear all cached entries
     pub fn clear(&self) {
-        let mut cache = self.data.lock().unwrap();
-        cache.clear();
+        self.data.lock().unwrap().clear();
+        self.early_closes.lock().unwrap().clear();
     }
 }
 
@@ -129,6 +160,26 @@ mod tests {
         assert_eq!(cache.get(year), Some(holidays));
     }
 
+    #[test]
+    fn test_early_close_cache() {
+        let cache = HolidayCache::new();
+        let eve = NaiveDate::from_ymd_opt(2025, 12, 24).unwrap();
+        let close = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+        let compute = || vec![Holiday::with_early_close(eve, "Christmas Eve", close)];
+
+        assert_eq!(cache.early_close_or_compute(eve, compute), Some(close));
+        // Later lookups in the year are answered from the cache
+        let next_day = eve.succ_opt().unwrap();
+        assert_eq!(
+            cache.early_close_or_compute(next_day, || unreachable!()),
+            None
+        );
+        assert_eq!(
+            cache.early_close_or_compute(eve, || unreachable!()),
+            Some(close)
+        );
+    }
+
     #[test]
     fn test_cache_stats() {
         let cache = HolidayCache::with_capacity(5);
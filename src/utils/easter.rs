@@ -35,6 +35,44 @@ pub fn calculate_easter_monday(year: i32) -> Result<NaiveDate> {
     Ok(calculate_easter(year)? + chrono::Duration::days(1))
 }
 
+/// Calculate Maundy Thursday (3 days before Easter)
+pub fn calculate_maundy_thursday(year: i32) -> Result<NaiveDate> {
+    Ok(calculate_easter(year)? - chrono::Duration::days(3))
+}
+
+/// Calculate Ascension Day (39 days after Easter)
+pub fn calculate_ascension_day(year: i32) -> Result<NaiveDate> {
+    Ok(calculate_easter(year)? + chrono::Duration::days(39))
+}
+
+/// Calculate Whit Monday (50 days after Easter)
+pub fn calculate_whit_monday(year: i32) -> Result<NaiveDate> {
+    Ok(calculate_easter(year)? + chrono::Duration::days(50))
+}
+
+/// Calculate Orthodox Easter Sunday in the Gregorian calendar
+///
+/// Uses the Julian computus (Meeus) and converts the Julian date by the
+/// calendar difference for the century (13 days from 1900 to 2099).
+pub fn calculate_orthodox_easter(year: i32) -> Result<NaiveDate> {
+    let a = year % 4;
+    let b = year % 7;
+    let c = year % 19;
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = ((d + e + 114) % 31) + 1;
+    let julian_difference = year / 100 - year / 400 - 2;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+        .map(|date| date + chrono::Duration::days(julian_difference.into()))
+        .ok_or_else(|| {
+            CalendarError::InvalidDateCalculation(format!(
+                "Orthodox Easter calculation failed for year {year}"
+            ))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,4 +109,30 @@ mod tests {
             NaiveDate::from_ymd_opt(2026, 4, 3).unwrap()
         );
     }
+
+    #[test]
+    fn test_moveable_feasts() {
+        assert_eq!(
+            calculate_maundy_thursday(2025).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 4, 17).unwrap()
+        );
+        assert_eq!(
+            calculate_ascension_day(2025).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 5, 29).unwrap()
+        );
+        assert_eq!(
+            calculate_whit_monday(2025).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 6, 9).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_orthodox_easter() {
+        for (year, month, day) in [(2023, 4, 16), (2024, 5, 5), (2025, 4, 20), (2026, 4, 12)] {
+            assert_eq!(
+                calculate_orthodox_easter(year).unwrap(),
+                NaiveDate::from_ymd_opt(year, month, day).unwrap()
+            );
+        }
+    }
 }
@@ -0,0 +1,267 @@
+//! Islamic (Hijri) calendar
+//!
+//! The tabular calendar is the arithmetic 30-year cycle with leap years
+//! 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29, counted from the civil
+//! epoch of 16 July 622 (Julian). It tracks the observed calendar to within
+//! a day or two, so the months that fix market holidays (Ramadan, Shawwal
+//! and Dhu al-Hijjah) are overridden with the starts announced under Saudi
+//! Arabia's Umm al-Qura calendar where they are known.
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::constants::date;
+
+/// 1 Muharram 1 AH in the proleptic Gregorian calendar
+const EPOCH: NaiveDate = date(622, 7, 19);
+
+/// Days in a 30-year cycle of the tabular calendar
+const CYCLE_DAYS: i64 = 10_631;
+
+/// First day of Ramadan
+pub const RAMADAN: u32 = 9;
+
+/// First day of Shawwal, Eid al-Fitr
+pub const SHAWWAL: u32 = 10;
+
+/// Dhu al-Hijjah, whose tenth day is Eid al-Adha
+pub const DHU_AL_HIJJAH: u32 = 12;
+
+/// Announced month starts that differ from, or confirm, the tabular calendar
+const UMM_AL_QURA_MONTH_STARTS: &[(i32, u32, NaiveDate)] = &[
+    (1441, RAMADAN, date(2020, 4, 24)),
+    (1441, SHAWWAL, date(2020, 5, 24)),
+    (1441, DHU_AL_HIJJAH, date(2020, 7, 22)),
+    (1442, RAMADAN, date(2021, 4, 13)),
+    (1442, SHAWWAL, date(2021, 5, 13)),
+    (1442, DHU_AL_HIJJAH, date(2021, 7, 11)),
+    (1443, RAMADAN, date(2022, 4, 2)),
+    (1443, SHAWWAL, date(2022, 5, 2)),
+    (1443, DHU_AL_HIJJAH, date(2022, 6, 30)),
+    (1444, RAMADAN, date(2023, 3, 23)),
+    (1444, SHAWWAL, date(2023, 4, 21)),
+    (1444, DHU_AL_HIJJAH, date(2023, 6, 19)),
+    (1445, RAMADAN, date(2024, 3, 11)),
+    (1445, SHAWWAL, date(2024, 4, 10)),
+    (1445, DHU_AL_HIJJAH, date(2024, 6, 7)),
+    (1446, RAMADAN, date(2025, 3, 1)),
+    (1446, SHAWWAL, date(2025, 3, 30)),
+    (1446, DHU_AL_HIJJAH, date(2025, 5, 28)),
+    (1447, RAMADAN, date(2026, 2, 18)),
+    (1447, SHAWWAL, date(2026, 3, 20)),
+    (1447, DHU_AL_HIJJAH, date(2026, 5, 18)),
+];
+
+/// A date in the Hijri calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HijriDate {
+    /// The year, anno Hegirae
+    pub year: i32,
+    /// The month, from 1 (Muharram) to 12 (Dhu al-Hijjah)
+    pub month: u32,
+    /// The day of the month, from 1 to 30
+    pub day: u32,
+}
+
+impl HijriDate {
+    /// Create a Hijri date
+    pub const fn new(year: i32, month: u32, day: u32) -> Self {
+        HijriDate { year, month, day }
+    }
+}
+
+/// Check whether a year of the tabular calendar has 355 days
+pub fn is_tabular_leap_year(year: i32) -> bool {
+    (14 + 11 * i64::from(year)).rem_euclid(30) < 11
+}
+
+/// Get the length of a month in the tabular calendar
+pub fn tabular_month_length(year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && is_tabular_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// Days from the epoch to the first of a tabular month
+fn tabular_month_start(year: i32, month: u32) -> i64 {
+    let year = i64::from(year);
+    let month = i64::from(month);
+    (year - 1) * 354 + (3 + 11 * year).div_euclid(30) + 29 * (month - 1) + month / 2
+}
+
+/// Convert a tabular Hijri date to the Gregorian calendar
+///
+/// Returns `None` for months outside 1–12 or days past the end of the month.
+///
+/// # Examples
+///
+/// ```
+/// use trading_calendar::utils::hijri::{tabular_to_gregorian, HijriDate};
+/// use chrono::NaiveDate;
+///
+/// let date = tabular_to_gregorian(HijriDate::new(1447, 1, 1));
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 6, 27));
+/// ```
+pub fn tabular_to_gregorian(date: HijriDate) -> Option<NaiveDate> {
+    if !(1..=12).contains(&date.month)
+        || date.day == 0
+        || date.day > tabular_month_length(date.year, date.month)
+    {
+        return None;
+    }
+    let days = tabular_month_start(date.year, date.month) + i64::from(date.day) - 1;
+    EPOCH.checked_add_signed(Duration::days(days))
+}
+
+/// Convert a Gregorian date to the tabular Hijri calendar
+pub fn tabular_from_gregorian(date: NaiveDate) -> HijriDate {
+    let days = (date - EPOCH).num_days();
+    // Estimate from the mean year, then correct for the leap pattern
+    let mut year = ((30 * days + 10_646).div_euclid(CYCLE_DAYS)) as i32;
+    while tabular_month_start(year + 1, 1) <= days {
+        year += 1;
+    }
+    while tabular_month_start(year, 1) > days {
+        year -= 1;
+    }
+    let month = (1..=12)
+        .rev()
+        .find(|&m| tabular_month_start(year, m) <= days)
+        .expect("Day falls after the first of Muharram");
+    HijriDate {
+        year,
+        month,
+        day: (days - tabular_month_start(year, month)) as u32 + 1,
+    }
+}
+
+/// Convert a Hijri date to the Gregorian calendar, preferring announced
+/// Umm al-Qura month starts over the tabular calendar
+///
+/// # Examples
+///
+/// ```
+/// use trading_calendar::utils::hijri::{umm_al_qura_to_gregorian, HijriDate};
+/// use chrono::NaiveDate;
+///
+/// // Eid al-Fitr 1446 was sighted a day before the tabular date
+/// let eid = umm_al_qura_to_gregorian(HijriDate::new(1446, 10, 1));
+/// assert_eq!(eid, NaiveDate::from_ymd_opt(2025, 3, 30));
+/// ```
+pub fn umm_al_qura_to_gregorian(date: HijriDate) -> Option<NaiveDate> {
+    let announced = UMM_AL_QURA_MONTH_STARTS
+        .iter()
+        .find(|(year, month, _)| *year == date.year && *month == date.month);
+    match announced {
+        Some(&(_, _, start)) if (1..=30).contains(&date.day) => {
+            Some(start + Duration::days(i64::from(date.day) - 1))
+        }
+        Some(_) => None,
+        None => tabular_to_gregorian(date),
+    }
+}
+
+/// Get the Gregorian dates in `year` of a recurring Hijri month and day
+///
+/// The Hijri year is eleven days shorter than the Gregorian year, so a
+/// Hijri date falls once or, every 33 years or so, twice in a Gregorian year.
+pub fn hijri_dates_in(year: i32, month: u32, day: u32) -> Vec<NaiveDate> {
+    hijri_days_in(year, month, day, 1)
+}
+
+/// Get the Gregorian dates in `year` of a run of `days` consecutive days
+/// starting on a recurring Hijri month and day
+///
+/// Runs that straddle the turn of the Gregorian year contribute the days
+/// that fall in `year`.
+pub fn hijri_days_in(year: i32, month: u32, day: u32, days: u32) -> Vec<NaiveDate> {
+    let hijri_year = tabular_from_gregorian(date(year, 1, 1)).year;
+    (hijri_year - 1..=hijri_year + 1)
+        .filter_map(|y| umm_al_qura_to_gregorian(HijriDate::new(y, month, day)))
+        .flat_map(|start| (0..days).map(move |offset| start + Duration::days(offset.into())))
+        .filter(|d| d.year() == year)
+        .collect()
+}
+
+/// Get the dates of Eid al-Fitr (1 Shawwal) in a Gregorian year
+pub fn eid_al_fitr(year: i32) -> Vec<NaiveDate> {
+    hijri_dates_in(year, SHAWWAL, 1)
+}
+
+/// Get the dates of Eid al-Adha (10 Dhu al-Hijjah) in a Gregorian year
+pub fn eid_al_adha(year: i32) -> Vec<NaiveDate> {
+    hijri_dates_in(year, DHU_AL_HIJJAH, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tabular_calendar() {
+        assert_eq!(tabular_to_gregorian(HijriDate::new(1, 1, 1)), Some(EPOCH));
+        assert_eq!(
+            tabular_to_gregorian(HijriDate::new(1446, 1, 1)),
+            Some(date(2024, 7, 8))
+        );
+        assert_eq!(
+            tabular_to_gregorian(HijriDate::new(1447, 9, 1)),
+            Some(date(2026, 2, 18))
+        );
+        // Dhu al-Hijjah has 30 days only in leap years
+        assert!(is_tabular_leap_year(1445));
+        assert!(tabular_to_gregorian(HijriDate::new(1445, 12, 30)).is_some());
+        assert!(tabular_to_gregorian(HijriDate::new(1446, 12, 30)).is_none());
+        assert!(tabular_to_gregorian(HijriDate::new(1446, 13, 1)).is_none());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut day = date(2019, 1, 1);
+        while day < date(2031, 1, 1) {
+            let hijri = tabular_from_gregorian(day);
+            assert_eq!(tabular_to_gregorian(hijri), Some(day), "{day}");
+            day += Duration::days(1);
+        }
+    }
+
+    #[test]
+    fn test_umm_al_qura_overrides() {
+        // Announced dates that differ from the tabular calendar
+        assert_eq!(
+            tabular_to_gregorian(HijriDate::new(1444, 10, 1)),
+            Some(date(2023, 4, 22))
+        );
+        assert_eq!(eid_al_fitr(2023), vec![date(2023, 4, 21)]);
+        assert_eq!(eid_al_adha(2022), vec![date(2022, 7, 9)]);
+        assert_eq!(eid_al_adha(2024), vec![date(2024, 6, 16)]);
+        assert_eq!(eid_al_adha(2025), vec![date(2025, 6, 6)]);
+
+        // Announced dates published for 1441 to 1447
+        let fitr = [
+            (2020, 5, 24),
+            (2021, 5, 13),
+            (2022, 5, 2),
+            (2024, 4, 10),
+            (2025, 3, 30),
+            (2026, 3, 20),
+        ];
+        for (y, m, d) in fitr {
+            assert_eq!(eid_al_fitr(y), vec![date(y, m, d)]);
+        }
+    }
+
+    #[test]
+    fn test_dates_twice_in_a_year() {
+        // 1 Shawwal fell in both January and December of 2000
+        assert_eq!(eid_al_fitr(2000).len(), 2);
+        assert_eq!(eid_al_fitr(2001).len(), 1);
+        assert_eq!(hijri_dates_in(2030, 1, 1).len(), 1);
+
+        // 29 Ramadan to 3 Shawwal 1447 straddles no year end
+        let fitr = hijri_days_in(2026, RAMADAN, 29, 5);
+        assert_eq!(fitr.first(), Some(&date(2026, 3, 18)));
+        assert_eq!(fitr.len(), 5);
+    }
+}
@@ -0,0 +1,311 @@
+//! Chinese and Korean lunisolar calendars
+//!
+//! Months begin on the civil day of the new moon, and the eleventh month
+//! is the one containing the December solstice. When thirteen months
+//! separate two eleventh months, the first of them without a principal
+//! solar term (a multiple of 30° of solar longitude) is a leap month and
+//! repeats the number of the month before it.
+//!
+//! The Chinese and Korean calendars follow the same rules and differ only
+//! in their meridian (UTC+8 and UTC+9), which occasionally moves a new moon
+//! across midnight: the Chinese New Year 2027 is on February 6th, Seollal
+//! on February 7th.
+
+use crate::utils::astronomy::{
+    delta_t, julian_day, local_date, lunation_at, new_moon, solar_longitude, solar_term,
+};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// A lunisolar calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lunisolar {
+    /// The Chinese calendar (农历), reckoned at UTC+8
+    Chinese,
+    /// The Korean calendar (음력), reckoned at UTC+9
+    Korean,
+}
+
+/// A date in a lunisolar calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LunarDate {
+    /// The lunar year, numbered as the Gregorian year it mostly overlaps
+    pub year: i32,
+    /// The month, from 1 to 12
+    pub month: u32,
+    /// Whether this is the leap month repeating `month`
+    pub leap_month: bool,
+    /// The day of the month, from 1 to 30
+    pub day: u32,
+}
+
+impl LunarDate {
+    /// Create a date in a regular month
+    pub const fn new(year: i32, month: u32, day: u32) -> Self {
+        LunarDate {
+            year,
+            month,
+            leap_month: false,
+            day,
+        }
+    }
+
+    /// Create a date in a leap month
+    pub const fn leap(year: i32, month: u32, day: u32) -> Self {
+        LunarDate {
+            year,
+            month,
+            leap_month: true,
+            day,
+        }
+    }
+}
+
+/// A month of the lunisolar calendar
+struct Month {
+    number: u32,
+    leap: bool,
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl Lunisolar {
+    /// Get the offset from UTC the calendar is reckoned at, in hours
+    pub fn utc_offset_hours(&self) -> i32 {
+        match self {
+            Lunisolar::Chinese => 8,
+            Lunisolar::Korean => 9,
+        }
+    }
+
+    /// Convert a lunar date to the Gregorian calendar
+    ///
+    /// Returns `None` if the month does not exist in that year (a leap
+    /// month that was not inserted) or is shorter than `day`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trading_calendar::utils::lunar::{LunarDate, Lunisolar};
+    /// use chrono::NaiveDate;
+    ///
+    /// // Mid-Autumn Festival 2025
+    /// let date = Lunisolar::Chinese.to_gregorian(LunarDate::new(2025, 8, 15));
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 10, 6));
+    /// ```
+    pub fn to_gregorian(&self, date: LunarDate) -> Option<NaiveDate> {
+        if !(1..=12).contains(&date.month) || date.day == 0 {
+            return None;
+        }
+        // Months 11 and 12 start the period ending with the following
+        // year's eleventh month
+        let period = if date.month >= 11 {
+            date.year + 1
+        } else {
+            date.year
+        };
+        let month = self
+            .months(period)
+            .into_iter()
+            .find(|m| m.number == date.month && m.leap == date.leap_month)?;
+        let day = month.start + Duration::days(i64::from(date.day) - 1);
+        (day < month.end).then_some(day)
+    }
+
+    /// Convert a Gregorian date to the lunar calendar
+    pub fn from_gregorian(&self, date: NaiveDate) -> LunarDate {
+        let year = date.year();
+        let period = if date >= self.month_eleven(year).0 {
+            year + 1
+        } else {
+            year
+        };
+        let month = self
+            .months(period)
+            .into_iter()
+            .find(|m| m.start <= date && date < m.end)
+            .expect("Every date falls in a lunar month");
+        LunarDate {
+            year: if month.number >= 11 {
+                period - 1
+            } else {
+                period
+            },
+            month: month.number,
+            leap_month: month.leap,
+            day: (date - month.start).num_days() as u32 + 1,
+        }
+    }
+
+    /// Get the Gregorian date of the lunar new year
+    pub fn new_year(&self, year: i32) -> NaiveDate {
+        self.to_gregorian(LunarDate::new(year, 1, 1))
+            .expect("Every lunar year has a first month")
+    }
+
+    /// Get the leap month inserted in a lunar year, if any
+    pub fn leap_month(&self, year: i32) -> Option<u32> {
+        let this_year = self.months(year).into_iter().filter(|m| m.number < 11);
+        // A leap eleventh or twelfth month closes the year
+        let year_end = self.months(year + 1).into_iter().filter(|m| m.number >= 11);
+        this_year.chain(year_end).find(|m| m.leap).map(|m| m.number)
+    }
+
+    /// Start date and lunation of the eleventh month of `year`
+    fn month_eleven(&self, year: i32) -> (NaiveDate, i64) {
+        let offset = self.utc_offset_hours();
+        let solstice = solar_term(year, 270.0);
+        let solstice_date = local_date(solstice, offset);
+        let mut k = lunation_at(solstice);
+        // A new moon later on the day of the solstice still starts month 11
+        if local_date(new_moon(k + 1), offset) <= solstice_date {
+            k += 1;
+        }
+        (local_date(new_moon(k), offset), k)
+    }
+
+    /// The months from the eleventh month of `year - 1` up to the eleventh
+    /// month of `year`
+    fn months(&self, year: i32) -> Vec<Month> {
+        let offset = self.utc_offset_hours();
+        let (_, first) = self.month_eleven(year - 1);
+        let (_, last) = self.month_eleven(year);
+        let starts: Vec<NaiveDate> = (first..=last)
+            .map(|k| local_date(new_moon(k), offset))
+            .collect();
+
+        let leap_index = if starts.len() == 14 {
+            (0..13).find(|&i| !self.has_principal_term(starts[i], starts[i + 1]))
+        } else {
+            None
+        };
+
+        let mut number = 10;
+        starts
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let leap = Some(i) == leap_index;
+                if !leap {
+                    number = number % 12 + 1;
+                }
+                Month {
+                    number,
+                    leap,
+                    start: pair[0],
+                    end: pair[1],
+                }
+            })
+            .collect()
+    }
+
+    /// Check whether a principal solar term falls in `[start, end)`
+    fn has_principal_term(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        let sector = |date: NaiveDate| (self.solar_longitude_at(date) / 30.0).floor();
+        sector(start) != sector(end)
+    }
+
+    /// Solar longitude at local midnight starting a date
+    fn solar_longitude_at(&self, date: NaiveDate) -> f64 {
+        let midnight = date.and_hms_opt(0, 0, 0).expect("Valid time").and_utc()
+            - Duration::hours(self.utc_offset_hours().into());
+        solar_longitude(julian_day(midnight) + delta_t(date.year()) / 86_400.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::date;
+
+    /// Festival dates published by the Hong Kong Observatory: New Year,
+    /// Buddha's Birthday, Tuen Ng, Mid-Autumn and Chung Yeung
+    const HKO_FESTIVALS: &[(i32, [(u32, u32); 5])] = &[
+        (2020, [(1, 25), (4, 30), (6, 25), (10, 1), (10, 25)]),
+        (2021, [(2, 12), (5, 19), (6, 14), (9, 21), (10, 14)]),
+        (2022, [(2, 1), (5, 8), (6, 3), (9, 10), (10, 4)]),
+        (2023, [(1, 22), (5, 26), (6, 22), (9, 29), (10, 23)]),
+        (2024, [(2, 10), (5, 15), (6, 10), (9, 17), (10, 11)]),
+        (2025, [(1, 29), (5, 5), (5, 31), (10, 6), (10, 29)]),
+        (2026, [(2, 17), (5, 24), (6, 19), (9, 25), (10, 18)]),
+        (2027, [(2, 6), (5, 13), (6, 9), (9, 15), (10, 8)]),
+        (2028, [(1, 26), (5, 2), (5, 28), (10, 3), (10, 26)]),
+        (2029, [(2, 13), (5, 20), (6, 16), (9, 22), (10, 16)]),
+        (2030, [(2, 3), (5, 9), (6, 5), (9, 12), (10, 5)]),
+    ];
+
+    /// Seollal, Buddha's Birthday and Chuseok published by KASI
+    const KASI_HOLIDAYS: &[(i32, [(u32, u32); 3])] = &[
+        (2020, [(1, 25), (4, 30), (10, 1)]),
+        (2021, [(2, 12), (5, 19), (9, 21)]),
+        (2022, [(2, 1), (5, 8), (9, 10)]),
+        (2023, [(1, 22), (5, 27), (9, 29)]),
+        (2024, [(2, 10), (5, 15), (9, 17)]),
+        (2025, [(1, 29), (5, 5), (10, 6)]),
+        (2026, [(2, 17), (5, 24), (9, 25)]),
+        (2027, [(2, 7), (5, 13), (9, 15)]),
+        (2028, [(1, 27), (5, 2), (10, 3)]),
+        (2029, [(2, 13), (5, 20), (9, 22)]),
+        (2030, [(2, 3), (5, 9), (9, 12)]),
+    ];
+
+    #[test]
+    fn test_chinese_festivals() {
+        let lunar = [(1, 1), (4, 8), (5, 5), (8, 15), (9, 9)];
+        for (year, festivals) in HKO_FESTIVALS {
+            for ((month, day), (m, d)) in lunar.iter().zip(festivals) {
+                let computed = Lunisolar::Chinese.to_gregorian(LunarDate::new(*year, *month, *day));
+                assert_eq!(computed, Some(date(*year, *m, *d)), "{year} {month}/{day}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_korean_holidays() {
+        let lunar = [(1, 1), (4, 8), (8, 15)];
+        for (year, holidays) in KASI_HOLIDAYS {
+            for ((month, day), (m, d)) in lunar.iter().zip(holidays) {
+                let computed = Lunisolar::Korean.to_gregorian(LunarDate::new(*year, *month, *day));
+                assert_eq!(computed, Some(date(*year, *m, *d)), "{year} {month}/{day}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_leap_months() {
+        // 2023 repeated the second month, 2025 the sixth
+        assert_eq!(Lunisolar::Chinese.leap_month(2023), Some(2));
+        assert_eq!(Lunisolar::Chinese.leap_month(2025), Some(6));
+        assert_eq!(Lunisolar::Chinese.leap_month(2024), None);
+        // The 2033 problem: the leap month follows the eleventh month
+        assert_eq!(Lunisolar::Chinese.leap_month(2033), Some(11));
+        assert_eq!(Lunisolar::Chinese.new_year(2034), date(2034, 2, 19));
+        assert_eq!(
+            Lunisolar::Chinese.to_gregorian(LunarDate::leap(2023, 2, 1)),
+            Some(date(2023, 3, 22))
+        );
+        assert_eq!(
+            Lunisolar::Chinese.to_gregorian(LunarDate::leap(2024, 2, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut day = date(2022, 12, 1);
+        while day < date(2026, 3, 1) {
+            let lunar = Lunisolar::Chinese.from_gregorian(day);
+            assert_eq!(Lunisolar::Chinese.to_gregorian(lunar), Some(day), "{day}");
+            day += Duration::days(1);
+        }
+
+        // Lunar New Year's Eve 2025 is the 29th day of the twelfth month
+        assert_eq!(
+            Lunisolar::Chinese.from_gregorian(date(2025, 1, 28)),
+            LunarDate::new(2024, 12, 29)
+        );
+        assert_eq!(
+            Lunisolar::Chinese.to_gregorian(LunarDate::new(2024, 12, 30)),
+            None
+        );
+    }
+}
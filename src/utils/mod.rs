@@ -1,15 +1,22 @@
 //! Utility functions for calendar operations
 //!
 //! This module provides helper functions for:
-//! - Holiday calculation (Easter dates)
+//! - Holiday calculation (Easter dates, equinoxes and solar terms)
+//! - Lunisolar and Hijri calendar conversion
 //! - Date manipulation (nth weekday of month)
 //! - Caching for performance optimization
 
+pub mod astronomy;
 pub mod cache;
 pub mod easter;
+pub mod hijri;
+pub mod lunar;
 
 pub use cache::HolidayCache;
-pub use easter::{calculate_easter_monday, calculate_good_friday};
+pub use easter::{
+    calculate_ascension_day, calculate_easter_monday, calculate_good_friday,
+    calculate_maundy_thursday, calculate_orthodox_easter, calculate_whit_monday,
+};
 
 use chrono::{Datelike, NaiveDate, Weekday};
 
@@ -0,0 +1,79 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_trading-calendar"))
+        .args(args)
+        .output()
+        .expect("Failed to run trading-calendar")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_is_open_exit_status() {
+    let open = run(&["is-open", "NYSE", "--at", "2025-07-03T12:00-04:00"]);
+    assert_eq!(open.status.code(), Some(0));
+    assert!(stdout(&open).starts_with("NYSE is open"));
+
+    // July 3rd is an early close at 13:00
+    let closed = run(&["is-open", "NYSE", "--at", "2025-07-03T14:00-04:00"]);
+    assert_eq!(closed.status.code(), Some(1));
+    let extended = run(&[
+        "is-open",
+        "NYSE",
+        "--at",
+        "2025-07-03T14:00-04:00",
+        "--extended",
+    ]);
+    assert_eq!(extended.status.code(), Some(0));
+    assert!(stdout(&extended).starts_with("NYSE is open (after-hours)"));
+
+    let json = run(&["is-open", "nyse", "--at", "2025-07-03T18:00Z", "-f", "json"]);
+    assert_eq!(
+        stdout(&json).trim(),
+        r#"{"at":"2025-07-03T18:00:00+00:00","market":"NYSE","open":false,"session":"after-hours"}"#
+    );
+}
+
+#[test]
+fn test_holidays_formats() {
+    let text = run(&["holidays", "LSE", "2026"]);
+    assert!(text.status.success());
+    assert!(stdout(&text).contains("2026-04-03  Good Friday"));
+
+    let csv = run(&["holidays", "NYSE", "2025", "--format", "csv"]);
+    let csv = stdout(&csv);
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("date,name,type,close"));
+    assert!(csv.contains("2025-11-28,Day After Thanksgiving,early_close,13:00\n"));
+    assert_eq!(lines.count(), 11 + 3);
+}
+
+#[test]
+fn test_day_arithmetic() {
+    let count = run(&["count", "NYSE", "2025-01-01", "2025-12-31"]);
+    assert_eq!(stdout(&count).trim(), "250");
+
+    let forward = run(&["add-days", "NYSE", "2025-12-22", "5"]);
+    assert_eq!(stdout(&forward).trim(), "2025-12-30");
+
+    let back = run(&["add-days", "NYSE", "2025-12-30", "-5"]);
+    assert_eq!(stdout(&back).trim(), "2025-12-22");
+}
+
+#[test]
+fn test_errors() {
+    let unknown = run(&["holidays", "XYZ", "2025"]);
+    assert_eq!(unknown.status.code(), Some(2));
+
+    let out_of_range = run(&["holidays", "NYSE", "2200"]);
+    assert_eq!(out_of_range.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&out_of_range.stderr).contains("2200"));
+
+    let bad_instant = run(&["is-open", "NYSE", "--at", "tomorrow"]);
+    assert_eq!(bad_instant.status.code(), Some(2));
+}
@@ -1,4 +1,4 @@
-use trading_calendar::{Market, NaiveDate, TradingCalendar};
+use trading_calendar::{CalendarError, Market, NaiveDate, TradingCalendar};
 
 #[test]
 fn test_nyse_holidays_2025() {
@@ -65,3 +65,85 @@ fn test_thread_safety() {
         handle.join().unwrap();
     }
 }
+
+#[test]
+fn test_trading_day_arithmetic_matches_stepping() {
+    let cal = TradingCalendar::new(Market::NYSE).unwrap();
+    let start = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+
+    // Stepping one trading day at a time must agree with the index,
+    // including across year boundaries
+    let mut stepped = start;
+    for n in 1..=300 {
+        stepped = cal.next_trading_day(stepped);
+        assert_eq!(cal.add_trading_days(start, n).unwrap(), stepped);
+        assert_eq!(cal.sub_trading_days(stepped, n).unwrap(), start);
+        assert_eq!(cal.trading_days_between(start, stepped).unwrap(), n);
+        assert_eq!(cal.trading_days_between(stepped, start).unwrap(), -n);
+    }
+
+    // Non-trading days move to the nearest trading day in the direction
+    let christmas = NaiveDate::from_ymd_opt(2025, 12, 25).unwrap();
+    assert_eq!(
+        cal.add_trading_days(christmas, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 12, 26).unwrap()
+    );
+    assert_eq!(
+        cal.add_trading_days(christmas, -1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 12, 24).unwrap()
+    );
+    assert_eq!(cal.add_trading_days(christmas, 0).unwrap(), christmas);
+}
+
+#[test]
+fn test_trading_days_of_month() {
+    let cal = TradingCalendar::new(Market::NYSE).unwrap();
+    let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+
+    assert_eq!(
+        cal.nth_trading_day_of_month(2025, 9, 1).unwrap(),
+        Some(date(9, 2))
+    );
+    assert_eq!(
+        cal.nth_trading_day_of_month(2025, 2, 19).unwrap(),
+        Some(date(2, 28))
+    );
+    assert_eq!(cal.nth_trading_day_of_month(2025, 2, 20).unwrap(), None);
+    assert_eq!(cal.nth_trading_day_of_month(2025, 2, 0).unwrap(), None);
+    assert_eq!(
+        cal.last_trading_day_of_month(2025, 12).unwrap(),
+        Some(date(12, 31))
+    );
+    assert_eq!(
+        cal.last_trading_day_of_month(2026, 5).unwrap(),
+        NaiveDate::from_ymd_opt(2026, 5, 29)
+    );
+    assert!(cal.last_trading_day_of_month(2025, 13).is_err());
+    assert!(cal.nth_trading_day_of_month(2101, 1, 1).is_err());
+}
+
+#[test]
+fn test_trading_day_arithmetic_range() {
+    let cal = TradingCalendar::with_year_range(Market::NYSE, 2020..=2030).unwrap();
+    let last = NaiveDate::from_ymd_opt(2030, 12, 31).unwrap();
+    assert!(cal.add_trading_days(last, 1).is_err());
+    assert!(cal
+        .add_trading_days(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap(), -1)
+        .is_err());
+    assert!(cal
+        .trading_days_between(last, NaiveDate::from_ymd_opt(2031, 1, 2).unwrap())
+        .is_err());
+
+    // Step counts at the limits of i64 are out of range, not an overflow
+    let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    for n in [i64::MAX, i64::MIN] {
+        assert!(matches!(
+            cal.add_trading_days(date, n),
+            Err(CalendarError::DateOutOfRange(_))
+        ));
+        assert!(matches!(
+            cal.sub_trading_days(date, n),
+            Err(CalendarError::DateOutOfRange(_))
+        ));
+    }
+}
@@ -161,3 +161,264 @@ fn test_holiday_struct_usage() {
         Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap())
     );
 }
+
+#[test]
+fn test_named_holidays_for_all_markets() {
+    let cases = vec![
+        (Market::NYSE, (2025, 11, 27), "Thanksgiving Day"),
+        (Market::LSE, (2025, 5, 26), "Spring Bank Holiday"),
+        (
+            Market::TSE,
+            (2024, 11, 4),
+            "Substitute Holiday for Culture Day",
+        ),
+        (Market::TSX, (2025, 5, 19), "Victoria Day"),
+        (Market::EURONEXT, (2025, 5, 1), "Labour Day"),
+        (Market::XETRA, (2025, 12, 24), "Christmas Eve"),
+        (Market::SIX, (2025, 1, 2), "Berchtoldstag"),
+        (Market::BIT, (2025, 8, 15), "Ferragosto"),
+        (Market::BME, (2025, 12, 26), "St. Stephen's Day"),
+        (Market::OMXS, (2025, 6, 20), "Midsummer Eve"),
+        (Market::OMXH, (2024, 12, 6), "Independence Day"),
+        (Market::OMXC, (2025, 6, 9), "Whit Monday"),
+        (Market::HKEX, (2025, 1, 29), "Lunar New Year's Day"),
+        (
+            Market::SSE,
+            (2025, 10, 8),
+            "National Day and Mid-Autumn Festival",
+        ),
+        (Market::KRX, (2025, 10, 8), "Substitute Holiday for Chuseok"),
+        (Market::ASX, (2025, 4, 25), "Anzac Day"),
+        (Market::NSE, (2025, 3, 14), "Holi"),
+        (Market::SGX, (2025, 10, 20), "Deepavali"),
+        (Market::TADAWUL, (2025, 2, 23), "Founding Day"),
+        (Market::DFM, (2025, 12, 2), "National Day"),
+        (Market::ADX, (2025, 6, 5), "Arafat Day"),
+        (Market::JSE, (2025, 4, 28), "Freedom Day (observed)"),
+        (Market::EGX, (2025, 4, 21), "Sham El-Nessim"),
+        (Market::B3, (2025, 3, 4), "Carnival Tuesday"),
+        (Market::BMV, (2025, 2, 3), "Constitution Day"),
+        (Market::CME, (2025, 12, 25), "Christmas Day"),
+        (Market::CBOE, (2025, 7, 4), "Independence Day"),
+    ];
+
+    for (market, (y, m, d), name) in cases {
+        let calendar = TradingCalendar::new(market).unwrap();
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let holiday = calendar.holiday_info(date).unwrap().unwrap();
+        assert_eq!(holiday.name, name);
+        assert!(holiday.market_closed);
+        assert!(holiday.early_close.is_none());
+
+        // Every listed holiday is reported as a holiday
+        for holiday in calendar.holidays(y).unwrap() {
+            assert!(calendar.is_holiday(holiday.date).unwrap());
+        }
+    }
+
+    // Regular trading days have no holiday information
+    let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+    let regular_day = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    assert!(nyse.holiday_info(regular_day).unwrap().is_none());
+    assert!(nyse.holidays(1989).is_err());
+}
+
+#[test]
+fn test_early_closes_for_all_markets() {
+    let cases = vec![
+        (
+            Market::NYSE,
+            (2025, 11, 28),
+            (13, 0),
+            "Day After Thanksgiving",
+        ),
+        (Market::LSE, (2025, 12, 31), (12, 30), "New Year's Eve"),
+        (Market::TSX, (2025, 12, 24), (13, 0), "Christmas Eve"),
+        (Market::EURONEXT, (2025, 12, 31), (14, 5), "New Year's Eve"),
+        (Market::BME, (2025, 12, 24), (14, 0), "Christmas Eve"),
+        (Market::OMXS, (2025, 4, 30), (13, 0), "Walpurgis Night"),
+        (Market::HKEX, (2025, 1, 28), (12, 0), "Lunar New Year's Eve"),
+        (
+            Market::ASX,
+            (2025, 12, 31),
+            (14, 10),
+            "Last trading day of the year",
+        ),
+        (Market::SGX, (2025, 12, 24), (12, 0), "Christmas Eve"),
+        (Market::JSE, (2025, 12, 31), (12, 0), "New Year's Eve"),
+        (Market::CME, (2025, 9, 1), (12, 0), "Labor Day"),
+        (Market::CBOEIndex, (2025, 12, 24), (13, 15), "Christmas Eve"),
+    ];
+
+    for (market, (y, m, d), (hour, minute), name) in cases {
+        let calendar = TradingCalendar::new(market).unwrap();
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let close = NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+
+        let hours = calendar.trading_hours(date);
+        assert!(hours.is_early_close());
+        assert_eq!(hours.market_close(), close);
+
+        let info = calendar.holiday_info(date).unwrap().unwrap();
+        assert_eq!(info.name, name);
+        assert!(!info.market_closed);
+        assert_eq!(info.early_close, Some(close));
+
+        let early_closes = calendar.early_closes(y).unwrap();
+        assert!(early_closes.contains(&info));
+        for day in early_closes {
+            assert!(calendar.is_trading_day(day.date).unwrap());
+        }
+    }
+
+    // TSE has no half days
+    let tse = TradingCalendar::new(Market::TSE).unwrap();
+    assert!(tse.early_closes(2025).unwrap().is_empty());
+}
+
+#[test]
+fn test_tse_lunch_break_and_close_extension() {
+    let tse = TradingCalendar::new(Market::TSE).unwrap();
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+    let before = tse.trading_hours(NaiveDate::from_ymd_opt(2024, 11, 1).unwrap());
+    assert_eq!(before.market_close(), time(15, 0));
+    assert!(!before.is_open_at(time(15, 15)));
+
+    let after = tse.trading_hours(NaiveDate::from_ymd_opt(2024, 11, 5).unwrap());
+    assert_eq!(after.market_close(), time(15, 30));
+    assert!(after.is_open_at(time(15, 15)));
+
+    // Closed over lunch
+    assert!(after.is_open_at(time(11, 0)));
+    assert!(!after.is_open_at(time(12, 0)));
+    assert!(after.is_open_at(time(13, 0)));
+
+    let segments = after.segments();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(
+        (segments[0].start, segments[0].end),
+        (time(9, 0), time(11, 30))
+    );
+    assert_eq!(
+        (segments[1].start, segments[1].end),
+        (time(12, 30), time(15, 30))
+    );
+
+    // The morning session ran to 11:00 until 2011-11-18
+    let old_hours = tse.trading_hours(NaiveDate::from_ymd_opt(2011, 11, 18).unwrap());
+    assert!(!old_hours.is_open_at(time(11, 15)));
+    assert_eq!(old_hours.segments()[0].end, time(11, 0));
+    let new_hours = tse.trading_hours(NaiveDate::from_ymd_opt(2011, 11, 21).unwrap());
+    assert!(new_hours.is_open_at(time(11, 15)));
+    assert_eq!(new_hours.segments()[0].end, time(11, 30));
+}
+
+#[test]
+fn test_asian_lunch_breaks() {
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+    let hkex = TradingCalendar::new(Market::HKEX).unwrap();
+    let hours = hkex.trading_hours(date);
+    assert!(hours.is_open_at(time(11, 59)));
+    assert!(!hours.is_open_at(time(12, 30)));
+    assert!(hours.is_open_at(time(13, 0)));
+
+    // Half days trade the morning session only
+    let eve = hkex.trading_hours(NaiveDate::from_ymd_opt(2025, 12, 24).unwrap());
+    assert_eq!(eve.segments().len(), 1);
+    assert!(!eve.is_open_at(time(13, 30)));
+
+    let sse = TradingCalendar::new(Market::SSE).unwrap();
+    let hours = sse.trading_hours(date);
+    assert!(!hours.is_open_at(time(12, 0)));
+    assert_eq!(hours.market_close(), time(15, 0));
+}
+
+#[test]
+fn test_special_closures_match_exchange_records() {
+    let nyse = TradingCalendar::new(Market::NYSE).unwrap();
+    let days_in = |calendar: &TradingCalendar, year| {
+        calendar
+            .count_trading_days(
+                NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
+            )
+            .unwrap()
+    };
+
+    // NYSE's published trading-day counts
+    assert_eq!(days_in(&nyse, 2001), 248);
+    assert_eq!(days_in(&nyse, 2012), 250);
+    assert_eq!(days_in(&nyse, 2025), 250);
+
+    let special = nyse.special_days(2025).unwrap();
+    assert_eq!(special.len(), 1);
+    assert!(special[0].is_closed());
+    assert!(!special[0].source.is_empty());
+    let info = nyse.holiday_info(special[0].date).unwrap().unwrap();
+    assert_eq!(info.name, special[0].name);
+
+    let lse = TradingCalendar::new(Market::LSE).unwrap();
+    let funeral = NaiveDate::from_ymd_opt(2022, 9, 19).unwrap();
+    assert!(!lse.is_trading_day(funeral).unwrap());
+    assert_eq!(lse.special_days(2022).unwrap().len(), 2);
+
+    let tse = TradingCalendar::new(Market::TSE).unwrap();
+    let enthronement = NaiveDate::from_ymd_opt(2019, 10, 22).unwrap();
+    assert!(!tse.is_trading_day(enthronement).unwrap());
+
+    // Markets without special days report none
+    let tsx = TradingCalendar::new(Market::TSX).unwrap();
+    assert!(tsx.special_days(2025).unwrap().is_empty());
+}
+
+#[test]
+fn test_sunday_to_thursday_weeks() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let tadawul = TradingCalendar::new(Market::TADAWUL).unwrap();
+    assert!(tadawul.is_trading_day(date(2025, 3, 9)).unwrap()); // Sunday
+    assert!(!tadawul.is_trading_day(date(2025, 3, 7)).unwrap()); // Friday
+    assert_eq!(tadawul.next_trading_day(date(2025, 3, 6)), date(2025, 3, 9));
+
+    // The UAE moved to a Saturday-Sunday weekend in 2022
+    let dfm = TradingCalendar::new(Market::DFM).unwrap();
+    assert!(dfm.is_trading_day(date(2021, 12, 26)).unwrap()); // Sunday
+    assert!(!dfm.is_trading_day(date(2022, 1, 2)).unwrap());
+    assert!(dfm.is_trading_day(date(2022, 1, 7)).unwrap()); // Friday
+}
+
+#[test]
+fn test_americas_derivatives_venues() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+    // B3 opens at 13:00 on Ash Wednesday
+    let b3 = TradingCalendar::new(Market::B3).unwrap();
+    assert_eq!(
+        b3.trading_hours(date(2025, 3, 5)).regular.start,
+        time(13, 0)
+    );
+    assert_eq!(
+        b3.trading_hours(date(2025, 3, 6)).regular.start,
+        time(10, 0)
+    );
+
+    // Globex halts rather than closes on Thanksgiving
+    let cme = TradingCalendar::new(Market::CME).unwrap();
+    let thanksgiving = date(2025, 11, 27);
+    assert!(cme.is_trading_day(thanksgiving).unwrap());
+    assert!(!cme.is_holiday(thanksgiving).unwrap());
+    assert_eq!(cme.trading_hours(thanksgiving).market_close(), time(12, 0));
+
+    // Index options trade a quarter hour past equity options
+    let cboe = TradingCalendar::new(Market::CBOE).unwrap();
+    let index = TradingCalendar::new(Market::CBOEIndex).unwrap();
+    let day = date(2025, 3, 10);
+    assert_eq!(cboe.trading_hours(day).market_close(), time(16, 0));
+    assert_eq!(index.trading_hours(day).market_close(), time(16, 15));
+    assert_eq!("cboe_index".parse::<Market>().unwrap(), Market::CBOEIndex);
+}
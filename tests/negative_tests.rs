@@ -1,12 +1,12 @@
 use chrono::Datelike;
-use trading_calendar::{Market, NaiveDate, TradingCalendar};
+use trading_calendar::{CalendarError, Market, NaiveDate, TradingCalendar};
 
 #[test]
 fn test_unsupported_years() {
     let _nyse = TradingCalendar::new(Market::NYSE).unwrap();
 
-    // Test years outside supported range (2020-2030)
-    let unsupported_years = vec![2019, 2031, 2100];
+    // Test years outside supported range (1990-2100)
+    let unsupported_years = vec![1989, 2101, 2200];
 
     for year in unsupported_years {
         let test_date = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
@@ -55,8 +55,8 @@ fn test_edge_case_dates() {
     assert!(nyse.is_trading_day(max_supported).unwrap() || nyse.is_holiday(max_supported).unwrap());
 
     // Just outside range should fail
-    let just_before = NaiveDate::from_ymd_opt(2019, 12, 31).unwrap();
-    let just_after = NaiveDate::from_ymd_opt(2031, 1, 1).unwrap();
+    let just_before = NaiveDate::from_ymd_opt(1989, 12, 31).unwrap();
+    let just_after = NaiveDate::from_ymd_opt(2101, 1, 1).unwrap();
 
     assert!(nyse.is_trading_day(just_before).is_err());
     assert!(nyse.is_trading_day(just_after).is_err());
@@ -106,6 +106,31 @@ fn test_market_creation_errors() {
         Market::LSE,
         Market::TSE,
         Market::TSX,
+        Market::EURONEXT,
+        Market::XETRA,
+        Market::SIX,
+        Market::BIT,
+        Market::BME,
+        Market::OMXS,
+        Market::OMXH,
+        Market::OMXC,
+        Market::HKEX,
+        Market::SSE,
+        Market::SZSE,
+        Market::KRX,
+        Market::ASX,
+        Market::NSE,
+        Market::SGX,
+        Market::TADAWUL,
+        Market::DFM,
+        Market::ADX,
+        Market::JSE,
+        Market::EGX,
+        Market::B3,
+        Market::BMV,
+        Market::CME,
+        Market::CBOE,
+        Market::CBOEIndex,
     ];
 
     for market in valid_markets {
@@ -212,3 +237,12 @@ fn test_trading_hours_validation() {
         }
     }
 }
+
+#[test]
+fn test_custom_market_requires_definition() {
+    // Custom markets have no built-in rules
+    assert!(matches!(
+        TradingCalendar::new(Market::Custom),
+        Err(CalendarError::InvalidConfiguration(_))
+    ));
+}